@@ -0,0 +1,13 @@
+use util::shapes::Point;
+
+use buildings::BuildingKind;
+
+/// A building placed somewhere on the map.
+#[derive(Copy, Clone, Debug)]
+pub struct Building {
+    /// What it is.
+    pub kind: BuildingKind,
+
+    /// Where it is.
+    pub position: Point<u32>,
+}
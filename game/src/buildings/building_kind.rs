@@ -0,0 +1,56 @@
+use items::ItemKind;
+
+/// The different buildings which can be placed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BuildingKind {
+    /// Where merchants come to trade.
+    TradeDepot,
+
+    /// A patch of soil for growing crops on.
+    FarmPlot,
+}
+
+impl BuildingKind {
+    /// Finds the building with the key given.
+    /// i.e. 'trade_depot'.
+    pub fn from_key(key: &str) -> Option<BuildingKind> {
+        match key {
+            "trade_depot" => Some(BuildingKind::TradeDepot),
+            "farm_plot" => Some(BuildingKind::FarmPlot),
+            _ => None,
+        }
+    }
+
+    /// A single word naming the building, for saving.
+    pub fn key(self) -> &'static str {
+        match self {
+            BuildingKind::TradeDepot => "trade_depot",
+            BuildingKind::FarmPlot => "farm_plot",
+        }
+    }
+
+    /// A human readable name for the building.
+    pub fn name(self) -> &'static str {
+        match self {
+            BuildingKind::TradeDepot => "trade depot",
+            BuildingKind::FarmPlot => "farm plot",
+        }
+    }
+
+    /// What is used up when it's built.
+    /// Returns None if it doesn't need anything.
+    pub fn material(self) -> Option<ItemKind> {
+        match self {
+            BuildingKind::TradeDepot => Some(ItemKind::Stone),
+            BuildingKind::FarmPlot => None,
+        }
+    }
+
+    /// Returns true if this can only be placed on soil.
+    pub fn needs_soil(self) -> bool {
+        match self {
+            BuildingKind::TradeDepot => false,
+            BuildingKind::FarmPlot => true,
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use std::slice::Iter;
+
+use util::shapes::Point;
+
+use buildings::Building;
+use buildings::BuildingKind;
+use farming::Farms;
+
+/// Every building in the fortress.
+pub struct Buildings {
+    /// The buildings, in the order they were built.
+    buildings: Vec<Building>,
+
+    /// What is growing on the farm plots.
+    farms: Farms,
+}
+
+impl Buildings {
+    /// Creates a fortress with no buildings.
+    /// Farm plots can grow the crops which come with the game.
+    pub fn new() -> Buildings {
+        Buildings::with_farms(Farms::new())
+    }
+
+    /// Creates a fortress with no buildings, using the farms given.
+    pub fn with_farms(farms: Farms) -> Buildings {
+        Buildings {
+            buildings: Vec::new(),
+            farms: farms,
+        }
+    }
+
+    /// Adds a building.
+    /// Farm plots are also added to the farms.
+    pub fn add(
+        &mut self,
+        building: Building,
+    ) {
+        if building.kind == BuildingKind::FarmPlot {
+            self.farms.add_plot(building.position);
+        }
+
+        self.buildings.push(building);
+    }
+
+    /// Returns the building at the position given, if there is one.
+    pub fn at(
+        &self,
+        position: Point<u32>,
+    ) -> Option<&Building> {
+        self.buildings.iter().find(|building| building.position == position)
+    }
+
+    /// Returns the first building of the kind given, if there is one.
+    pub fn find(
+        &self,
+        kind: BuildingKind,
+    ) -> Option<&Building> {
+        self.buildings.iter().find(|building| building.kind == kind)
+    }
+
+    /// Returns the number of buildings.
+    pub fn len(&self) -> usize {
+        self.buildings.len()
+    }
+
+    /// Returns true if nothing has been built.
+    pub fn is_empty(&self) -> bool {
+        self.buildings.is_empty()
+    }
+
+    /// Iterates over every building, in the order they were built.
+    pub fn iter(&self) -> Iter<'_, Building> {
+        self.buildings.iter()
+    }
+
+    /// Returns the farm plots, and what is growing on them.
+    pub fn farms(&self) -> &Farms {
+        &self.farms
+    }
+
+    /// Returns the farm plots, for changing.
+    pub fn farms_mut(&mut self) -> &mut Farms {
+        &mut self.farms
+    }
+}
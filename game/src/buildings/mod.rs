@@ -0,0 +1,12 @@
+//! Buildings.
+//!
+//! Places in the fortress which are set aside for something, like trading.
+//!
+
+mod building;
+mod building_kind;
+mod buildings;
+
+pub use self::building::Building;
+pub use self::building_kind::BuildingKind;
+pub use self::buildings::Buildings;
@@ -0,0 +1,47 @@
+/// What happens when a citizen can't take any more.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BreakdownKind {
+    /// They storm about, refusing to work, until they've calmed down.
+    Tantrum,
+
+    /// They stop, and stare, and do nothing at all, for a long while.
+    Melancholy,
+}
+
+impl BreakdownKind {
+    /// How many ticks it lasts.
+    pub fn duration(self) -> u32 {
+        match self {
+            BreakdownKind::Tantrum => 300,
+            BreakdownKind::Melancholy => 1_500,
+        }
+    }
+
+    /// A human readable name.
+    pub fn name(self) -> &'static str {
+        match self {
+            BreakdownKind::Tantrum => "tantrum",
+            BreakdownKind::Melancholy => "melancholy",
+        }
+    }
+}
+
+/// A breakdown a citizen is going through.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Breakdown {
+    /// What sort it is.
+    pub kind: BreakdownKind,
+
+    /// How many ticks until it's over.
+    pub remaining: u32,
+}
+
+impl Breakdown {
+    /// Starts a new breakdown.
+    pub fn new(kind: BreakdownKind) -> Breakdown {
+        Breakdown {
+            kind: kind,
+            remaining: kind.duration(),
+        }
+    }
+}
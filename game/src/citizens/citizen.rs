@@ -0,0 +1,114 @@
+use citizens::Breakdown;
+use citizens::Facet;
+use citizens::Mood;
+use citizens::Personality;
+use citizens::ThoughtKind;
+use citizens::Thoughts;
+use jobs::Labours;
+
+/// The record kept for each citizen of the fortress.
+///
+/// Their name, body, and skills live on their creature. This holds what only
+/// citizens have.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Citizen {
+    /// What they are like.
+    pub personality: Personality,
+
+    /// The kinds of work they will pick up.
+    pub labours: Labours,
+
+    /// What is on their mind.
+    pub thoughts: Thoughts,
+
+    /// How much bad feeling has built up.
+    /// When this gets too high, they break down.
+    pub stress: u32,
+
+    /// The breakdown they are going through, if they are.
+    pub breakdown: Option<Breakdown>,
+}
+
+impl Citizen {
+    /// Creates a new citizen, with nothing on their mind.
+    pub fn new(
+        personality: Personality,
+        labours: Labours,
+    ) -> Citizen {
+        Citizen {
+            personality: personality,
+            labours: labours,
+            thoughts: Thoughts::new(),
+            stress: 0,
+            breakdown: None,
+        }
+    }
+
+    /// Gives them something to think about.
+    ///
+    /// How strongly they feel depends on who they are. The brave are less
+    /// shaken by violence, and the cheerful take more joy from good things.
+    pub fn think(
+        &mut self,
+        kind: ThoughtKind,
+    ) {
+        self.think_scaled(kind, 100);
+    }
+
+    /// Gives them something to think about, felt more, or less, strongly than
+    /// usual. The percent scales the kind's effect, before their personality
+    /// is taken into account.
+    pub fn think_scaled(
+        &mut self,
+        kind: ThoughtKind,
+        percent: i32,
+    ) {
+        let effect = kind.effect() * percent / 100;
+        let scale = match kind {
+            ThoughtKind::SawDeath | ThoughtKind::WasInjured => {
+                150 - i32::from(self.personality.get(Facet::Bravery))
+            },
+            _ if effect > 0 => 50 + i32::from(self.personality.get(Facet::Cheerfulness)),
+            _ => 100,
+        };
+
+        self.thoughts.add(kind, effect * scale / 100);
+    }
+
+    /// Returns how they are feeling, as a number.
+    /// 0 is content, above is happier, and below is sadder.
+    pub fn mood_value(&self) -> i32 {
+        let cheerfulness = i32::from(self.personality.get(Facet::Cheerfulness));
+
+        self.thoughts.total() + (cheerfulness - 50) / 5
+    }
+
+    /// Returns how they are feeling.
+    pub fn mood(&self) -> Mood {
+        Mood::from_value(self.mood_value())
+    }
+
+    /// Returns true if they are in a fit state to pick up work.
+    pub fn can_work(&self) -> bool {
+        self.breakdown.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_brave_are_less_shaken() {
+        let mut brave = Citizen::new(Personality::average(), Labours::none());
+        let mut coward = brave.clone();
+        brave.personality.set(Facet::Bravery, 100);
+        coward.personality.set(Facet::Bravery, 0);
+
+        brave.think(ThoughtKind::SawDeath);
+        coward.think(ThoughtKind::SawDeath);
+
+        assert!(brave.mood_value() > coward.mood_value());
+        assert!(brave.mood_value() < 0);
+    }
+}
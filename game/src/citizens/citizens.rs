@@ -0,0 +1,386 @@
+use std::collections::btree_map::Iter;
+use std::collections::BTreeMap;
+
+use util::random::Random;
+use util::shapes::Point;
+use world::map::Map;
+
+use citizens::generate_name;
+use citizens::Breakdown;
+use citizens::BreakdownKind;
+use citizens::Citizen;
+use citizens::Facet;
+use citizens::Personality;
+use citizens::ThoughtKind;
+use combat::Body;
+use combat::BodyPlan;
+use creatures::CreatureId;
+use creatures::Creatures;
+use creatures::Faction;
+use events::GameEvent;
+use jobs::Labour;
+use jobs::Labours;
+use navigation::distance_squared;
+use navigation::find_edge_positions;
+use navigation::free_neighbours;
+use navigation::step_towards;
+use skills::Skill;
+use GameTile;
+
+/// The character citizens are drawn with.
+const CITIZEN_SYMBOL: char = 'D';
+
+/// How big a dwarf is.
+const DWARF_SIZE: u8 = 10;
+
+/// The most skills a migrant arrives with.
+const MAX_STARTING_SKILLS: u32 = 3;
+
+/// The highest level of skill a migrant arrives with.
+const MAX_STARTING_LEVEL: u32 = 5;
+
+/// How close migrants walk up to the fortress. In tiles.
+const ARRIVAL_DISTANCE: u64 = 3;
+
+/// How much stress a citizen can take before they break down.
+const BREAKDOWN_STRESS: u32 = 3_000;
+
+/// Citizens with less patience than this throw tantrums, rather than falling
+/// into melancholy.
+const TANTRUM_PATIENCE: u8 = 50;
+
+/// Every citizen of the fortress, and those on their way to it.
+pub struct Citizens {
+    /// The records for each citizen.
+    records: BTreeMap<CreatureId, Citizen>,
+
+    /// Migrants who are still walking to the fortress.
+    arriving: Vec<CreatureId>,
+}
+
+impl Citizens {
+    /// Creates an empty fortress.
+    pub fn new() -> Citizens {
+        Citizens {
+            records: BTreeMap::new(),
+            arriving: Vec::new(),
+        }
+    }
+
+    /// Finds the record for the citizen given.
+    pub fn get(
+        &self,
+        id: CreatureId,
+    ) -> Option<&Citizen> {
+        self.records.get(&id)
+    }
+
+    /// Finds the record for the citizen given, for changing.
+    pub fn get_mut(
+        &mut self,
+        id: CreatureId,
+    ) -> Option<&mut Citizen> {
+        self.records.get_mut(&id)
+    }
+
+    /// Returns the number of citizens.
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Returns true if there are no citizens.
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Iterates over every citizen, in the order they arrived.
+    pub fn iter(&self) -> Iter<'_, CreatureId, Citizen> {
+        self.records.iter()
+    }
+
+    /// Gives the citizen something to think about.
+    pub fn think(
+        &mut self,
+        id: CreatureId,
+        kind: ThoughtKind,
+    ) {
+        if let Some(citizen) = self.records.get_mut(&id) {
+            citizen.think(kind);
+        }
+    }
+
+    /// Gives the citizen something to think about, scaled by the percent
+    /// given.
+    pub fn think_scaled(
+        &mut self,
+        id: CreatureId,
+        kind: ThoughtKind,
+        percent: i32,
+    ) {
+        if let Some(citizen) = self.records.get_mut(&id) {
+            citizen.think_scaled(kind, percent);
+        }
+    }
+
+    /// Returns true if the citizen is still walking to the fortress.
+    pub fn is_arriving(
+        &self,
+        id: CreatureId,
+    ) -> bool {
+        self.arriving.contains(&id)
+    }
+
+    /// Brings new migrants onto the map, together along one edge.
+    ///
+    /// Each gets a name, a personality, and a few skills. They start with the
+    /// labours for their skills turned on. Returns the ids of those who could
+    /// be placed.
+    pub fn spawn_wave(
+        &mut self,
+        map: &Map<GameTile>,
+        creatures: &mut Creatures,
+        rng: &mut Random,
+        size: u32,
+    ) -> Vec<CreatureId> {
+        let positions = find_edge_positions(map, creatures, rng, size as usize);
+        let mut migrants = Vec::new();
+
+        for position in positions {
+            let name = generate_name(rng);
+            let id = creatures.spawn(
+                name,
+                CITIZEN_SYMBOL,
+                Faction::Fortress,
+                position,
+                Body::new(BodyPlan::Humanoid, DWARF_SIZE),
+            );
+
+            let mut labours = Labours::none();
+            if let Some(creature) = creatures.get_mut(id) {
+                for _ in 0..rng.range(1, MAX_STARTING_SKILLS + 1) {
+                    if let Some(&skill) = rng.pick(&Skill::ALL) {
+                        let level = rng.range(1, MAX_STARTING_LEVEL + 1) as u8;
+                        creature.skills.set_level(skill, level);
+                    }
+                }
+
+                for &labour in Labour::ALL.iter() {
+                    labours.set(labour, creature.skills.level(labour.skill()) > 0);
+                }
+            }
+
+            self.records.insert(id, Citizen::new(Personality::random(rng), labours));
+            self.arriving.push(id);
+            migrants.push(id);
+        }
+
+        migrants
+    }
+
+    /// Walks arriving migrants towards the fortress, and forgets about any
+    /// citizens who have died.
+    pub fn update(
+        &mut self,
+        map: &Map<GameTile>,
+        creatures: &mut Creatures,
+        fortress: Point<u32>,
+    ) {
+        self.records.retain(|&id, _| creatures.get(id).is_some());
+
+        let arriving = self.arriving.drain(..).collect::<Vec<CreatureId>>();
+        for id in arriving {
+            let position = match creatures.get(id) {
+                Some(creature) => creature.position,
+                None => continue,
+            };
+
+            if distance_squared(position, fortress) <= ARRIVAL_DISTANCE * ARRIVAL_DISTANCE {
+                continue;
+            }
+
+            if let Some(next) = step_towards(map, creatures, &[fortress], position, fortress) {
+                if let Some(creature) = creatures.get_mut(id) {
+                    creature.position = next;
+                }
+            }
+
+            self.arriving.push(id);
+        }
+    }
+
+    /// Fades old thoughts, builds up, or eases, stress, and carries out any
+    /// breakdowns.
+    ///
+    /// Citizens who are unhappy for long enough break down. The impatient
+    /// throw tantrums, stomping about at random, whilst others sink into
+    /// melancholy. Either way they do no work until it's over. Returns
+    /// anything worth telling the player about.
+    pub fn update_moods(
+        &mut self,
+        map: &Map<GameTile>,
+        creatures: &mut Creatures,
+        rng: &mut Random,
+    ) -> Vec<GameEvent> {
+        let mut events = Vec::new();
+
+        for (&id, citizen) in self.records.iter_mut() {
+            citizen.thoughts.update();
+
+            let name = match creatures.get(id) {
+                Some(creature) => creature.name.clone(),
+                None => continue,
+            };
+
+            if let Some(mut breakdown) = citizen.breakdown {
+                breakdown.remaining = breakdown.remaining.saturating_sub(1);
+
+                if breakdown.kind == BreakdownKind::Tantrum {
+                    stomp_about(map, creatures, rng, id);
+                }
+
+                if breakdown.remaining == 0 {
+                    citizen.breakdown = None;
+                    events.push(GameEvent::Recovered {
+                        name: name,
+                    });
+                } else {
+                    citizen.breakdown = Some(breakdown);
+                }
+
+                continue;
+            }
+
+            let mood = citizen.mood_value();
+            if mood < 0 {
+                let patience = i32::from(citizen.personality.get(Facet::Patience));
+                let gain = (-mood * (150 - patience) / 100) as u32;
+
+                citizen.stress += gain;
+            } else {
+                citizen.stress = citizen.stress.saturating_sub(mood as u32 + 1);
+            }
+
+            if citizen.stress >= BREAKDOWN_STRESS {
+                let kind = if citizen.personality.get(Facet::Patience) < TANTRUM_PATIENCE {
+                    BreakdownKind::Tantrum
+                } else {
+                    BreakdownKind::Melancholy
+                };
+
+                citizen.stress /= 2;
+                citizen.breakdown = Some(Breakdown::new(kind));
+                events.push(GameEvent::BrokeDown {
+                    name: name,
+                    kind: kind,
+                });
+            }
+        }
+
+        events
+    }
+}
+
+/// Moves the creature one step in a random direction, if it can.
+fn stomp_about(
+    map: &Map<GameTile>,
+    creatures: &mut Creatures,
+    rng: &mut Random,
+    id: CreatureId,
+) {
+    let position = match creatures.get(id) {
+        Some(creature) => creature.position,
+        None => return,
+    };
+
+    let neighbours = free_neighbours(map, creatures, &[], position);
+    if let Some(&next) = rng.pick(&neighbours) {
+        if let Some(creature) = creatures.get_mut(id) {
+            creature.position = next;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use world::tiles::Tile;
+
+    #[test]
+    fn migrants_arrive_and_walk_to_the_fortress() {
+        let map = Map::new(20, 20, GameTile::new(Tile::Ground));
+        let mut creatures = Creatures::new();
+        let mut citizens = Citizens::new();
+        let mut rng = Random::new(4);
+        let fortress = Point::new(10, 10);
+
+        let migrants = citizens.spawn_wave(&map, &mut creatures, &mut rng, 3);
+        assert_eq!(migrants.len(), 3);
+        assert_eq!(citizens.len(), 3);
+
+        for &id in &migrants {
+            let creature = creatures.get(id).unwrap();
+
+            assert_eq!(creature.faction, Faction::Fortress);
+            assert!(creature.skills.iter().count() > 0);
+            assert!(citizens.is_arriving(id));
+        }
+
+        for _ in 0..50 {
+            citizens.update(&map, &mut creatures, fortress);
+        }
+
+        for &id in &migrants {
+            assert!(!citizens.is_arriving(id));
+        }
+    }
+
+    #[test]
+    fn lasting_misery_ends_in_a_breakdown() {
+        let map = Map::new(20, 20, GameTile::new(Tile::Ground));
+        let mut creatures = Creatures::new();
+        let mut citizens = Citizens::new();
+        let mut rng = Random::new(4);
+
+        let id = citizens.spawn_wave(&map, &mut creatures, &mut rng, 1)[0];
+        citizens.get_mut(id).unwrap().personality.set(Facet::Patience, 0);
+
+        let mut events = Vec::new();
+        for _ in 0..1000 {
+            citizens.think(id, ThoughtKind::LostFriend);
+            events.extend(citizens.update_moods(&map, &mut creatures, &mut rng));
+
+            if !citizens.get(id).unwrap().can_work() {
+                break;
+            }
+        }
+
+        let citizen = citizens.get(id).unwrap();
+        assert!(!citizen.can_work());
+        assert_eq!(citizen.breakdown.unwrap().kind, BreakdownKind::Tantrum);
+        assert!(events.iter().any(|event| {
+            match *event {
+                GameEvent::BrokeDown {
+                    kind,
+                    ..
+                } => kind == BreakdownKind::Tantrum,
+                _ => false,
+            }
+        }));
+    }
+
+    #[test]
+    fn forgets_the_dead() {
+        let map = Map::new(20, 20, GameTile::new(Tile::Ground));
+        let mut creatures = Creatures::new();
+        let mut citizens = Citizens::new();
+        let mut rng = Random::new(4);
+
+        let migrants = citizens.spawn_wave(&map, &mut creatures, &mut rng, 2);
+        creatures.remove(migrants[0]);
+        citizens.update(&map, &mut creatures, Point::new(10, 10));
+
+        assert!(citizens.get(migrants[0]).is_none());
+        assert!(citizens.get(migrants[1]).is_some());
+    }
+}
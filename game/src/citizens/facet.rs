@@ -0,0 +1,36 @@
+/// One side of a citizen's personality.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Facet {
+    /// How well they face danger.
+    Bravery,
+
+    /// How easily they are made happy.
+    Cheerfulness,
+
+    /// How long it takes them to lose their temper.
+    Patience,
+
+    /// How much they enjoy hard work.
+    Industry,
+
+    /// How much they like being around others.
+    Sociability,
+}
+
+impl Facet {
+    /// Every facet, in order.
+    pub const ALL: [Facet; 5] =
+        [Facet::Bravery, Facet::Cheerfulness, Facet::Patience, Facet::Industry, Facet::Sociability];
+
+    /// Words describing someone who has a lot, or very little, of this facet.
+    /// Returned as high, then low.
+    pub fn descriptions(self) -> (&'static str, &'static str) {
+        match self {
+            Facet::Bravery => ("brave", "cowardly"),
+            Facet::Cheerfulness => ("cheerful", "gloomy"),
+            Facet::Patience => ("patient", "hot tempered"),
+            Facet::Industry => ("hard working", "lazy"),
+            Facet::Sociability => ("friendly", "solitary"),
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use util::random::Random;
+use world::calendar::Season;
+
+/// Below this happiness, no one wants to move to the fortress.
+const MIN_HAPPINESS: f32 = 0.25;
+
+/// Every wave has at least this many migrants, before happiness is counted.
+const BASE_WAVE_SIZE: u32 = 2;
+
+/// How much wealth draws in one more migrant.
+const WEALTH_PER_MIGRANT: u32 = 1000;
+
+/// The most migrants in a single wave.
+const MAX_WAVE_SIZE: u32 = 12;
+
+/// Decides how many migrants arrive at the start of a season.
+///
+/// No one travels in winter. Otherwise a rich fortress draws more migrants, and
+/// a happy fortress draws more still. Happiness is from 0.0 (miserable) to 1.0
+/// (joyous), with 0.5 being content.
+pub fn plan_wave(
+    rng: &mut Random,
+    season: Season,
+    wealth: u32,
+    happiness: f32,
+) -> u32 {
+    if season == Season::Winter || happiness < MIN_HAPPINESS {
+        return 0;
+    }
+
+    let base = BASE_WAVE_SIZE + wealth / WEALTH_PER_MIGRANT + rng.range(0, 2);
+    let size = (base as f32 * happiness * 2.0).round() as u32;
+
+    size.min(MAX_WAVE_SIZE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_migrants_in_winter_or_when_miserable() {
+        let mut rng = Random::new(1);
+
+        assert_eq!(plan_wave(&mut rng, Season::Winter, 5000, 1.0), 0);
+        assert_eq!(plan_wave(&mut rng, Season::Spring, 5000, 0.1), 0);
+    }
+
+    #[test]
+    fn wealth_and_happiness_draw_more_migrants() {
+        let mut rng = Random::new(1);
+
+        let poor = plan_wave(&mut rng, Season::Spring, 0, 0.5);
+        let rich = plan_wave(&mut rng, Season::Spring, 5000, 0.5);
+        let happy = plan_wave(&mut rng, Season::Spring, 5000, 1.0);
+
+        assert!(poor >= BASE_WAVE_SIZE);
+        assert!(rich > poor);
+        assert!(happy > rich);
+        assert!(happy <= MAX_WAVE_SIZE);
+    }
+}
@@ -0,0 +1,30 @@
+//! Citizens.
+//!
+//! The dwarves who live in the fortress. Who they are, what they are like,
+//! how they are feeling, and how new ones arrive.
+//!
+
+mod breakdown;
+mod citizen;
+mod citizens;
+mod facet;
+mod migration;
+mod mood;
+mod names;
+mod personality;
+mod thought;
+mod thought_kind;
+mod thoughts;
+
+pub use self::breakdown::Breakdown;
+pub use self::breakdown::BreakdownKind;
+pub use self::citizen::Citizen;
+pub use self::citizens::Citizens;
+pub use self::facet::Facet;
+pub use self::migration::plan_wave;
+pub use self::mood::Mood;
+pub use self::names::generate_name;
+pub use self::personality::Personality;
+pub use self::thought::Thought;
+pub use self::thought_kind::ThoughtKind;
+pub use self::thoughts::Thoughts;
@@ -0,0 +1,54 @@
+/// How a citizen is feeling overall.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Mood {
+    /// At their very lowest.
+    Miserable,
+
+    /// Things are getting them down.
+    Unhappy,
+
+    /// Nothing much is bothering them.
+    Content,
+
+    /// Things are going well.
+    Happy,
+
+    /// Things could not be better.
+    Ecstatic,
+}
+
+impl Mood {
+    /// Returns the mood for the value given.
+    /// Where 0 is content, above is happier, and below is sadder.
+    pub fn from_value(value: i32) -> Mood {
+        match value {
+            value if value >= 40 => Mood::Ecstatic,
+            value if value >= 15 => Mood::Happy,
+            value if value > -15 => Mood::Content,
+            value if value > -40 => Mood::Unhappy,
+            _ => Mood::Miserable,
+        }
+    }
+
+    /// How fast they work in this mood, as a percentage of normal.
+    pub fn work_rate(self) -> u32 {
+        match self {
+            Mood::Miserable => 60,
+            Mood::Unhappy => 85,
+            Mood::Content => 100,
+            Mood::Happy => 110,
+            Mood::Ecstatic => 125,
+        }
+    }
+
+    /// A human readable name for the mood.
+    pub fn name(self) -> &'static str {
+        match self {
+            Mood::Miserable => "miserable",
+            Mood::Unhappy => "unhappy",
+            Mood::Content => "content",
+            Mood::Happy => "happy",
+            Mood::Ecstatic => "ecstatic",
+        }
+    }
+}
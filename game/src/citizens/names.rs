@@ -0,0 +1,29 @@
+use util::random::Random;
+
+/// Given names.
+const FIRST_NAMES: [&str; 24] = [
+    "Urist", "Bomrek", "Datan", "Edem", "Fikod", "Goden", "Ingish", "Kadol", "Litast", "Mosus",
+    "Nish", "Olon", "Reg", "Sakzul", "Tobul", "Udib", "Vabok", "Zuglar", "Asmel", "Cog", "Dumed",
+    "Erith", "Iden", "Lolor",
+];
+
+/// The first half of a family name.
+const SURNAME_STARTS: [&str; 16] = [
+    "Bell", "Copper", "Deep", "Flint", "Gold", "Granite", "Hammer", "Iron", "Oak", "Rock",
+    "Silver", "Stone", "Anvil", "Coal", "Gem", "Boulder",
+];
+
+/// The second half of a family name.
+const SURNAME_ENDS: [&str; 16] = [
+    "beard", "delver", "fist", "forge", "hand", "helm", "shield", "vault", "carver", "mantle",
+    "hewer", "bellows", "tunnel", "axe", "cutter", "brow",
+];
+
+/// Makes up a dwarven name, like 'Urist Stonebeard'.
+pub fn generate_name(rng: &mut Random) -> String {
+    let first = rng.pick(&FIRST_NAMES).cloned().unwrap_or("Urist");
+    let start = rng.pick(&SURNAME_STARTS).cloned().unwrap_or("Stone");
+    let end = rng.pick(&SURNAME_ENDS).cloned().unwrap_or("beard");
+
+    format!("{} {}{}", first, start, end)
+}
@@ -0,0 +1,109 @@
+use util::random::Random;
+
+use citizens::Facet;
+
+/// The highest value for a facet. 50 is average.
+pub const MAX_FACET: u8 = 100;
+
+/// Facets this far from average are worth mentioning.
+const NOTABLE_DIFFERENCE: u8 = 25;
+
+/// What a citizen is like.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Personality {
+    /// How strong each facet is, from 0 to 100.
+    /// Held in the same order as `Facet::ALL`.
+    facets: [u8; 5],
+}
+
+impl Personality {
+    /// Creates a completely average personality.
+    pub fn average() -> Personality {
+        Personality {
+            facets: [MAX_FACET / 2; 5],
+        }
+    }
+
+    /// Creates a random personality.
+    ///
+    /// Each facet is the average of two rolls, so most people are closer to
+    /// average than not.
+    pub fn random(rng: &mut Random) -> Personality {
+        let mut personality = Personality::average();
+
+        for facet in personality.facets.iter_mut() {
+            let roll =
+                rng.range(0, u32::from(MAX_FACET) + 1) + rng.range(0, u32::from(MAX_FACET) + 1);
+            *facet = (roll / 2) as u8;
+        }
+
+        personality
+    }
+
+    /// Returns how strong the facet is, from 0 to 100.
+    pub fn get(
+        &self,
+        facet: Facet,
+    ) -> u8 {
+        self.facets[facet as usize]
+    }
+
+    /// Sets how strong the facet is.
+    /// Anything above 100 is capped.
+    pub fn set(
+        &mut self,
+        facet: Facet,
+        value: u8,
+    ) {
+        self.facets[facet as usize] = value.min(MAX_FACET);
+    }
+
+    /// Returns words for the facets which stand out.
+    /// i.e. 'brave', 'lazy'.
+    pub fn describe(&self) -> Vec<&'static str> {
+        let average = MAX_FACET / 2;
+
+        Facet::ALL
+            .iter()
+            .filter_map(|&facet| {
+                let value = self.get(facet);
+                let (high, low) = facet.descriptions();
+
+                if value >= average + NOTABLE_DIFFERENCE {
+                    Some(high)
+                } else if value <= average - NOTABLE_DIFFERENCE {
+                    Some(low)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn describes_standout_facets() {
+        let mut personality = Personality::average();
+        assert!(personality.describe().is_empty());
+
+        personality.set(Facet::Bravery, 90);
+        personality.set(Facet::Industry, 10);
+
+        assert_eq!(personality.describe(), vec!["brave", "lazy"]);
+    }
+
+    #[test]
+    fn random_facets_are_in_range() {
+        let mut rng = Random::new(2);
+
+        for _ in 0..100 {
+            let personality = Personality::random(&mut rng);
+
+            assert!(Facet::ALL.iter().all(|&facet| personality.get(facet) <= MAX_FACET));
+        }
+    }
+}
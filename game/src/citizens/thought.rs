@@ -0,0 +1,40 @@
+use citizens::ThoughtKind;
+
+/// A feeling a citizen has, which fades over time.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Thought {
+    /// What they are thinking about.
+    pub kind: ThoughtKind,
+
+    /// How much it changes their mood, when fresh.
+    /// This is the kind's effect, after their personality is taken into
+    /// account.
+    pub effect: i32,
+
+    /// How many ticks until it's forgotten.
+    pub remaining: u32,
+}
+
+impl Thought {
+    /// Creates a fresh thought.
+    pub fn new(
+        kind: ThoughtKind,
+        effect: i32,
+    ) -> Thought {
+        Thought {
+            kind: kind,
+            effect: effect,
+            remaining: kind.duration(),
+        }
+    }
+
+    /// How much the thought changes their mood right now.
+    ///
+    /// This fades evenly, from the full effect down to nothing, as the thought
+    /// gets older.
+    pub fn strength(&self) -> i32 {
+        let duration = self.kind.duration().max(1);
+
+        (i64::from(self.effect) * i64::from(self.remaining) / i64::from(duration)) as i32
+    }
+}
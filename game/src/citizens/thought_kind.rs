@@ -0,0 +1,68 @@
+/// Something which has happened to a citizen, which they have feelings
+/// about.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ThoughtKind {
+    /// They ate something really good.
+    AteFineMeal,
+
+    /// They made something of the very best quality.
+    CreatedMasterwork,
+
+    /// They got better at one of their skills.
+    ImprovedSkill,
+
+    /// They saw someone, or something, die.
+    SawDeath,
+
+    /// A fellow citizen has died.
+    LostFriend,
+
+    /// They were hurt in a fight.
+    WasInjured,
+
+    /// They had to sleep outside, in the rain.
+    SleptInRain,
+}
+
+impl ThoughtKind {
+    /// How much the thought changes their mood, when it's fresh.
+    /// Good thoughts are positive, and bad ones negative.
+    pub fn effect(self) -> i32 {
+        match self {
+            ThoughtKind::AteFineMeal => 10,
+            ThoughtKind::CreatedMasterwork => 25,
+            ThoughtKind::ImprovedSkill => 5,
+            ThoughtKind::SawDeath => -20,
+            ThoughtKind::LostFriend => -30,
+            ThoughtKind::WasInjured => -15,
+            ThoughtKind::SleptInRain => -10,
+        }
+    }
+
+    /// How many ticks it takes for the thought to fade away.
+    pub fn duration(self) -> u32 {
+        match self {
+            ThoughtKind::AteFineMeal => 2_000,
+            ThoughtKind::CreatedMasterwork => 6_000,
+            ThoughtKind::ImprovedSkill => 1_000,
+            ThoughtKind::SawDeath => 3_000,
+            ThoughtKind::LostFriend => 8_000,
+            ThoughtKind::WasInjured => 2_000,
+            ThoughtKind::SleptInRain => 1_500,
+        }
+    }
+
+    /// Describes the thought, for showing to the player.
+    /// i.e. 'ate a fine meal'.
+    pub fn describe(self) -> &'static str {
+        match self {
+            ThoughtKind::AteFineMeal => "ate a fine meal",
+            ThoughtKind::CreatedMasterwork => "created a masterwork",
+            ThoughtKind::ImprovedSkill => "got better at their work",
+            ThoughtKind::SawDeath => "saw a death",
+            ThoughtKind::LostFriend => "lost a friend",
+            ThoughtKind::WasInjured => "was injured",
+            ThoughtKind::SleptInRain => "slept outside in the rain",
+        }
+    }
+}
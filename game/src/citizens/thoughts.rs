@@ -0,0 +1,97 @@
+use std::slice::Iter;
+
+use citizens::Thought;
+use citizens::ThoughtKind;
+
+/// Everything a citizen is currently thinking about.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Thoughts {
+    /// The thoughts, oldest first.
+    thoughts: Vec<Thought>,
+}
+
+impl Thoughts {
+    /// Creates an empty mind.
+    pub fn new() -> Thoughts {
+        Thoughts {
+            thoughts: Vec::new(),
+        }
+    }
+
+    /// Adds a fresh thought.
+    ///
+    /// Thinking the same thing again doesn't stack. It replaces the old
+    /// thought, so it lasts longer, and becomes the most recent.
+    pub fn add(
+        &mut self,
+        kind: ThoughtKind,
+        effect: i32,
+    ) {
+        self.thoughts.retain(|thought| thought.kind != kind);
+        self.thoughts.push(Thought::new(kind, effect));
+    }
+
+    /// Ages every thought by one tick, and forgets those which have faded.
+    pub fn update(&mut self) {
+        for thought in self.thoughts.iter_mut() {
+            thought.remaining = thought.remaining.saturating_sub(1);
+        }
+
+        self.thoughts.retain(|thought| thought.remaining > 0);
+    }
+
+    /// Returns how much all of the thoughts change the mood, together.
+    pub fn total(&self) -> i32 {
+        self.thoughts.iter().map(|thought| thought.strength()).sum()
+    }
+
+    /// Returns the number of thoughts.
+    pub fn len(&self) -> usize {
+        self.thoughts.len()
+    }
+
+    /// Returns true if there is nothing on their mind.
+    pub fn is_empty(&self) -> bool {
+        self.thoughts.is_empty()
+    }
+
+    /// Iterates over every thought, oldest first.
+    pub fn iter(&self) -> Iter<'_, Thought> {
+        self.thoughts.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn thoughts_fade_away() {
+        let mut thoughts = Thoughts::new();
+        thoughts.add(ThoughtKind::SawDeath, ThoughtKind::SawDeath.effect());
+        assert_eq!(thoughts.total(), ThoughtKind::SawDeath.effect());
+
+        for _ in 0..ThoughtKind::SawDeath.duration() / 2 {
+            thoughts.update();
+        }
+        assert_eq!(thoughts.total(), ThoughtKind::SawDeath.effect() / 2);
+
+        for _ in 0..ThoughtKind::SawDeath.duration() / 2 {
+            thoughts.update();
+        }
+        assert!(thoughts.is_empty());
+        assert_eq!(thoughts.total(), 0);
+    }
+
+    #[test]
+    fn repeated_thoughts_do_not_stack() {
+        let mut thoughts = Thoughts::new();
+        thoughts.add(ThoughtKind::ImprovedSkill, 5);
+        thoughts.add(ThoughtKind::AteFineMeal, 10);
+        thoughts.add(ThoughtKind::ImprovedSkill, 5);
+
+        assert_eq!(thoughts.len(), 2);
+        assert_eq!(thoughts.total(), 15);
+        assert_eq!(thoughts.iter().last().unwrap().kind, ThoughtKind::ImprovedSkill);
+    }
+}
@@ -0,0 +1,157 @@
+use util::random::Random;
+
+use combat::AttackOutcome;
+use combat::Body;
+use combat::Equipment;
+use combat::Weapon;
+use skills::Skill;
+use skills::Skills;
+
+/// The chance to hit between two equally skilled creatures.
+const BASE_HIT_CHANCE: i32 = 60;
+
+/// How much each level of fighting, or dodging, changes the chance to hit.
+const HIT_CHANCE_PER_LEVEL: i32 = 3;
+
+/// Even the best fighter can miss, and the worst can hit.
+const MIN_HIT_CHANCE: i32 = 10;
+const MAX_HIT_CHANCE: i32 = 95;
+
+/// No shield can block everything.
+const MAX_BLOCK_CHANCE: u32 = 75;
+
+/// Works out one attack, and applies any wounds to the defender's body.
+///
+/// First the defender tries to dodge, then to block with a shield. If the
+/// blow lands a body part is picked, the armour takes off what it can, and
+/// the rest is dealt to the tissues of the part.
+pub fn resolve_attack(
+    rng: &mut Random,
+    attacker_skills: &Skills,
+    weapon: Weapon,
+    defender_skills: &Skills,
+    defender_equipment: &Equipment,
+    defender_body: &mut Body,
+) -> AttackOutcome {
+    let fighting = i32::from(attacker_skills.level(Skill::Fighting));
+    let dodging = if defender_body.can_walk() {
+        i32::from(defender_skills.level(Skill::Dodging))
+    } else {
+        0
+    };
+
+    let hit_chance = (BASE_HIT_CHANCE + (fighting - dodging) * HIT_CHANCE_PER_LEVEL)
+        .clamp(MIN_HIT_CHANCE, MAX_HIT_CHANCE);
+    if rng.range(0, 100) >= hit_chance as u32 {
+        return AttackOutcome::Dodged;
+    }
+
+    if let Some(shield) = defender_equipment.shield {
+        if defender_body.can_grasp() {
+            let shield_skill = u32::from(defender_skills.level(Skill::Shield));
+            let block_chance = (shield_skill * 3 + u32::from(shield.block)).min(MAX_BLOCK_CHANCE);
+
+            if rng.range(0, 100) < block_chance {
+                return AttackOutcome::Blocked;
+            }
+        }
+    }
+
+    let part_index = defender_body.pick_part(rng);
+    let part = defender_body.parts()[part_index].name;
+
+    let damage = weapon.damage + fighting as u32 / 4 + rng.range(0, weapon.damage / 2 + 1);
+    let damage = damage.saturating_sub(defender_equipment.protection(weapon.kind));
+    if damage == 0 {
+        return AttackOutcome::Deflected {
+            part: part,
+        };
+    }
+
+    let wound = defender_body.wound(part_index, damage, weapon.kind);
+
+    AttackOutcome::Hit {
+        part: part,
+        tissues: wound.tissues,
+        damage: damage,
+        bleeding: wound.bleeding,
+        disabled_part: wound.disabled_part,
+        killed: wound.killed,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use combat::Armour;
+    use combat::BodyPlan;
+    use combat::DamageKind;
+
+    #[test]
+    fn thick_armour_deflects_blows() {
+        let mut rng = Random::new(3);
+        let skills = Skills::new();
+        let equipment = Equipment {
+            weapon: None,
+            armour: Some(Armour {
+                name: "plate",
+                protection: 100,
+            }),
+            shield: None,
+        };
+        let mut body = Body::new(BodyPlan::Humanoid, 10);
+
+        for _ in 0..50 {
+            match resolve_attack(
+                &mut rng,
+                &skills,
+                Weapon::unarmed(),
+                &skills,
+                &equipment,
+                &mut body,
+            ) {
+                AttackOutcome::Dodged
+                | AttackOutcome::Deflected {
+                    ..
+                } => {},
+                outcome => panic!("unexpected outcome {:?}", outcome),
+            }
+        }
+
+        assert!(body.parts().iter().all(|part| part.is_healthy()));
+    }
+
+    #[test]
+    fn skilled_fighters_hit_more_often() {
+        let axe = Weapon {
+            name: "axe",
+            damage: 1,
+            kind: DamageKind::Edge,
+        };
+        let novice = Skills::new();
+        let mut master = Skills::new();
+        master.set_level(Skill::Fighting, 20);
+
+        let count_hits = |attacker: &Skills| {
+            let mut rng = Random::new(11);
+            let mut body = Body::new(BodyPlan::Humanoid, 250);
+
+            (0..200)
+                .filter(|_| {
+                    let outcome = resolve_attack(
+                        &mut rng,
+                        attacker,
+                        axe,
+                        &novice,
+                        &Equipment::none(),
+                        &mut body,
+                    );
+                    outcome != AttackOutcome::Dodged
+                })
+                .count()
+        };
+
+        assert!(count_hits(&master) > count_hits(&novice));
+    }
+}
@@ -0,0 +1,374 @@
+use util::random::Random;
+
+use combat::BodyPart;
+use combat::DamageKind;
+use combat::PartFunction;
+use combat::Tissue;
+use combat::TissueKind;
+
+/// The shapes of body that creatures can have.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BodyPlan {
+    /// Two arms, two legs, and a head. Like a dwarf.
+    Humanoid,
+
+    /// Four legs, a head, and a tail. Like a deer.
+    Quadruped,
+}
+
+impl BodyPlan {
+    /// Finds the body plan with the name given.
+    /// i.e. 'humanoid'.
+    pub fn from_name(name: &str) -> Option<BodyPlan> {
+        match name {
+            "humanoid" => Some(BodyPlan::Humanoid),
+            "quadruped" => Some(BodyPlan::Quadruped),
+            _ => None,
+        }
+    }
+}
+
+/// A wound which is still bleeding.
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct Bleed {
+    /// How much blood is lost each tick.
+    /// This goes down over time, as the wound clots.
+    rate: u8,
+}
+
+/// What happened when a body part was damaged.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WoundResult {
+    /// The tissues damaged, from the outside in.
+    pub tissues: Vec<TissueKind>,
+
+    /// How fast the new wound is bleeding.
+    pub bleeding: u8,
+
+    /// If the part stopped working because of this wound.
+    pub disabled_part: bool,
+
+    /// If this wound was fatal.
+    pub killed: bool,
+}
+
+/// A creature's body. It's parts, it's blood, and it's wounds.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Body {
+    /// Every part of the body.
+    parts: Vec<BodyPart>,
+
+    /// How much blood the body has when healthy.
+    max_blood: u32,
+
+    /// How much blood is left.
+    /// When this runs out, the creature dies.
+    blood: u32,
+
+    /// The wounds which are still bleeding.
+    bleeds: Vec<Bleed>,
+}
+
+impl Body {
+    /// Creates a new healthy body, from the plan given.
+    ///
+    /// The size scales the amount of blood, and the toughness of the tissues.
+    /// 10 is roughly the size of a dwarf.
+    pub fn new(
+        plan: BodyPlan,
+        size: u8,
+    ) -> Body {
+        let scale = u32::from(size.max(1));
+        let parts = match plan {
+            BodyPlan::Humanoid => humanoid_parts(scale),
+            BodyPlan::Quadruped => quadruped_parts(scale),
+        };
+
+        let max_blood = 10 * scale;
+
+        Body {
+            parts: parts,
+            max_blood: max_blood,
+            blood: max_blood,
+            bleeds: Vec::new(),
+        }
+    }
+
+    /// Returns all of the parts of this body.
+    pub fn parts(&self) -> &[BodyPart] {
+        &self.parts
+    }
+
+    /// Returns how much blood is left, as a fraction of a full amount.
+    pub fn blood_fraction(&self) -> f32 {
+        self.blood as f32 / self.max_blood as f32
+    }
+
+    /// Returns how much blood is being lost each tick.
+    pub fn bleeding_rate(&self) -> u32 {
+        self.bleeds.iter().map(|bleed| u32::from(bleed.rate)).sum()
+    }
+
+    /// Picks a random part to be hit.
+    /// Bigger parts are more likely to be picked.
+    pub fn pick_part(
+        &self,
+        rng: &mut Random,
+    ) -> usize {
+        let total = self.parts.iter().map(|part| u32::from(part.size)).sum::<u32>();
+        let mut roll = rng.range(0, total);
+
+        for (i, part) in self.parts.iter().enumerate() {
+            let size = u32::from(part.size);
+
+            if roll < size {
+                return i;
+            }
+
+            roll -= size;
+        }
+
+        0
+    }
+
+    /// Damages the body part given, working inwards through it's tissues.
+    pub fn wound(
+        &mut self,
+        part_index: usize,
+        damage: u32,
+        kind: DamageKind,
+    ) -> WoundResult {
+        let was_functional = self.parts[part_index].is_functional();
+        let mut remaining = damage;
+        let mut tissues = Vec::new();
+        let mut bleeding = 0;
+
+        for tissue in self.parts[part_index].tissues.iter_mut() {
+            if remaining == 0 {
+                break;
+            }
+
+            let before = tissue.health;
+            remaining = tissue.damage(remaining);
+            let taken = u32::from(before - tissue.health);
+
+            if taken > 0 {
+                tissues.push(tissue.kind);
+
+                if tissue.kind.bleeds() {
+                    bleeding += match kind {
+                        DamageKind::Edge => taken,
+                        DamageKind::Blunt => taken / 2,
+                    };
+                }
+            }
+        }
+
+        // Big creatures can lose more than the fastest a wound can bleed.
+        let bleeding = bleeding.min(u32::from(u8::MAX)) as u8;
+
+        if bleeding > 0 {
+            self.bleeds.push(Bleed {
+                rate: bleeding,
+            });
+        }
+
+        let part = &self.parts[part_index];
+
+        WoundResult {
+            tissues: tissues,
+            bleeding: bleeding,
+            disabled_part: was_functional && !part.is_functional(),
+            killed: self.is_dead(),
+        }
+    }
+
+    /// Loses blood from all of the open wounds.
+    /// The wounds clot a little each time this is called.
+    pub fn bleed(&mut self) {
+        let lost = self.bleeding_rate();
+        self.blood = self.blood.saturating_sub(lost);
+
+        for bleed in self.bleeds.iter_mut() {
+            bleed.rate = bleed.rate.saturating_sub(1);
+        }
+
+        self.bleeds.retain(|bleed| bleed.rate > 0);
+    }
+
+    /// Returns true if this body has stopped working.
+    pub fn is_dead(&self) -> bool {
+        self.blood == 0 || self.parts.iter().any(|part| part.is_fatally_wounded())
+    }
+
+    /// Returns true if enough legs work to be able to walk.
+    pub fn can_walk(&self) -> bool {
+        let (working, total) = self.count_function(PartFunction::Stance);
+
+        total > 0 && working * 2 >= total
+    }
+
+    /// Returns true if at least one hand works.
+    pub fn can_grasp(&self) -> bool {
+        let (working, _) = self.count_function(PartFunction::Grasp);
+
+        working > 0
+    }
+
+    /// Returns true if the eyes still work.
+    pub fn can_see(&self) -> bool {
+        let (working, _) = self.count_function(PartFunction::Sight);
+
+        working > 0
+    }
+
+    /// Returns how many parts with the function given still work, and how
+    /// many there are in total.
+    fn count_function(
+        &self,
+        function: PartFunction,
+    ) -> (usize, usize) {
+        let parts = self.parts.iter().filter(|part| part.function == function);
+        let total = parts.clone().count();
+        let working = parts.filter(|part| part.is_functional()).count();
+
+        (working, total)
+    }
+}
+
+/// Creates a set of tissues, scaled to the size of the creature.
+///
+/// Each entry is the kind of tissue, and it's thickness for a creature of
+/// size 10.
+fn tissues(
+    scale: u32,
+    layers: &[(TissueKind, u32)],
+) -> Vec<Tissue> {
+    layers
+        .iter()
+        .map(|&(kind, thickness)| Tissue::new(kind, (thickness * scale / 10).clamp(1, 255) as u8))
+        .collect()
+}
+
+fn limb(scale: u32) -> Vec<Tissue> {
+    tissues(
+        scale,
+        &[
+            (TissueKind::Skin, 3),
+            (TissueKind::Fat, 2),
+            (TissueKind::Muscle, 6),
+            (TissueKind::Bone, 6),
+        ],
+    )
+}
+
+fn head(scale: u32) -> Vec<Tissue> {
+    tissues(
+        scale,
+        &[
+            (TissueKind::Skin, 3),
+            (TissueKind::Muscle, 2),
+            (TissueKind::Bone, 8),
+            (TissueKind::Organ, 4),
+        ],
+    )
+}
+
+fn torso(scale: u32) -> Vec<Tissue> {
+    tissues(
+        scale,
+        &[
+            (TissueKind::Skin, 3),
+            (TissueKind::Fat, 5),
+            (TissueKind::Muscle, 8),
+            (TissueKind::Bone, 8),
+            (TissueKind::Organ, 6),
+        ],
+    )
+}
+
+fn humanoid_parts(scale: u32) -> Vec<BodyPart> {
+    vec![
+        BodyPart::new("head", 10, true, PartFunction::Sight, head(scale)),
+        BodyPart::new("upper body", 30, true, PartFunction::None, torso(scale)),
+        BodyPart::new("lower body", 20, false, PartFunction::None, limb(scale)),
+        BodyPart::new("left arm", 10, false, PartFunction::Grasp, limb(scale)),
+        BodyPart::new("right arm", 10, false, PartFunction::Grasp, limb(scale)),
+        BodyPart::new("left leg", 10, false, PartFunction::Stance, limb(scale)),
+        BodyPart::new("right leg", 10, false, PartFunction::Stance, limb(scale)),
+    ]
+}
+
+fn quadruped_parts(scale: u32) -> Vec<BodyPart> {
+    vec![
+        BodyPart::new("head", 10, true, PartFunction::Sight, head(scale)),
+        BodyPart::new("body", 40, true, PartFunction::None, torso(scale)),
+        BodyPart::new("front left leg", 10, false, PartFunction::Stance, limb(scale)),
+        BodyPart::new("front right leg", 10, false, PartFunction::Stance, limb(scale)),
+        BodyPart::new("rear left leg", 10, false, PartFunction::Stance, limb(scale)),
+        BodyPart::new("rear right leg", 10, false, PartFunction::Stance, limb(scale)),
+        BodyPart::new("tail", 5, false, PartFunction::None, limb(scale)),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_body_is_healthy() {
+        let body = Body::new(BodyPlan::Humanoid, 10);
+
+        assert!(!body.is_dead());
+        assert!(body.can_walk());
+        assert!(body.can_grasp());
+        assert!(body.can_see());
+        assert!(body.parts().iter().all(|part| part.is_healthy()));
+    }
+
+    #[test]
+    fn breaking_both_legs_stops_walking() {
+        let mut body = Body::new(BodyPlan::Humanoid, 10);
+
+        let result = body.wound(5, 100, DamageKind::Blunt);
+        assert!(result.disabled_part);
+        assert!(body.can_walk());
+
+        body.wound(6, 100, DamageKind::Blunt);
+        assert!(!body.can_walk());
+        assert!(!body.is_dead());
+    }
+
+    #[test]
+    fn destroying_the_head_kills() {
+        let mut body = Body::new(BodyPlan::Humanoid, 10);
+        let result = body.wound(0, 100, DamageKind::Edge);
+
+        assert!(result.killed);
+        assert!(body.is_dead());
+    }
+
+    #[test]
+    fn bleeding_clots_over_time() {
+        let mut body = Body::new(BodyPlan::Humanoid, 10);
+        let result = body.wound(3, 8, DamageKind::Edge);
+        assert!(result.bleeding > 0);
+
+        for _ in 0..100 {
+            body.bleed();
+        }
+
+        assert_eq!(body.bleeding_rate(), 0);
+        assert!(body.blood_fraction() < 1.0);
+        assert!(!body.is_dead());
+    }
+
+    #[test]
+    fn huge_wounds_bleed_as_fast_as_a_wound_can() {
+        let mut body = Body::new(BodyPlan::Quadruped, 255);
+        let result = body.wound(1, 10_000, DamageKind::Edge);
+
+        assert_eq!(result.bleeding, u8::MAX);
+        assert_eq!(body.bleeding_rate(), u32::from(u8::MAX));
+    }
+}
@@ -0,0 +1,81 @@
+use combat::Tissue;
+use combat::TissueKind;
+
+/// What a body part is used for.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PartFunction {
+    /// Nothing special.
+    None,
+
+    /// Holding things, like weapons.
+    Grasp,
+
+    /// Standing, and walking.
+    Stance,
+
+    /// Seeing.
+    Sight,
+}
+
+/// A part of a body. Like an arm, or a head.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BodyPart {
+    /// The name of the part, like 'left arm'.
+    pub name: &'static str,
+
+    /// How big the part is, compared to the rest of the body.
+    /// Bigger parts are hit more often.
+    pub size: u8,
+
+    /// If destroying the organs in this part kills the creature.
+    pub is_vital: bool,
+
+    /// What the part is used for.
+    pub function: PartFunction,
+
+    /// The layers of this part, from the outside in.
+    pub tissues: Vec<Tissue>,
+}
+
+impl BodyPart {
+    /// Trivial constructor.
+    pub fn new(
+        name: &'static str,
+        size: u8,
+        is_vital: bool,
+        function: PartFunction,
+        tissues: Vec<Tissue>,
+    ) -> BodyPart {
+        BodyPart {
+            name: name,
+            size: size,
+            is_vital: is_vital,
+            function: function,
+            tissues: tissues,
+        }
+    }
+
+    /// Returns true if this part can still do it's job.
+    ///
+    /// A part with destroyed muscle, or broken bones, doesn't work.
+    pub fn is_functional(&self) -> bool {
+        !self.tissues.iter().any(|tissue| {
+            tissue.is_destroyed()
+                && (tissue.kind == TissueKind::Muscle || tissue.kind == TissueKind::Bone)
+        })
+    }
+
+    /// Returns true if this is a vital part, and it's vital organs are gone.
+    pub fn is_fatally_wounded(&self) -> bool {
+        self.is_vital
+            && self
+                .tissues
+                .iter()
+                .any(|tissue| tissue.kind == TissueKind::Organ && tissue.is_destroyed())
+    }
+
+    /// Returns true if every tissue is undamaged.
+    pub fn is_healthy(&self) -> bool {
+        self.tissues.iter().all(|tissue| tissue.health == tissue.max_health)
+    }
+}
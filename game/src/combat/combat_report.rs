@@ -0,0 +1,126 @@
+use combat::TissueKind;
+
+/// What happened to a single attack.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AttackOutcome {
+    /// The defender got out of the way.
+    Dodged,
+
+    /// The defender blocked it with their shield.
+    Blocked,
+
+    /// It hit, but the armour stopped all of the damage.
+    Deflected {
+        /// The part that was hit.
+        part: &'static str,
+    },
+
+    /// It hit, and hurt.
+    Hit {
+        /// The part that was hit.
+        part: &'static str,
+
+        /// The tissues damaged, from the outside in.
+        tissues: Vec<TissueKind>,
+
+        /// How much damage got through the armour.
+        damage: u32,
+
+        /// How fast the new wound bleeds.
+        bleeding: u8,
+
+        /// If the part stopped working.
+        disabled_part: bool,
+
+        /// If the defender died.
+        killed: bool,
+    },
+}
+
+/// A record of one creature attacking another.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CombatReport {
+    /// The name of who attacked.
+    pub attacker: String,
+
+    /// The name of who was attacked.
+    pub defender: String,
+
+    /// The name of what they attacked with.
+    pub weapon: &'static str,
+
+    /// What happened.
+    pub outcome: AttackOutcome,
+}
+
+impl CombatReport {
+    /// Returns true if the defender was killed by this attack.
+    pub fn is_fatal(&self) -> bool {
+        match self.outcome {
+            AttackOutcome::Hit {
+                killed,
+                ..
+            } => killed,
+            _ => false,
+        }
+    }
+
+    /// Describes the attack, for showing to the player.
+    /// i.e. 'Urist strikes the goblin in the left arm with the axe, tearing the
+    /// skin and fracturing the bone!'
+    pub fn describe(&self) -> String {
+        match self.outcome {
+            AttackOutcome::Dodged => {
+                format!(
+                    "{} strikes at {} with the {}, but it is dodged.",
+                    self.attacker, self.defender, self.weapon
+                )
+            },
+            AttackOutcome::Blocked => {
+                format!(
+                    "{} strikes at {} with the {}, but it is blocked.",
+                    self.attacker, self.defender, self.weapon
+                )
+            },
+            AttackOutcome::Deflected {
+                part,
+            } => {
+                format!(
+                    "{} strikes {} in the {} with the {}, but it is deflected by the armour.",
+                    self.attacker, self.defender, part, self.weapon
+                )
+            },
+            AttackOutcome::Hit {
+                part,
+                ref tissues,
+                disabled_part,
+                killed,
+                ..
+            } => {
+                let mut text = format!(
+                    "{} strikes {} in the {} with the {}",
+                    self.attacker, self.defender, part, self.weapon
+                );
+
+                let damage = tissues
+                    .iter()
+                    .map(|tissue| format!("{} the {}", tissue.damage_verb(), tissue.name()))
+                    .collect::<Vec<String>>();
+                if !damage.is_empty() {
+                    text.push_str(", ");
+                    text.push_str(&damage.join(" and "));
+                }
+
+                if killed {
+                    text.push_str(format!(", killing {}!", self.defender).as_str());
+                } else if disabled_part {
+                    text.push_str(format!(". The {} is disabled!", part).as_str());
+                } else {
+                    text.push('!');
+                }
+
+                text
+            },
+        }
+    }
+}
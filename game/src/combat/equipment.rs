@@ -0,0 +1,96 @@
+/// How a weapon damages what it hits.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DamageKind {
+    /// Cuts through tissue, and causes lots of bleeding.
+    Edge,
+
+    /// Crushes tissue. Bleeds less, but armour is less help against it.
+    Blunt,
+}
+
+/// Something held to attack with.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Weapon {
+    /// The name of the weapon, like 'axe'.
+    pub name: &'static str,
+
+    /// How much damage a blow does, before armour.
+    pub damage: u32,
+
+    /// How the weapon damages things.
+    pub kind: DamageKind,
+}
+
+impl Weapon {
+    /// What a creature fights with when it has no weapon.
+    pub fn unarmed() -> Weapon {
+        Weapon {
+            name: "fist",
+            damage: 3,
+            kind: DamageKind::Blunt,
+        }
+    }
+}
+
+/// Something worn to protect the body.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Armour {
+    /// The name of the armour, like 'mail shirt'.
+    pub name: &'static str,
+
+    /// How much damage is taken off of each blow.
+    pub protection: u32,
+}
+
+/// Something held to block blows with.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Shield {
+    /// The name of the shield, like 'buckler'.
+    pub name: &'static str,
+
+    /// How much easier blocking is with this shield.
+    pub block: u8,
+}
+
+/// Everything a creature is holding and wearing.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Equipment {
+    /// What it attacks with. `None` means it uses it's fists.
+    pub weapon: Option<Weapon>,
+
+    /// What it's wearing.
+    pub armour: Option<Armour>,
+
+    /// What it blocks with.
+    pub shield: Option<Shield>,
+}
+
+impl Equipment {
+    /// Creates a set of equipment with nothing in it.
+    pub fn none() -> Equipment {
+        Equipment {
+            weapon: None,
+            armour: None,
+            shield: None,
+        }
+    }
+
+    /// Returns the weapon being used, or fists if there is no weapon.
+    pub fn weapon(&self) -> Weapon {
+        self.weapon.unwrap_or_else(Weapon::unarmed)
+    }
+
+    /// Returns how much damage the armour takes off of a blow of the kind
+    /// given.
+    pub fn protection(
+        &self,
+        kind: DamageKind,
+    ) -> u32 {
+        let protection = self.armour.map(|armour| armour.protection).unwrap_or(0);
+
+        match kind {
+            DamageKind::Edge => protection,
+            DamageKind::Blunt => protection / 2,
+        }
+    }
+}
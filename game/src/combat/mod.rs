@@ -0,0 +1,27 @@
+//! Combat.
+//!
+//! Bodies, wounds, equipment, and working out what happens when one creature
+//! attacks another.
+//!
+
+mod attack;
+mod body;
+mod body_part;
+mod combat_report;
+mod equipment;
+mod tissue;
+
+pub use self::attack::resolve_attack;
+pub use self::body::Body;
+pub use self::body::BodyPlan;
+pub use self::body_part::BodyPart;
+pub use self::body_part::PartFunction;
+pub use self::combat_report::AttackOutcome;
+pub use self::combat_report::CombatReport;
+pub use self::equipment::Armour;
+pub use self::equipment::DamageKind;
+pub use self::equipment::Equipment;
+pub use self::equipment::Shield;
+pub use self::equipment::Weapon;
+pub use self::tissue::Tissue;
+pub use self::tissue::TissueKind;
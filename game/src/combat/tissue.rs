@@ -0,0 +1,98 @@
+/// The different layers a body part can be made of.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TissueKind {
+    /// The outer layer.
+    Skin,
+
+    /// Padding under the skin.
+    Fat,
+
+    /// What makes the part move.
+    Muscle,
+
+    /// What holds the part together.
+    Bone,
+
+    /// Something vital, like a brain or a heart.
+    Organ,
+}
+
+impl TissueKind {
+    /// Returns true if damaging this tissue will make the wound bleed.
+    pub fn bleeds(self) -> bool {
+        match self {
+            TissueKind::Skin | TissueKind::Fat | TissueKind::Bone => false,
+            TissueKind::Muscle | TissueKind::Organ => true,
+        }
+    }
+
+    /// The verb used to describe damaging this tissue.
+    /// i.e. 'tearing the skin', or 'fracturing the bone'.
+    pub fn damage_verb(self) -> &'static str {
+        match self {
+            TissueKind::Skin => "tearing",
+            TissueKind::Fat => "bruising",
+            TissueKind::Muscle => "tearing",
+            TissueKind::Bone => "fracturing",
+            TissueKind::Organ => "rupturing",
+        }
+    }
+
+    /// A human readable name for the tissue.
+    pub fn name(self) -> &'static str {
+        match self {
+            TissueKind::Skin => "skin",
+            TissueKind::Fat => "fat",
+            TissueKind::Muscle => "muscle",
+            TissueKind::Bone => "bone",
+            TissueKind::Organ => "organ",
+        }
+    }
+}
+
+/// A single layer of a body part, and how healthy it is.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Tissue {
+    /// What this layer is.
+    pub kind: TissueKind,
+
+    /// How much damage it can take before it's destroyed.
+    pub max_health: u8,
+
+    /// How much more damage it can take.
+    /// When this is 0 the tissue is destroyed.
+    pub health: u8,
+}
+
+impl Tissue {
+    /// Creates a new undamaged tissue.
+    pub fn new(
+        kind: TissueKind,
+        max_health: u8,
+    ) -> Tissue {
+        Tissue {
+            kind: kind,
+            max_health: max_health,
+            health: max_health,
+        }
+    }
+
+    /// Returns true if this tissue has taken so much damage it no longer
+    /// works.
+    pub fn is_destroyed(&self) -> bool {
+        self.health == 0
+    }
+
+    /// Applies the damage to this tissue.
+    /// Returns how much damage was left over, after this tissue took it's
+    /// share.
+    pub fn damage(
+        &mut self,
+        amount: u32,
+    ) -> u32 {
+        let taken = amount.min(u32::from(self.health));
+        self.health -= taken as u8;
+
+        amount - taken
+    }
+}
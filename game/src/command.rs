@@ -0,0 +1,150 @@
+use world::calendar::Season;
+
+use creatures::CreatureId;
+use jobs::JobKind;
+use jobs::Labour;
+
+/// Something the player has asked the game to do.
+///
+/// All changes from the outside world go through commands. This allows the
+/// game to log them, and replay them later.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GameCommand {
+    /// Moves the player by the amount given.
+    /// If they cannot move there then nothing happens.
+    MovePlayer {
+        /// How far to move across.
+        x: i32,
+
+        /// How far to move down.
+        y: i32,
+    },
+
+    /// Adds a job to the end of the job queue.
+    QueueJob {
+        /// The job to add.
+        kind: JobKind,
+    },
+
+    /// Turns a labour on, or off, for a citizen.
+    SetLabour {
+        /// The citizen.
+        creature: CreatureId,
+
+        /// The labour to change.
+        labour: Labour,
+
+        /// If they should do it.
+        enabled: bool,
+    },
+
+    /// Offers a deal to the merchants at the trade depot.
+    ///
+    /// Goods are picked with masks, where bit 0 is the first item, bit 1 the
+    /// second, and so on.
+    Trade {
+        /// The fortress' items being offered.
+        offered: u64,
+
+        /// The merchants' goods being asked for.
+        requested: u64,
+    },
+
+    /// Chooses what to plant on a farm plot, during a season.
+    SetCrop {
+        /// Across.
+        x: u32,
+
+        /// Down.
+        y: u32,
+
+        /// The season it's being chosen for.
+        season: Season,
+
+        /// The crop to plant, as an index into the farms' crops.
+        /// None leaves the plot empty.
+        crop: Option<u8>,
+    },
+}
+
+impl GameCommand {
+    /// Converts this command into a line of text, for saving.
+    /// You can get the command back by passing the text to `decode`.
+    pub fn encode(&self) -> String {
+        match *self {
+            GameCommand::MovePlayer {
+                x,
+                y,
+            } => format!("move_player {} {}", x, y),
+            GameCommand::QueueJob {
+                kind,
+            } => format!("queue_job {}", kind.encode()),
+            GameCommand::SetLabour {
+                creature,
+                labour,
+                enabled,
+            } => format!("set_labour {} {} {}", creature.0, labour.name(), enabled as u8),
+            GameCommand::Trade {
+                offered,
+                requested,
+            } => format!("trade {} {}", offered, requested),
+            GameCommand::SetCrop {
+                x,
+                y,
+                season,
+                crop,
+            } => {
+                let crop = crop.map(|crop| crop.to_string()).unwrap_or_else(|| "none".to_string());
+
+                format!("set_crop {} {} {} {}", x, y, season.name(), crop)
+            },
+        }
+    }
+
+    /// Converts the words from an encoded command back into a command.
+    /// Returns None if the words are not a valid command.
+    pub fn decode(words: &[&str]) -> Option<GameCommand> {
+        match words {
+            ["move_player", x, y] => {
+                Some(GameCommand::MovePlayer {
+                    x: x.parse().ok()?,
+                    y: y.parse().ok()?,
+                })
+            },
+            ["queue_job", kind @ ..] => {
+                Some(GameCommand::QueueJob {
+                    kind: JobKind::decode(kind)?,
+                })
+            },
+            ["set_labour", creature, labour, enabled] => {
+                Some(GameCommand::SetLabour {
+                    creature: CreatureId(creature.parse().ok()?),
+                    labour: Labour::from_name(labour)?,
+                    enabled: match *enabled {
+                        "1" => true,
+                        "0" => false,
+                        _ => return None,
+                    },
+                })
+            },
+            ["trade", offered, requested] => {
+                Some(GameCommand::Trade {
+                    offered: offered.parse().ok()?,
+                    requested: requested.parse().ok()?,
+                })
+            },
+            ["set_crop", x, y, season, crop] => {
+                Some(GameCommand::SetCrop {
+                    x: x.parse().ok()?,
+                    y: y.parse().ok()?,
+                    season: Season::from_name(season)?,
+                    crop: match *crop {
+                        "none" => None,
+                        crop => Some(crop.parse().ok()?),
+                    },
+                })
+            },
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use util::shapes::Point;
+
+use combat::Body;
+use combat::Equipment;
+use creatures::CreatureId;
+use creatures::Faction;
+use skills::Skills;
+
+/// Something alive, which lives in the world.
+#[derive(Clone, Debug)]
+pub struct Creature {
+    /// Who this creature is.
+    pub id: CreatureId,
+
+    /// What to call the creature when telling the player about it.
+    pub name: String,
+
+    /// The character it is drawn with.
+    pub symbol: char,
+
+    /// Who it is loyal to.
+    pub faction: Faction,
+
+    /// Where it is in the world.
+    pub position: Point<u32>,
+
+    /// It's body, and any wounds it has.
+    pub body: Body,
+
+    /// How good it is at things.
+    pub skills: Skills,
+
+    /// What it is holding and wearing.
+    pub equipment: Equipment,
+}
+
+impl Creature {
+    /// Returns true if this creature has died.
+    pub fn is_dead(&self) -> bool {
+        self.body.is_dead()
+    }
+}
@@ -0,0 +1,5 @@
+/// Uniquely identifies a creature, for the whole of a game.
+///
+/// Ids are never reused, even after the creature has died.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CreatureId(pub u32);
@@ -0,0 +1,169 @@
+use std::slice::Iter;
+use std::slice::IterMut;
+
+use util::shapes::Point;
+
+use combat::Body;
+use combat::Equipment;
+use creatures::Creature;
+use creatures::CreatureId;
+use creatures::Faction;
+use skills::Skills;
+
+/// Every creature in the world.
+pub struct Creatures {
+    /// The creatures, in the order they were spawned.
+    creatures: Vec<Creature>,
+
+    /// The id for the next creature spawned.
+    next_id: u32,
+}
+
+impl Creatures {
+    /// Creates an empty collection.
+    pub fn new() -> Creatures {
+        Creatures {
+            creatures: Vec::new(),
+            next_id: 0,
+        }
+    }
+
+    /// Adds a new creature, with no skills or equipment.
+    /// Returns the id of the new creature.
+    pub fn spawn(
+        &mut self,
+        name: String,
+        symbol: char,
+        faction: Faction,
+        position: Point<u32>,
+        body: Body,
+    ) -> CreatureId {
+        let id = CreatureId(self.next_id);
+        self.next_id += 1;
+
+        self.creatures.push(Creature {
+            id: id,
+            name: name,
+            symbol: symbol,
+            faction: faction,
+            position: position,
+            body: body,
+            skills: Skills::new(),
+            equipment: Equipment::none(),
+        });
+
+        id
+    }
+
+    /// Removes the creature, and returns it.
+    pub fn remove(
+        &mut self,
+        id: CreatureId,
+    ) -> Option<Creature> {
+        let index = self.index_of(id)?;
+
+        Some(self.creatures.remove(index))
+    }
+
+    /// Finds the creature with the id given.
+    pub fn get(
+        &self,
+        id: CreatureId,
+    ) -> Option<&Creature> {
+        self.index_of(id).map(|index| &self.creatures[index])
+    }
+
+    /// Finds the creature with the id given, for changing.
+    pub fn get_mut(
+        &mut self,
+        id: CreatureId,
+    ) -> Option<&mut Creature> {
+        let index = self.index_of(id)?;
+
+        Some(&mut self.creatures[index])
+    }
+
+    /// Returns the creature standing at the position given, if there is one.
+    pub fn at(
+        &self,
+        x: u32,
+        y: u32,
+    ) -> Option<&Creature> {
+        self.creatures.iter().find(|creature| creature.position.x == x && creature.position.y == y)
+    }
+
+    /// Returns the number of creatures.
+    pub fn len(&self) -> usize {
+        self.creatures.len()
+    }
+
+    /// Returns true if there are no creatures.
+    pub fn is_empty(&self) -> bool {
+        self.creatures.is_empty()
+    }
+
+    /// Iterates over every creature, in the order they were spawned.
+    pub fn iter(&self) -> Iter<'_, Creature> {
+        self.creatures.iter()
+    }
+
+    /// Iterates over every creature, for changing.
+    pub fn iter_mut(&mut self) -> IterMut<'_, Creature> {
+        self.creatures.iter_mut()
+    }
+
+    /// Creatures are kept in id order, so they can be found with a search.
+    fn index_of(
+        &self,
+        id: CreatureId,
+    ) -> Option<usize> {
+        self.creatures.binary_search_by_key(&id, |creature| creature.id).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use combat::BodyPlan;
+
+    fn spawn(
+        creatures: &mut Creatures,
+        name: &str,
+    ) -> CreatureId {
+        creatures.spawn(
+            name.to_string(),
+            'd',
+            Faction::Wild,
+            Point::new(0, 0),
+            Body::new(BodyPlan::Quadruped, 10),
+        )
+    }
+
+    #[test]
+    fn ids_are_not_reused() {
+        let mut creatures = Creatures::new();
+        let deer = spawn(&mut creatures, "deer");
+        creatures.remove(deer);
+        let elk = spawn(&mut creatures, "elk");
+
+        assert_ne!(deer, elk);
+        assert!(creatures.get(deer).is_none());
+        assert_eq!(creatures.get(elk).unwrap().name, "elk");
+    }
+
+    #[test]
+    fn finds_creatures_after_removals() {
+        let mut creatures = Creatures::new();
+        let ids = (0..5)
+            .map(|i| spawn(&mut creatures, &format!("deer {}", i)))
+            .collect::<Vec<CreatureId>>();
+
+        creatures.remove(ids[1]);
+        creatures.remove(ids[3]);
+
+        assert_eq!(creatures.len(), 3);
+        assert_eq!(creatures.get(ids[4]).unwrap().name, "deer 4");
+        assert_eq!(creatures.get(ids[2]).unwrap().name, "deer 2");
+    }
+}
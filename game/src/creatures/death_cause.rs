@@ -0,0 +1,25 @@
+/// How a creature died.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DeathCause {
+    /// Killed outright in combat.
+    Slain {
+        /// The name of who killed them.
+        by: String,
+    },
+
+    /// Lost too much blood from their wounds.
+    BledOut,
+}
+
+impl DeathCause {
+    /// Describes the death, to follow on from the creature's name.
+    /// i.e. 'has been slain by Urist'.
+    pub fn describe(&self) -> String {
+        match *self {
+            DeathCause::Slain {
+                ref by,
+            } => format!("has been slain by {}", by),
+            DeathCause::BledOut => "has bled to death".to_string(),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+/// Who a creature is loyal to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Faction {
+    /// A member of the player's fortress.
+    Fortress,
+
+    /// Animals, and other wildlife. Not loyal to anyone.
+    Wild,
+
+    /// Wants to do harm to the fortress.
+    Hostile,
+
+    /// Friendly outsiders, like merchants.
+    Visitor,
+}
+
+impl Faction {
+    /// Returns true if the two factions will fight each other.
+    pub fn is_hostile_to(
+        self,
+        other: Faction,
+    ) -> bool {
+        match (self, other) {
+            (Faction::Hostile, Faction::Fortress) | (Faction::Fortress, Faction::Hostile) => true,
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,16 @@
+//! Creatures.
+//!
+//! Everything alive in the world, other than the player.
+//!
+
+mod creature;
+mod creature_id;
+mod creatures;
+mod death_cause;
+mod faction;
+
+pub use self::creature::Creature;
+pub use self::creature_id::CreatureId;
+pub use self::creatures::Creatures;
+pub use self::death_cause::DeathCause;
+pub use self::faction::Faction;
@@ -0,0 +1,284 @@
+//! Reading the data files, like `data/species.txt`.
+//!
+//! Each file is a list of entries. Every entry starts with a line of
+//! `<kind> <name>`, followed by one line for each of its properties, as
+//! `<property> <value>`. Blank lines, and lines starting with '#', are
+//! skipped.
+//!
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// The reasons why a data file cannot be read.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DataFileError {
+    /// A property was given before the first entry.
+    NoEntry {
+        /// The kind of entry in the file.
+        /// i.e. 'crop'.
+        kind: &'static str,
+
+        /// The line number, starting from 1.
+        line: usize,
+    },
+
+    /// A property which doesn't exist.
+    UnknownProperty {
+        /// The line number, starting from 1.
+        line: usize,
+
+        /// The name of the property.
+        property: String,
+    },
+
+    /// A property had a value which couldn't be understood.
+    InvalidValue {
+        /// The line number, starting from 1.
+        line: usize,
+
+        /// The name of the property.
+        property: String,
+    },
+
+    /// An entry is missing a property it must have.
+    MissingProperty {
+        /// The kind of entry.
+        /// i.e. 'crop'.
+        kind: &'static str,
+
+        /// The entry's name.
+        name: String,
+
+        /// The name of the property.
+        property: &'static str,
+    },
+}
+
+impl fmt::Display for DataFileError {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        match *self {
+            DataFileError::NoEntry {
+                kind,
+                line,
+            } => write!(f, "line {}, property given before any {}", line, kind),
+            DataFileError::UnknownProperty {
+                line,
+                ref property,
+            } => write!(f, "line {}, unknown property '{}'", line, property),
+            DataFileError::InvalidValue {
+                line,
+                ref property,
+            } => write!(f, "line {}, invalid value for '{}'", line, property),
+            DataFileError::MissingProperty {
+                kind,
+                ref name,
+                property,
+            } => write!(f, "{} '{}' is missing '{}'", kind, name, property),
+        }
+    }
+}
+
+impl Error for DataFileError {
+}
+
+/// An entry which is part way through being read.
+///
+/// Each kind of data file implements this, to say which properties its
+/// entries have.
+pub trait PartialEntry: Sized {
+    /// What is made once the entry has been read.
+    type Entry;
+
+    /// The word at the start of each entry.
+    /// i.e. 'crop'.
+    const KIND: &'static str;
+
+    /// Starts reading an entry with the name given.
+    fn new(name: &str) -> Self;
+
+    /// Reads one property, from the line number given.
+    fn set(
+        &mut self,
+        line: usize,
+        property: &str,
+        value: &str,
+    ) -> Result<(), DataFileError>;
+
+    /// Makes the entry, once all of its properties have been read.
+    fn finish(self) -> Result<Self::Entry, DataFileError>;
+}
+
+/// Reads every entry from the text given.
+pub fn parse_entries<P: PartialEntry>(text: &str) -> Result<Vec<P::Entry>, DataFileError> {
+    let mut entries = Vec::new();
+    let mut current: Option<P> = None;
+
+    for (i, line) in text.lines().enumerate() {
+        let line_number = i + 1;
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (property, value) = match line.find(char::is_whitespace) {
+            Some(split) => (&line[..split], line[split..].trim()),
+            None => (line, ""),
+        };
+
+        if property == P::KIND {
+            if let Some(partial) = current.take() {
+                entries.push(partial.finish()?);
+            }
+
+            current = Some(P::new(value));
+            continue;
+        }
+
+        match current {
+            Some(ref mut partial) => partial.set(line_number, property, value)?,
+            None => {
+                return Err(DataFileError::NoEntry {
+                    kind: P::KIND,
+                    line: line_number,
+                })
+            },
+        }
+    }
+
+    if let Some(partial) = current.take() {
+        entries.push(partial.finish()?);
+    }
+
+    Ok(entries)
+}
+
+/// Parses a single character, like the symbol for an entry.
+pub fn parse_char(value: &str) -> Option<char> {
+    let mut chars = value.chars();
+
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
+}
+
+/// Parses a number, of whichever type is wanted.
+pub fn parse_number<N: FromStr>(value: &str) -> Option<N> {
+    value.parse().ok()
+}
+
+/// Parses either 'yes' or 'no'.
+pub fn parse_yes_no(value: &str) -> Option<bool> {
+    match value {
+        "yes" => Some(true),
+        "no" => Some(false),
+        _ => None,
+    }
+}
+
+/// Parses a space separated list, where every item must be understood.
+pub fn parse_list<T, F>(
+    value: &str,
+    parse_item: F,
+) -> Option<Vec<T>>
+where
+    F: Fn(&str) -> Option<T>,
+{
+    value.split_whitespace().map(parse_item).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Pair {
+        name: String,
+        value: Option<u32>,
+    }
+
+    impl PartialEntry for Pair {
+        type Entry = (String, u32);
+
+        const KIND: &'static str = "pair";
+
+        fn new(name: &str) -> Pair {
+            Pair {
+                name: name.to_string(),
+                value: None,
+            }
+        }
+
+        fn set(
+            &mut self,
+            line: usize,
+            property: &str,
+            value: &str,
+        ) -> Result<(), DataFileError> {
+            let value = parse_number(value).ok_or_else(|| {
+                DataFileError::InvalidValue {
+                    line: line,
+                    property: property.to_string(),
+                }
+            })?;
+
+            self.value = Some(value);
+            Ok(())
+        }
+
+        fn finish(self) -> Result<(String, u32), DataFileError> {
+            match self.value {
+                Some(value) => Ok((self.name, value)),
+                None => {
+                    Err(DataFileError::MissingProperty {
+                        kind: Self::KIND,
+                        name: self.name,
+                        property: "value",
+                    })
+                },
+            }
+        }
+    }
+
+    #[test]
+    fn reads_each_entry() {
+        let text = "# Pairs.\npair one\nvalue 1\n\npair two three\nvalue 23\n";
+
+        assert_eq!(
+            parse_entries::<Pair>(text),
+            Ok(vec![("one".to_string(), 1), ("two three".to_string(), 23)])
+        );
+        assert_eq!(
+            parse_entries::<Pair>("\nvalue 1\n"),
+            Err(DataFileError::NoEntry {
+                kind: "pair",
+                line: 2,
+            })
+        );
+        assert_eq!(
+            parse_entries::<Pair>("pair one\nvalue x\n"),
+            Err(DataFileError::InvalidValue {
+                line: 2,
+                property: "value".to_string(),
+            })
+        );
+        assert_eq!(
+            parse_entries::<Pair>("pair one\n").unwrap_err().to_string(),
+            "pair 'one' is missing 'value'"
+        );
+    }
+
+    #[test]
+    fn parses_values() {
+        assert_eq!(parse_char("x"), Some('x'));
+        assert_eq!(parse_char("xy"), None);
+        assert_eq!(parse_yes_no("no"), Some(false));
+        assert_eq!(parse_yes_no("maybe"), None);
+        assert_eq!(parse_list("1 2 3", parse_number), Some(vec![1, 2, 3]));
+        assert_eq!(parse_list::<u32, _>("1 b 3", parse_number), None);
+    }
+}
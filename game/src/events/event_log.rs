@@ -0,0 +1,122 @@
+use std::collections::vec_deque::Drain;
+use std::collections::VecDeque;
+
+use events::GameEvent;
+use events::Severity;
+
+/// An event, along with when it happened.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimedEvent {
+    /// The tick the event happened on.
+    pub tick: u64,
+
+    /// The game time the event happened at.
+    /// In seconds.
+    pub time: u64,
+
+    /// How important the event is.
+    pub severity: Severity,
+
+    /// What happened.
+    pub event: GameEvent,
+}
+
+/// A bounded queue of events waiting to be read.
+///
+/// If nobody drains the log, then it won't grow forever. Once it's full the
+/// oldest events are thrown away to make room.
+pub struct EventLog {
+    /// The events not yet drained, oldest first.
+    events: VecDeque<TimedEvent>,
+
+    /// The most events this will hold at once.
+    capacity: usize,
+}
+
+impl EventLog {
+    /// Creates a new empty log, holding at most the capacity given.
+    pub fn new(capacity: usize) -> EventLog {
+        EventLog {
+            events: VecDeque::with_capacity(capacity),
+            capacity: capacity,
+        }
+    }
+
+    /// Adds an event to the end of the log.
+    pub fn push(
+        &mut self,
+        tick: u64,
+        time: u64,
+        event: GameEvent,
+    ) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        while self.events.len() >= self.capacity {
+            self.events.pop_front();
+        }
+
+        self.events.push_back(TimedEvent {
+            tick: tick,
+            time: time,
+            severity: event.severity(),
+            event: event,
+        });
+    }
+
+    /// Returns how many events are waiting to be drained.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns true if there are no events waiting.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Removes, and returns, all of the events waiting. Oldest first.
+    pub fn drain(&mut self) -> Drain<'_, TimedEvent> {
+        self.events.drain(..)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use world::calendar::Season;
+
+    fn season_event(season: Season) -> GameEvent {
+        GameEvent::SeasonChanged {
+            season: season,
+        }
+    }
+
+    #[test]
+    fn drains_in_order() {
+        let mut log = EventLog::new(10);
+        log.push(1, 5, season_event(Season::Spring));
+        log.push(2, 10, season_event(Season::Summer));
+
+        let events = log.drain().collect::<Vec<TimedEvent>>();
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].event, season_event(Season::Spring));
+        assert_eq!(events[1].tick, 2);
+        assert!(log.is_empty());
+    }
+
+    #[test]
+    fn drops_oldest_when_full() {
+        let mut log = EventLog::new(2);
+        log.push(1, 0, season_event(Season::Spring));
+        log.push(2, 0, season_event(Season::Summer));
+        log.push(3, 0, season_event(Season::Autumn));
+
+        let events = log.drain().collect::<Vec<TimedEvent>>();
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].tick, 2);
+        assert_eq!(events[1].tick, 3);
+    }
+}
@@ -0,0 +1,322 @@
+use world::calendar::Season;
+use world::weather::Precipitation;
+
+use citizens::BreakdownKind;
+use combat::CombatReport;
+use creatures::DeathCause;
+use creatures::Faction;
+use events::Severity;
+use invasions::SiegeOutcome;
+use skills::Skill;
+
+/// Something which has happened in the game.
+#[derive(Clone, Debug, PartialEq)]
+pub enum GameEvent {
+    /// The season has changed.
+    SeasonChanged {
+        /// The season we are now in.
+        season: Season,
+    },
+
+    /// It has started, or stopped, raining or snowing.
+    WeatherChanged {
+        /// What is falling from the sky now.
+        precipitation: Precipitation,
+    },
+
+    /// One creature has attacked another.
+    Combat {
+        /// What happened in the attack.
+        report: CombatReport,
+    },
+
+    /// A creature has died.
+    CreatureDied {
+        /// The name of the creature.
+        name: String,
+
+        /// Who the creature was loyal to.
+        faction: Faction,
+
+        /// How it died.
+        cause: DeathCause,
+    },
+
+    /// A job has been given up on.
+    JobCancelled {
+        /// The name of who was doing it, if anyone was.
+        worker: Option<String>,
+
+        /// What the job was.
+        job: String,
+
+        /// Why it was given up on.
+        reason: &'static str,
+    },
+
+    /// A citizen has got better at something.
+    SkillImproved {
+        /// The name of the citizen.
+        name: String,
+
+        /// The skill which improved.
+        skill: Skill,
+
+        /// The level they are now at.
+        level: u8,
+    },
+
+    /// A citizen has made something of the very best quality.
+    MasterworkCreated {
+        /// The name of the citizen.
+        name: String,
+
+        /// What they did, like 'make table'.
+        what: String,
+    },
+
+    /// A crop wasn't ripe by the end of it's season, and has died.
+    CropWithered {
+        /// The name of the crop.
+        crop: String,
+    },
+
+    /// A citizen has been pushed too far, and broken down.
+    BrokeDown {
+        /// The name of the citizen.
+        name: String,
+
+        /// How they broke down.
+        kind: BreakdownKind,
+    },
+
+    /// A citizen has got over their breakdown.
+    Recovered {
+        /// The name of the citizen.
+        name: String,
+    },
+
+    /// Merchants have arrived at the edge of the map.
+    CaravanArrived {
+        /// How many goods they have brought.
+        goods: usize,
+    },
+
+    /// The merchants have reached the trade depot, and are ready to trade.
+    CaravanReady,
+
+    /// The merchants are leaving.
+    CaravanLeft {
+        /// Why they are going.
+        reason: &'static str,
+    },
+
+    /// A deal was made with the merchants.
+    Traded {
+        /// How many goods the fortress gave.
+        given: usize,
+
+        /// How many goods the fortress got.
+        received: usize,
+    },
+
+    /// The merchants turned down a deal.
+    OfferRefused,
+
+    /// New citizens have arrived at the edge of the map.
+    MigrantsArrived {
+        /// How many there are.
+        count: usize,
+    },
+
+    /// Invaders have arrived at the edge of the map.
+    SiegeBegan {
+        /// How many invaders there are.
+        invaders: usize,
+    },
+
+    /// The invaders are gone.
+    SiegeEnded {
+        /// How it ended.
+        outcome: SiegeOutcome,
+    },
+}
+
+impl GameEvent {
+    /// Returns how important this event is.
+    pub fn severity(&self) -> Severity {
+        match *self {
+            GameEvent::SeasonChanged {
+                ..
+            } => Severity::Notable,
+            GameEvent::WeatherChanged {
+                ..
+            } => Severity::Info,
+            GameEvent::Combat {
+                ref report,
+            } => {
+                if report.is_fatal() {
+                    Severity::Danger
+                } else {
+                    Severity::Warning
+                }
+            },
+            GameEvent::CreatureDied {
+                faction,
+                ..
+            } => {
+                match faction {
+                    Faction::Fortress => Severity::Danger,
+                    Faction::Wild | Faction::Hostile | Faction::Visitor => Severity::Notable,
+                }
+            },
+            GameEvent::JobCancelled {
+                ..
+            } => Severity::Warning,
+            GameEvent::SkillImproved {
+                ..
+            } => Severity::Info,
+            GameEvent::MasterworkCreated {
+                ..
+            } => Severity::Notable,
+            GameEvent::CropWithered {
+                ..
+            } => Severity::Warning,
+            GameEvent::BrokeDown {
+                kind,
+                ..
+            } => {
+                match kind {
+                    BreakdownKind::Tantrum => Severity::Warning,
+                    BreakdownKind::Melancholy => Severity::Danger,
+                }
+            },
+            GameEvent::Recovered {
+                ..
+            } => Severity::Info,
+            GameEvent::CaravanArrived {
+                ..
+            } => Severity::Notable,
+            GameEvent::CaravanReady => Severity::Notable,
+            GameEvent::CaravanLeft {
+                ..
+            } => Severity::Warning,
+            GameEvent::Traded {
+                ..
+            } => Severity::Info,
+            GameEvent::OfferRefused => Severity::Info,
+            GameEvent::MigrantsArrived {
+                ..
+            } => Severity::Notable,
+            GameEvent::SiegeBegan {
+                ..
+            } => Severity::Danger,
+            GameEvent::SiegeEnded {
+                ..
+            } => Severity::Notable,
+        }
+    }
+
+    /// Returns a description of the event, for showing to the player.
+    pub fn describe(&self) -> String {
+        match *self {
+            GameEvent::SeasonChanged {
+                season,
+            } => {
+                match season {
+                    Season::Spring => "Spring has arrived.".to_string(),
+                    Season::Summer => "Summer has arrived.".to_string(),
+                    Season::Autumn => "Autumn has arrived.".to_string(),
+                    Season::Winter => "Winter has arrived.".to_string(),
+                }
+            },
+            GameEvent::WeatherChanged {
+                precipitation,
+            } => {
+                match precipitation {
+                    Precipitation::Clear => "The skies have cleared.".to_string(),
+                    Precipitation::Rain => "It has started to rain.".to_string(),
+                    Precipitation::Snow => "It has started to snow.".to_string(),
+                }
+            },
+            GameEvent::Combat {
+                ref report,
+            } => report.describe(),
+            GameEvent::CreatureDied {
+                ref name,
+                ref cause,
+                ..
+            } => format!("{} {}.", name, cause.describe()),
+            GameEvent::JobCancelled {
+                ref worker,
+                ref job,
+                reason,
+            } => {
+                match *worker {
+                    Some(ref worker) => format!("{} cancels {}, {}.", worker, job, reason),
+                    None => format!("The job {} is cancelled, {}.", job, reason),
+                }
+            },
+            GameEvent::SkillImproved {
+                ref name,
+                skill,
+                level,
+            } => format!("{} has improved at {}, and is now level {}.", name, skill.name(), level),
+            GameEvent::MasterworkCreated {
+                ref name,
+                ref what,
+            } => format!("{} has created a masterwork, doing {}!", name, what),
+            GameEvent::CropWithered {
+                ref crop,
+            } => format!("A crop of {} has withered, as it's out of season.", crop),
+            GameEvent::BrokeDown {
+                ref name,
+                kind,
+            } => {
+                match kind {
+                    BreakdownKind::Tantrum => format!("{} is throwing a tantrum!", name),
+                    BreakdownKind::Melancholy => {
+                        format!("{} has fallen into a deep melancholy.", name)
+                    },
+                }
+            },
+            GameEvent::Recovered {
+                ref name,
+            } => format!("{} has calmed down, and is ready to work again.", name),
+            GameEvent::CaravanArrived {
+                goods,
+            } => format!("A caravan of merchants has arrived, bringing {} goods.", goods),
+            GameEvent::CaravanReady => {
+                "The merchants have reached the trade depot, and are ready to trade.".to_string()
+            },
+            GameEvent::CaravanLeft {
+                reason,
+            } => format!("The merchants are leaving, as {}.", reason),
+            GameEvent::Traded {
+                given,
+                received,
+            } => format!("Traded {} goods for {} from the merchants.", given, received),
+            GameEvent::OfferRefused => "The merchants refuse the offer.".to_string(),
+            GameEvent::MigrantsArrived {
+                count,
+            } => {
+                if count == 1 {
+                    "A migrant has arrived.".to_string()
+                } else {
+                    format!("{} migrants have arrived.", count)
+                }
+            },
+            GameEvent::SiegeBegan {
+                invaders,
+            } => format!("A vile force of darkness has arrived! {} invaders approach.", invaders),
+            GameEvent::SiegeEnded {
+                outcome,
+            } => {
+                match outcome {
+                    SiegeOutcome::Defeated => "The invaders are slain.".to_string(),
+                    SiegeOutcome::Retreated => "The invaders have fled.".to_string(),
+                }
+            },
+        }
+    }
+}
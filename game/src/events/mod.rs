@@ -0,0 +1,15 @@
+//! Game events.
+//!
+//! Things which happen in the game, that the player might want to know about.
+//! The game pushes them into a log, and whoever is showing the game drains
+//! them out.
+//!
+
+mod event_log;
+mod game_event;
+mod severity;
+
+pub use self::event_log::EventLog;
+pub use self::event_log::TimedEvent;
+pub use self::game_event::GameEvent;
+pub use self::severity::Severity;
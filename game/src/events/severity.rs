@@ -0,0 +1,41 @@
+/// How important an event is.
+///
+/// These are ordered, from the least important to the most.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// Background information.
+    /// Nice to know, but safe to ignore.
+    Info,
+
+    /// Something the player will probably want to know about.
+    Notable,
+
+    /// Something has gone wrong, or might go wrong soon.
+    Warning,
+
+    /// Lives are at stake.
+    Danger,
+}
+
+impl Severity {
+    /// Returns the next most severe level.
+    /// After the most severe, this wraps back around to the least.
+    pub fn next(self) -> Severity {
+        match self {
+            Severity::Info => Severity::Notable,
+            Severity::Notable => Severity::Warning,
+            Severity::Warning => Severity::Danger,
+            Severity::Danger => Severity::Info,
+        }
+    }
+
+    /// A short human readable name.
+    pub fn name(self) -> &'static str {
+        match self {
+            Severity::Info => "info",
+            Severity::Notable => "notable",
+            Severity::Warning => "warning",
+            Severity::Danger => "danger",
+        }
+    }
+}
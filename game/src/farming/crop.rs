@@ -0,0 +1,34 @@
+use world::calendar::Season;
+
+/// Something which can be grown on a farm plot.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Crop {
+    /// What the crop is called, like 'wheat'.
+    pub name: String,
+
+    /// The character it is drawn with, once it's ripe.
+    pub symbol: char,
+
+    /// How many ticks it takes to go from being planted to being ripe.
+    pub growth_time: u32,
+
+    /// The seasons it can be planted, and grow, in.
+    /// Outside of these it withers.
+    pub seasons: Vec<Season>,
+
+    /// How much food is harvested from it.
+    pub harvest: u32,
+
+    /// If it grows in plots dug into the rock, rather than in the open.
+    pub underground: bool,
+}
+
+impl Crop {
+    /// Returns true if this crop grows during the season given.
+    pub fn grows_in(
+        &self,
+        season: Season,
+    ) -> bool {
+        self.seasons.contains(&season)
+    }
+}
@@ -0,0 +1,147 @@
+use world::calendar::Season;
+
+use data_file::parse_char;
+use data_file::parse_entries;
+use data_file::parse_list;
+use data_file::parse_number;
+use data_file::parse_yes_no;
+use data_file::DataFileError;
+use data_file::PartialEntry;
+use farming::Crop;
+
+/// Reads a list of crops from the text given.
+///
+/// Each crop starts with a line of `crop <name>`, followed by one line for
+/// each of its properties, as `<property> <value>`. Blank lines, and lines
+/// starting with '#', are skipped. See `data/crops.txt` for an example.
+pub fn parse_crops(text: &str) -> Result<Vec<Crop>, DataFileError> {
+    parse_entries::<PartialCrop>(text)
+}
+
+/// A crop which is part way through being read.
+struct PartialCrop {
+    name: String,
+    symbol: Option<char>,
+    growth_time: Option<u32>,
+    seasons: Option<Vec<Season>>,
+    harvest: Option<u32>,
+    underground: Option<bool>,
+}
+
+impl PartialEntry for PartialCrop {
+    type Entry = Crop;
+
+    const KIND: &'static str = "crop";
+
+    fn new(name: &str) -> PartialCrop {
+        PartialCrop {
+            name: name.to_string(),
+            symbol: None,
+            growth_time: None,
+            seasons: None,
+            harvest: None,
+            underground: None,
+        }
+    }
+
+    fn set(
+        &mut self,
+        line: usize,
+        property: &str,
+        value: &str,
+    ) -> Result<(), DataFileError> {
+        let invalid = || {
+            DataFileError::InvalidValue {
+                line: line,
+                property: property.to_string(),
+            }
+        };
+
+        match property {
+            "symbol" => self.symbol = Some(parse_char(value).ok_or_else(invalid)?),
+            "growth_time" => {
+                let growth_time = parse_number(value).ok_or_else(invalid)?;
+                if growth_time == 0 {
+                    return Err(invalid());
+                }
+
+                self.growth_time = Some(growth_time);
+            },
+            "seasons" => {
+                self.seasons = Some(parse_list(value, Season::from_name).ok_or_else(invalid)?)
+            },
+            "yield" => self.harvest = Some(parse_number(value).ok_or_else(invalid)?),
+            "underground" => self.underground = Some(parse_yes_no(value).ok_or_else(invalid)?),
+            _ => {
+                return Err(DataFileError::UnknownProperty {
+                    line: line,
+                    property: property.to_string(),
+                })
+            },
+        }
+
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Crop, DataFileError> {
+        let name = self.name;
+        let missing = |property| {
+            DataFileError::MissingProperty {
+                kind: Self::KIND,
+                name: name.clone(),
+                property: property,
+            }
+        };
+
+        Ok(Crop {
+            symbol: self.symbol.ok_or_else(|| missing("symbol"))?,
+            growth_time: self.growth_time.ok_or_else(|| missing("growth_time"))?,
+            seasons: self.seasons.ok_or_else(|| missing("seasons"))?,
+            harvest: self.harvest.ok_or_else(|| missing("yield"))?,
+            underground: self.underground.unwrap_or(false),
+            name: name,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_crop() {
+        let text = "
+            # A comment.
+            crop plump helmet
+            symbol p
+            growth_time 1200
+            seasons spring winter
+            yield 3
+            underground yes
+        ";
+
+        let crops = parse_crops(text).unwrap();
+
+        assert_eq!(crops.len(), 1);
+        assert_eq!(crops[0].name, "plump helmet");
+        assert_eq!(crops[0].symbol, 'p');
+        assert_eq!(crops[0].growth_time, 1200);
+        assert_eq!(crops[0].seasons, vec![Season::Spring, Season::Winter]);
+        assert_eq!(crops[0].harvest, 3);
+        assert!(crops[0].underground);
+    }
+
+    #[test]
+    fn reports_missing_properties() {
+        let text = "crop wheat\nsymbol w\ngrowth_time 100\nseasons summer\n";
+
+        assert_eq!(
+            parse_crops(text),
+            Err(DataFileError::MissingProperty {
+                kind: "crop",
+                name: "wheat".to_string(),
+                property: "yield",
+            })
+        );
+    }
+}
@@ -0,0 +1,37 @@
+use util::shapes::Point;
+use world::calendar::Season;
+
+use farming::Planting;
+
+/// A patch of soil set aside for growing crops.
+#[derive(Copy, Clone, Debug)]
+pub struct FarmPlot {
+    /// Where it is.
+    pub position: Point<u32>,
+
+    /// The crop to plant in each season, in the order of `Season::ALL`.
+    /// Each is an index into the farms' crops, or None to leave it fallow.
+    pub crops: [Option<u8>; 4],
+
+    /// What is growing there right now, if anything.
+    pub planted: Option<Planting>,
+}
+
+impl FarmPlot {
+    /// Creates an empty plot, with nothing chosen to grow in it.
+    pub fn new(position: Point<u32>) -> FarmPlot {
+        FarmPlot {
+            position: position,
+            crops: [None; 4],
+            planted: None,
+        }
+    }
+
+    /// Returns the crop chosen for the season given, if there is one.
+    pub fn crop_for(
+        &self,
+        season: Season,
+    ) -> Option<u8> {
+        self.crops[season as usize]
+    }
+}
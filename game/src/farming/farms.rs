@@ -0,0 +1,347 @@
+use util::shapes::Point;
+use world::calendar::Season;
+use world::map::Map;
+
+use events::GameEvent;
+use farming::parse_crops;
+use farming::Crop;
+use farming::FarmPlot;
+use farming::GrowthStage;
+use farming::Planting;
+use jobs::JobKind;
+use jobs::Jobs;
+use weather::is_underground;
+use GameTile;
+
+/// The crops which come with the game.
+const CROP_DATA: &str = include_str!("../../data/crops.txt");
+
+/// Looks after every farm plot, and what is growing on them.
+pub struct Farms {
+    /// Every crop which can be grown.
+    crops: Vec<Crop>,
+
+    /// The plots, in the order they were built.
+    plots: Vec<FarmPlot>,
+}
+
+impl Farms {
+    /// Creates farms using the crops which come with the game.
+    pub fn new() -> Farms {
+        let crops = parse_crops(CROP_DATA).expect("built in crop data is invalid");
+
+        Farms::with_crops(crops)
+    }
+
+    /// Creates farms using the crops given.
+    pub fn with_crops(crops: Vec<Crop>) -> Farms {
+        Farms {
+            crops: crops,
+            plots: Vec::new(),
+        }
+    }
+
+    /// Returns every crop which can be grown.
+    pub fn crops(&self) -> &[Crop] {
+        &self.crops
+    }
+
+    /// Returns the crop at the index given, if there is one.
+    pub fn crop(
+        &self,
+        index: u8,
+    ) -> Option<&Crop> {
+        self.crops.get(usize::from(index))
+    }
+
+    /// Adds an empty plot at the position given.
+    pub(crate) fn add_plot(
+        &mut self,
+        position: Point<u32>,
+    ) {
+        self.plots.push(FarmPlot::new(position));
+    }
+
+    /// Returns the plot at the position given, if there is one.
+    pub fn plot(
+        &self,
+        position: Point<u32>,
+    ) -> Option<&FarmPlot> {
+        self.plots.iter().find(|plot| plot.position == position)
+    }
+
+    /// Iterates over every plot, in the order they were built.
+    pub fn iter(&self) -> impl Iterator<Item = &FarmPlot> + '_ {
+        self.plots.iter()
+    }
+
+    /// Returns the number of plots.
+    pub fn len(&self) -> usize {
+        self.plots.len()
+    }
+
+    /// Returns true if there are no plots.
+    pub fn is_empty(&self) -> bool {
+        self.plots.is_empty()
+    }
+
+    /// Returns what is growing at the position given, and how far along it
+    /// is. Returns None if nothing is growing there.
+    pub fn growth_at(
+        &self,
+        position: Point<u32>,
+    ) -> Option<(&Crop, GrowthStage)> {
+        let planting = self.plot(position)?.planted?;
+        let crop = self.crop(planting.crop)?;
+
+        Some((crop, GrowthStage::from_growth(planting.growth, crop.growth_time)))
+    }
+
+    /// Returns true if the crop can be grown on the plot at the position
+    /// given, during the season given.
+    ///
+    /// The crop must grow in that season. Underground crops only grow on
+    /// plots dug into the rock, and other crops only grow in the open.
+    pub fn can_grow(
+        &self,
+        map: &Map<GameTile>,
+        position: Point<u32>,
+        crop: u8,
+        season: Season,
+    ) -> bool {
+        match self.crop(crop) {
+            Some(crop) => {
+                crop.grows_in(season) && crop.underground == is_underground(map, position)
+            },
+            None => false,
+        }
+    }
+
+    /// Chooses what to plant on the plot at the position given, during the
+    /// season given. None leaves the plot empty for that season.
+    ///
+    /// Returns false, and changes nothing, if there is no plot there or the
+    /// crop can't be grown there in that season.
+    pub fn set_crop(
+        &mut self,
+        map: &Map<GameTile>,
+        position: Point<u32>,
+        season: Season,
+        crop: Option<u8>,
+    ) -> bool {
+        if let Some(crop) = crop {
+            if !self.can_grow(map, position, crop, season) {
+                return false;
+            }
+        }
+
+        match self.plots.iter_mut().find(|plot| plot.position == position) {
+            Some(plot) => {
+                plot.crops[season as usize] = crop;
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Grows everything which has been planted, and asks for plots to be
+    /// planted, or harvested, when they need it.
+    ///
+    /// Crops which aren't ripe by the end of their season wither away.
+    /// Returns anything worth telling the player about.
+    pub fn update(
+        &mut self,
+        map: &Map<GameTile>,
+        jobs: &mut Jobs,
+        season: Season,
+    ) -> Vec<GameEvent> {
+        let mut events = Vec::new();
+
+        for i in 0..self.plots.len() {
+            let position = self.plots[i].position;
+
+            match self.plots[i].planted {
+                Some(planting) => {
+                    let crop = &self.crops[usize::from(planting.crop)];
+                    let is_ripe = planting.growth >= crop.growth_time;
+
+                    if is_ripe {
+                        queue_once(
+                            jobs,
+                            JobKind::Harvest {
+                                x: position.x,
+                                y: position.y,
+                            },
+                        );
+                    } else if crop.grows_in(season) {
+                        self.plots[i].planted = Some(Planting {
+                            crop: planting.crop,
+                            growth: planting.growth + 1,
+                        });
+                    } else {
+                        events.push(GameEvent::CropWithered {
+                            crop: crop.name.clone(),
+                        });
+                        self.plots[i].planted = None;
+                    }
+                },
+                None => {
+                    let crop = match self.plots[i].crop_for(season) {
+                        Some(crop) => crop,
+                        None => continue,
+                    };
+
+                    if self.can_grow(map, position, crop, season) {
+                        queue_once(
+                            jobs,
+                            JobKind::Plant {
+                                crop: crop,
+                                x: position.x,
+                                y: position.y,
+                            },
+                        );
+                    }
+                },
+            }
+        }
+
+        events
+    }
+
+    /// Sows the crop on the empty plot at the position given.
+    /// Returns false if there is no empty plot there.
+    pub(crate) fn plant(
+        &mut self,
+        position: Point<u32>,
+        crop: u8,
+    ) -> bool {
+        match self.plots.iter_mut().find(|plot| plot.position == position) {
+            Some(plot) if plot.planted.is_none() => {
+                plot.planted = Some(Planting {
+                    crop: crop,
+                    growth: 0,
+                });
+                true
+            },
+            _ => false,
+        }
+    }
+
+    /// Takes the ripe crop off the plot at the position given.
+    /// Returns how much food it gave, or None if nothing there was ripe.
+    pub(crate) fn harvest(
+        &mut self,
+        position: Point<u32>,
+    ) -> Option<u32> {
+        let (_, stage) = self.growth_at(position)?;
+        if stage != GrowthStage::Ripe {
+            return None;
+        }
+
+        let plot = self.plots.iter_mut().find(|plot| plot.position == position)?;
+        let planting = plot.planted.take()?;
+
+        Some(self.crops[usize::from(planting.crop)].harvest)
+    }
+}
+
+/// Adds the job, unless it's already waiting to be done.
+fn queue_once(
+    jobs: &mut Jobs,
+    kind: JobKind,
+) {
+    if !jobs.iter().any(|job| job.kind == kind) {
+        jobs.add(kind);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use world::tiles::Tile;
+
+    fn farms() -> Farms {
+        let crops = parse_crops(
+            "
+            crop wheat
+            symbol w
+            growth_time 10
+            seasons spring
+            yield 4
+
+            crop mushroom
+            symbol m
+            growth_time 10
+            seasons spring
+            yield 2
+            underground yes
+            ",
+        )
+        .unwrap();
+
+        let mut farms = Farms::with_crops(crops);
+        farms.add_plot(Point::new(5, 5));
+        farms
+    }
+
+    #[test]
+    fn crops_are_planted_grown_and_harvested() {
+        let map = Map::new(10, 10, GameTile::new(Tile::Grass));
+        let mut farms = farms();
+        let mut jobs = Jobs::new();
+        let position = Point::new(5, 5);
+
+        assert!(farms.set_crop(&map, position, Season::Spring, Some(0)));
+        farms.update(&map, &mut jobs, Season::Spring);
+        assert_eq!(jobs.len(), 1);
+
+        assert!(farms.plant(position, 0));
+        for _ in 0..10 {
+            farms.update(&map, &mut jobs, Season::Spring);
+        }
+
+        assert_eq!(farms.growth_at(position).map(|(_, stage)| stage), Some(GrowthStage::Ripe));
+        farms.update(&map, &mut jobs, Season::Spring);
+        assert!(jobs.iter().any(|job| {
+            job.kind
+                == JobKind::Harvest {
+                    x: 5,
+                    y: 5,
+                }
+        }));
+
+        assert_eq!(farms.harvest(position), Some(4));
+        assert!(farms.growth_at(position).is_none());
+    }
+
+    #[test]
+    fn crops_wither_out_of_season() {
+        let map = Map::new(10, 10, GameTile::new(Tile::Grass));
+        let mut farms = farms();
+        let mut jobs = Jobs::new();
+        let position = Point::new(5, 5);
+
+        farms.plant(position, 0);
+        let events = farms.update(&map, &mut jobs, Season::Summer);
+
+        assert_eq!(events.len(), 1);
+        assert!(farms.growth_at(position).is_none());
+    }
+
+    #[test]
+    fn underground_crops_need_a_plot_in_the_rock() {
+        let mut map = Map::new(10, 10, GameTile::new(Tile::Ground));
+        let mut farms = farms();
+        let position = Point::new(5, 5);
+
+        assert!(!farms.set_crop(&map, position, Season::Spring, Some(1)));
+
+        for x in 4..7 {
+            map.set(x, 4, GameTile::new(Tile::Wall));
+            map.set(x, 6, GameTile::new(Tile::Wall));
+        }
+
+        assert!(farms.set_crop(&map, position, Season::Spring, Some(1)));
+        assert!(!farms.set_crop(&map, position, Season::Spring, Some(0)));
+    }
+}
@@ -0,0 +1,38 @@
+/// How far along a planted crop is.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GrowthStage {
+    /// It's only just been planted.
+    Seedling,
+
+    /// It's coming along, but isn't ready yet.
+    Growing,
+
+    /// It's ready to be harvested.
+    Ripe,
+}
+
+impl GrowthStage {
+    /// Works out the stage of a crop which has grown for `growth` ticks, out
+    /// of the `growth_time` it needs to ripen.
+    pub fn from_growth(
+        growth: u32,
+        growth_time: u32,
+    ) -> GrowthStage {
+        if growth >= growth_time {
+            GrowthStage::Ripe
+        } else if growth * 3 < growth_time {
+            GrowthStage::Seedling
+        } else {
+            GrowthStage::Growing
+        }
+    }
+
+    /// A human readable name for the stage.
+    pub fn name(self) -> &'static str {
+        match self {
+            GrowthStage::Seedling => "seedling",
+            GrowthStage::Growing => "growing",
+            GrowthStage::Ripe => "ripe",
+        }
+    }
+}
@@ -0,0 +1,20 @@
+//! Farming.
+//!
+//! Farm plots, the crops which are grown on them, and when they need
+//! planting and harvesting. What crops there are is described in
+//! `data/crops.txt`.
+//!
+
+mod crop;
+mod crop_parser;
+mod farm_plot;
+mod farms;
+mod growth_stage;
+mod planting;
+
+pub use self::crop::Crop;
+pub use self::crop_parser::parse_crops;
+pub use self::farm_plot::FarmPlot;
+pub use self::farms::Farms;
+pub use self::growth_stage::GrowthStage;
+pub use self::planting::Planting;
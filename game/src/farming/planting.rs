@@ -0,0 +1,9 @@
+/// A crop which has been planted on a farm plot.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Planting {
+    /// Which crop it is, as an index into the farms' crops.
+    pub crop: u8,
+
+    /// How many ticks it has been growing for.
+    pub growth: u32,
+}
@@ -0,0 +1,71 @@
+use util::shapes::Point;
+use world::map::Map;
+
+use fov::field_of_view;
+use fov::Visibility;
+
+/// Tracks what is currently visible on a map, and what has been revealed in
+/// the past.
+///
+/// Once a tile has been seen it stays revealed. It just drops back to being
+/// remembered when it falls out of view.
+pub struct FogOfWar {
+    /// The tiles visible from the last update.
+    visible: Map<bool>,
+
+    /// Every tile that has ever been visible.
+    revealed: Map<bool>,
+}
+
+impl FogOfWar {
+    /// Creates a new fog for a map of the size given.
+    /// Everything starts off hidden.
+    pub fn new(
+        width: u32,
+        height: u32,
+    ) -> FogOfWar {
+        FogOfWar {
+            visible: Map::new(width, height, false),
+            revealed: Map::new(width, height, false),
+        }
+    }
+
+    /// Recalculates what is visible from the origin.
+    ///
+    /// Anything seen is also marked as revealed.
+    pub fn update<F>(
+        &mut self,
+        origin: Point<u32>,
+        radius: u32,
+        is_opaque: F,
+    ) where
+        F: Fn(u32, u32) -> bool,
+    {
+        let width = self.visible.width;
+        let height = self.visible.height;
+        let visible = &mut self.visible;
+        let revealed = &mut self.revealed;
+
+        visible.fill(|_, _, _| false);
+
+        field_of_view(width, height, origin, radius, is_opaque, |x, y| {
+            visible.set(x, y, true);
+            revealed.set(x, y, true);
+        });
+    }
+
+    /// Returns how visible the tile is at the position given.
+    pub fn get(
+        &self,
+        x: u32,
+        y: u32,
+    ) -> Visibility {
+        if self.visible.get(x, y) {
+            Visibility::Visible
+        } else if self.revealed.get(x, y) {
+            Visibility::Remembered
+        } else {
+            Visibility::Hidden
+        }
+    }
+}
@@ -0,0 +1,13 @@
+//! Field of view.
+//!
+//! Works out what can be seen from a point on the map, and keeps a memory of
+//! what has been seen before.
+//!
+
+mod fog_of_war;
+mod shadowcast;
+mod visibility;
+
+pub use self::fog_of_war::FogOfWar;
+pub use self::shadowcast::field_of_view;
+pub use self::visibility::Visibility;
@@ -0,0 +1,198 @@
+use util::shapes::Point;
+
+/// Multipliers for translating a position in the first octant, into each of
+/// the eight octants around the origin.
+///
+/// Each entry is `[xx, xy, yx, yy]`.
+const OCTANTS: [[i32; 4]; 8] = [
+    [1, 0, 0, 1],
+    [0, 1, 1, 0],
+    [0, -1, 1, 0],
+    [-1, 0, 0, 1],
+    [-1, 0, 0, -1],
+    [0, -1, -1, 0],
+    [0, 1, -1, 0],
+    [1, 0, 0, -1],
+];
+
+/// Computes what can be seen from the origin, using recursive shadowcasting.
+///
+/// `is_opaque` is asked if a tile blocks sight. `mark_visible` is called once
+/// for every tile which can be seen, including the origin. Tiles outside of
+/// the width and height are never visited.
+pub fn field_of_view<O, V>(
+    width: u32,
+    height: u32,
+    origin: Point<u32>,
+    radius: u32,
+    is_opaque: O,
+    mut mark_visible: V,
+) where
+    O: Fn(u32, u32) -> bool,
+    V: FnMut(u32, u32),
+{
+    if origin.x >= width || origin.y >= height {
+        return;
+    }
+
+    mark_visible(origin.x, origin.y);
+
+    let mut caster = Shadowcaster {
+        width: width as i32,
+        height: height as i32,
+        origin_x: origin.x as i32,
+        origin_y: origin.y as i32,
+        radius: radius as i32,
+        is_opaque: is_opaque,
+        mark_visible: mark_visible,
+    };
+
+    for octant in OCTANTS.iter() {
+        caster.cast(1, 1.0, 0.0, *octant);
+    }
+}
+
+/// Holds the state which is shared across a whole field of view calculation.
+struct Shadowcaster<O, V> {
+    width: i32,
+    height: i32,
+    origin_x: i32,
+    origin_y: i32,
+    radius: i32,
+    is_opaque: O,
+    mark_visible: V,
+}
+
+impl<O, V> Shadowcaster<O, V>
+where
+    O: Fn(u32, u32) -> bool,
+    V: FnMut(u32, u32),
+{
+    /// Scans the rows of one octant, from `row` outwards, between the start
+    /// and end slopes. When a run of opaque tiles is found the area behind is
+    /// scanned recursively with a narrower slope.
+    fn cast(
+        &mut self,
+        row: i32,
+        mut start_slope: f32,
+        end_slope: f32,
+        octant: [i32; 4],
+    ) {
+        if start_slope < end_slope {
+            return;
+        }
+
+        let [xx, xy, yx, yy] = octant;
+        let radius_squared = self.radius * self.radius;
+        let mut next_start_slope = start_slope;
+
+        for distance in row..(self.radius + 1) {
+            let dy = -distance;
+            let mut blocked = false;
+
+            for dx in -distance..1 {
+                let left_slope = (dx as f32 - 0.5) / (dy as f32 + 0.5);
+                let right_slope = (dx as f32 + 0.5) / (dy as f32 - 0.5);
+
+                if start_slope < right_slope {
+                    continue;
+                } else if end_slope > left_slope {
+                    break;
+                }
+
+                let x = self.origin_x + dx * xx + dy * xy;
+                let y = self.origin_y + dx * yx + dy * yy;
+                let is_opaque = self.is_opaque_at(x, y);
+
+                if dx * dx + dy * dy <= radius_squared && self.is_in_bounds(x, y) {
+                    (self.mark_visible)(x as u32, y as u32);
+                }
+
+                if blocked {
+                    if is_opaque {
+                        next_start_slope = right_slope;
+                    } else {
+                        blocked = false;
+                        start_slope = next_start_slope;
+                    }
+                } else if is_opaque && distance < self.radius {
+                    blocked = true;
+                    self.cast(distance + 1, start_slope, left_slope, octant);
+                    next_start_slope = right_slope;
+                }
+            }
+
+            if blocked {
+                break;
+            }
+        }
+    }
+
+    fn is_in_bounds(
+        &self,
+        x: i32,
+        y: i32,
+    ) -> bool {
+        0 <= x && x < self.width && 0 <= y && y < self.height
+    }
+
+    /// Anything outside of the map is treated as opaque.
+    fn is_opaque_at(
+        &self,
+        x: i32,
+        y: i32,
+    ) -> bool {
+        !self.is_in_bounds(x, y) || (self.is_opaque)(x as u32, y as u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn visible_tiles<O>(
+        size: u32,
+        origin: Point<u32>,
+        radius: u32,
+        is_opaque: O,
+    ) -> Vec<bool>
+    where
+        O: Fn(u32, u32) -> bool,
+    {
+        let mut visible = vec![false; (size * size) as usize];
+
+        field_of_view(size, size, origin, radius, is_opaque, |x, y| {
+            visible[(y * size + x) as usize] = true;
+        });
+
+        visible
+    }
+
+    #[test]
+    fn sees_everything_in_an_open_room() {
+        let visible = visible_tiles(11, Point::new(5, 5), 10, |_, _| false);
+
+        assert!(visible.iter().all(|&v| v));
+    }
+
+    #[test]
+    fn radius_limits_sight() {
+        let visible = visible_tiles(11, Point::new(5, 5), 2, |_, _| false);
+
+        assert!(visible[5 * 11 + 7]);
+        assert!(!visible[5 * 11 + 8]);
+        assert!(!visible[0]);
+    }
+
+    #[test]
+    fn walls_block_sight() {
+        // A wall runs down the column at x = 7.
+        let visible = visible_tiles(11, Point::new(5, 5), 10, |x, _| x == 7);
+
+        assert!(visible[5 * 11 + 6]);
+        assert!(visible[5 * 11 + 7]);
+        assert!(!visible[5 * 11 + 8]);
+        assert!(!visible[5 * 11 + 10]);
+        assert!(visible[5 * 11]);
+    }
+}
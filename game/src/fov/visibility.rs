@@ -0,0 +1,14 @@
+/// How much of a tile the player can currently see.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Visibility {
+    /// The tile has never been seen.
+    /// We know nothing about it.
+    Hidden,
+
+    /// The tile has been seen before, but it's not in view right now.
+    /// What we know about it might be out of date.
+    Remembered,
+
+    /// The tile is in view right now.
+    Visible,
+}
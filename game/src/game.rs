@@ -1,13 +1,79 @@
+use util::random::Random;
+use util::shapes::Point;
+use world::biomes::Biome;
+use world::calendar::Season;
 use world::calendar::WorldTime;
 use world::map::Map;
 use world::map::MapIterator;
 use world::player::Player;
 use world::tiles::Tile;
+use world::weather::Location;
+use world::weather::Precipitation;
+use world::weather::WeatherReport;
 use world::world_setup::WorldSetup;
 
+use std::collections::vec_deque::Drain;
+use std::time::Instant;
+
+use buildings::BuildingKind;
+use buildings::Buildings;
+use citizens::plan_wave;
+use citizens::Citizens;
+use citizens::ThoughtKind;
+use combat::resolve_attack;
+use combat::AttackOutcome;
+use combat::Body;
+use combat::CombatReport;
+use creatures::Creature;
+use creatures::CreatureId;
+use creatures::Creatures;
+use creatures::DeathCause;
+use creatures::Faction;
+use events::EventLog;
+use events::GameEvent;
+use events::TimedEvent;
+use farming::Farms;
+use fov::FogOfWar;
+use fov::Visibility;
+use invasions::Invasions;
+use items::Items;
+use jobs::JobKind;
+use jobs::Jobs;
+use jobs::Labour;
+use lighting::LightSource;
+use lighting::Lighting;
+use navigation::distance_squared;
+use replay::InputLog;
+use replay::StateHasher;
+use replay::STATE_HASH_INTERVAL;
+use scheduler::Scheduler;
+use scheduler::System;
+use scheduler::SystemTiming;
+use timers::next_season_start;
+use timers::Recurrence;
+use timers::TimedAction;
+use timers::Timers;
+use trade::Caravan;
+use trade::Caravans;
+use trade::OfferEvaluation;
+use wealth::Wealth;
+use weather::is_exposed;
+use weather::Weathering;
+use wildlife::Wildlife;
+use GameCommand;
 use GameSetup;
 use GameTile;
 
+/// The most events held, waiting to be drained.
+const EVENT_LOG_CAPACITY: usize = 256;
+
+/// How happy the fortress is, when there is no one in it to be unhappy.
+const CONTENT_HAPPINESS: f32 = 0.5;
+
+/// The mood at which a citizen counts as completely happy, or, when negative,
+/// completely miserable.
+const FULL_HAPPINESS_MOOD: i32 = 50;
+
 /// This models, and runs, the core game.
 /// It includes updating the game based on the events given.
 pub struct Game<'a> {
@@ -24,9 +90,67 @@ pub struct Game<'a> {
     /// The player in the world.
     pub player: Player,
 
+    /// What the player can see right now, and what they have seen before.
+    fog_of_war: FogOfWar,
+
+    /// The light from the sky, and from things like torches.
+    lighting: Lighting,
+
+    /// The weather, and the snow and puddles it leaves behind.
+    weathering: Weathering,
+
+    /// Everything alive in the world, other than the player.
+    creatures: Creatures,
+
+    /// The records for the citizens of the fortress.
+    citizens: Citizens,
+
+    /// Everything which has been made, or built.
+    items: Items,
+
+    /// Work waiting to be done.
+    jobs: Jobs,
+
+    /// Places set aside for something.
+    buildings: Buildings,
+
+    /// Merchants visiting the fortress.
+    caravans: Caravans,
+
+    /// What the wild animals are up to.
+    wildlife: Wildlife,
+
+    /// Hostile groups attacking the fortress.
+    invasions: Invasions,
+
     /// The current time.
     /// In seconds.
-    time: u32,
+    time: u64,
+
+    /// How many times the game has been ticked.
+    ticks: u64,
+
+    /// The one source of randomness for the game.
+    ///
+    /// Every random decision must be drawn from here. Otherwise games cannot
+    /// be replayed.
+    rng: Random,
+
+    /// Every command applied to the game so far.
+    input_log: InputLog,
+
+    /// The season as of the last tick.
+    /// Used for spotting when the season changes.
+    season: Season,
+
+    /// Events waiting to be shown to the player.
+    events: EventLog,
+
+    /// Decides which systems run on each tick.
+    scheduler: Scheduler,
+
+    /// Things due to happen at set times, like caravans arriving.
+    timers: Timers,
 
     /// A setup or description of the world.
     /// Like it's calendar, and things like that.
@@ -44,19 +168,67 @@ impl<'a> Game<'a> {
         world_setup: WorldSetup<'a>,
         game_setup: GameSetup,
     ) -> Game<'a> {
-        Game {
-            map: map.map(|&t| GameTile::new(t)),
+        let start_time = world_setup.calendar.get_time(0);
+        let start_season = world_setup.calendar.get_season(0);
+
+        let mut game_map = map.map(|&t| GameTile::new(t));
+        game_map.fill(|&tile, x, y| {
+            GameTile {
+                biome: match world_setup.biomes {
+                    Some(biomes) => biomes.get(x, y),
+                    None => world_setup.biome,
+                },
+                ..tile
+            }
+        });
+
+        let mut game = Game {
+            map: game_map,
 
             width: map.width,
             height: map.height,
 
             player: player,
+            fog_of_war: FogOfWar::new(map.width, map.height),
+            lighting: Lighting::new(start_time, start_season),
+            weathering: Weathering::new(
+                world_setup.calendar,
+                game_setup.seed,
+                0,
+                Location {
+                    biome: world_setup.biome,
+                    latitude: world_setup.latitude,
+                    elevation: world_setup.elevation,
+                    underground: false,
+                },
+            ),
+            creatures: Creatures::new(),
+            citizens: Citizens::new(),
+            items: Items::new(),
+            jobs: Jobs::new(),
+            buildings: Buildings::new(),
+            caravans: Caravans::new(),
+            wildlife: Wildlife::new(),
+            invasions: Invasions::new(),
 
             time: 0,
+            ticks: 0,
+
+            rng: Random::new(game_setup.seed),
+            input_log: InputLog::new(game_setup.seed),
+
+            season: start_season,
+            events: EventLog::new(EVENT_LOG_CAPACITY),
+            scheduler: Scheduler::new(&game_setup.systems),
+            timers: Timers::new(),
 
             world_setup: world_setup,
             game_setup: game_setup,
-        }
+        };
+
+        game.schedule_seasonal_visits();
+        game.update_field_of_view();
+        game
     }
 
     /// A lot of the world has natural ways to update.
@@ -66,8 +238,851 @@ impl<'a> Game<'a> {
     /// to triggerring a random encounter, to causing other
     /// effects.
     ///
+    /// Which systems run, and in what order, is decided by the systems in
+    /// the `GameSetup`. How long each one took is in `system_timings`.
+    ///
     pub fn tick(&mut self) {
-        self.time += self.game_setup.time_tick_speed;
+        self.ticks += 1;
+        self.time += u64::from(self.game_setup.time_tick_speed);
+
+        for system in self.scheduler.begin_tick(self.ticks) {
+            let start = Instant::now();
+            self.run_system(system);
+            self.scheduler.record(system, start.elapsed());
+        }
+
+        if self.ticks % STATE_HASH_INTERVAL == 0 {
+            self.record_state_hash();
+        }
+    }
+
+    /// Updates one part of the game.
+    fn run_system(
+        &mut self,
+        system: System,
+    ) {
+        match system {
+            System::FieldOfView => self.update_field_of_view(),
+            System::Lighting => self.update_lighting(),
+            System::Weather => self.update_weather(),
+            System::Seasons => self.update_season(),
+            System::Timers => self.update_timers(),
+            System::Wounds => self.update_wounds(),
+            System::Citizens => self.update_citizens(),
+            System::Farms => self.update_farms(),
+            System::Jobs => self.update_jobs(),
+            System::Wildlife => self.update_wildlife(),
+            System::Invasions => self.update_invasions(),
+            System::Caravans => self.update_caravans(),
+        }
+    }
+
+    /// Returns how long each system has taken to run, in the order they run.
+    ///
+    /// `SystemTiming::last` gives the time for the most recent tick.
+    pub fn system_timings(&self) -> &[SystemTiming] {
+        self.scheduler.timings()
+    }
+
+    /// Applies the command to the game.
+    ///
+    /// The command is also added to the input log, so the game can be replayed
+    /// later.
+    pub fn apply(
+        &mut self,
+        command: GameCommand,
+    ) {
+        self.input_log.push_command(self.ticks, command);
+
+        match command {
+            GameCommand::MovePlayer {
+                x,
+                y,
+            } => self.move_player(x, y),
+            GameCommand::QueueJob {
+                kind,
+            } => self.queue_job(kind),
+            GameCommand::SetLabour {
+                creature,
+                labour,
+                enabled,
+            } => self.set_labour(creature, labour, enabled),
+            GameCommand::Trade {
+                offered,
+                requested,
+            } => self.trade(offered, requested),
+            GameCommand::SetCrop {
+                x,
+                y,
+                season,
+                crop,
+            } => self.set_crop(Point::new(x, y), season, crop),
+        }
+    }
+
+    /// Moves the player, if the tile they are moving to can be walked on.
+    fn move_player(
+        &mut self,
+        x: i32,
+        y: i32,
+    ) {
+        let to_x = self.player.position.x as i64 + i64::from(x);
+        let to_y = self.player.position.y as i64 + i64::from(y);
+
+        if to_x < 0 || to_y < 0 || to_x >= i64::from(self.width) || to_y >= i64::from(self.height) {
+            return;
+        }
+
+        let to = Point::new(to_x as u32, to_y as u32);
+        if self.map.get(to.x, to.y).tile.is_passable() {
+            self.player.position = to;
+            self.update_field_of_view();
+        }
+    }
+
+    /// Adds a job to the end of the job queue.
+    fn queue_job(
+        &mut self,
+        kind: JobKind,
+    ) {
+        self.jobs.add(kind);
+    }
+
+    /// Turns a labour on, or off, for a citizen.
+    fn set_labour(
+        &mut self,
+        creature: CreatureId,
+        labour: Labour,
+        enabled: bool,
+    ) {
+        if let Some(citizen) = self.citizens.get_mut(creature) {
+            citizen.labours.set(labour, enabled);
+        }
+    }
+
+    /// Chooses what to plant on a farm plot, during a season.
+    /// Nothing happens if the crop can't be grown there.
+    fn set_crop(
+        &mut self,
+        position: Point<u32>,
+        season: Season,
+        crop: Option<u8>,
+    ) {
+        self.buildings.farms_mut().set_crop(&self.map, position, season, crop);
+    }
+
+    /// Offers a deal to the merchants, and carries it out if they agree.
+    fn trade(
+        &mut self,
+        offered: u64,
+        requested: u64,
+    ) {
+        let evaluation = match self.evaluate_trade(offered, requested) {
+            Some(evaluation) => evaluation,
+            None => return,
+        };
+
+        if !evaluation.accepted {
+            self.push_event(GameEvent::OfferRefused);
+            return;
+        }
+
+        let depot = match self
+            .caravans
+            .caravan()
+            .and_then(|caravan| self.creatures.get(caravan.merchant))
+        {
+            Some(merchant) => merchant.position,
+            None => return,
+        };
+
+        let given = self.items.take_selected(offered);
+        let given_count = given.len();
+        let received = self.caravans.exchange(given, requested);
+        let received_count = received.len();
+
+        for mut item in received {
+            item.position = depot;
+            self.items.add(item);
+        }
+
+        self.push_event(GameEvent::Traded {
+            given: given_count,
+            received: received_count,
+        });
+    }
+
+    /// Returns the seed the game was started with.
+    pub fn seed(&self) -> u64 {
+        self.game_setup.seed
+    }
+
+    /// Returns how many times the game has been ticked.
+    pub fn ticks(&self) -> u64 {
+        self.ticks
+    }
+
+    /// Returns the log of every command applied so far.
+    ///
+    /// Write this out, along with the map and setup, to be able to replay the
+    /// game.
+    pub fn input_log(&self) -> &InputLog {
+        &self.input_log
+    }
+
+    /// Hashes the current state, and adds it to the input log.
+    ///
+    /// This happens automatically every so often. Call this when the game is
+    /// over so the very end of it can also be checked.
+    pub fn record_state_hash(&mut self) {
+        let hash = self.state_hash();
+
+        self.input_log.push_hash(self.ticks, hash);
+    }
+
+    /// Returns a hash of the whole game state.
+    ///
+    /// Two games which have been played in the same way will have the same
+    /// hash. If they differ then the simulation has diverged.
+    pub fn state_hash(&self) -> u64 {
+        let mut hasher = StateHasher::new();
+
+        hasher.write_u64(self.ticks);
+        hasher.write_u64(self.time);
+        hasher.write_u64(self.rng.state());
+        hasher.write_u32(self.player.position.x);
+        hasher.write_u32(self.player.position.y);
+
+        for (game_tile, _) in self.map.slice_all() {
+            hasher.write_u8(game_tile.tile as u8);
+            hasher.write_u8(game_tile.snow);
+            hasher.write_u8(game_tile.puddle);
+        }
+
+        for source in self.lighting.sources() {
+            hasher.write_u32(source.position.x);
+            hasher.write_u32(source.position.y);
+            hasher.write_u8(source.kind as u8);
+        }
+
+        for item in self.items.iter() {
+            hasher.write_u8(item.kind as u8);
+            hasher.write_u8(item.quality as u8);
+            hasher.write_u32(item.position.x);
+            hasher.write_u32(item.position.y);
+        }
+
+        for construction in self.items.constructions() {
+            hasher.write_u8(construction.kind as u8);
+            hasher.write_u8(construction.quality as u8);
+        }
+
+        for building in self.buildings.iter() {
+            hasher.write_u8(building.kind as u8);
+            hasher.write_u32(building.position.x);
+            hasher.write_u32(building.position.y);
+        }
+
+        for plot in self.buildings.farms().iter() {
+            for crop in plot.crops.iter() {
+                hasher.write_u8(crop.map(|crop| crop + 1).unwrap_or(0));
+            }
+
+            if let Some(planting) = plot.planted {
+                hasher.write_u8(planting.crop);
+                hasher.write_u32(planting.growth);
+            }
+        }
+
+        for timer in self.timers.iter() {
+            hasher.write_u64(timer.due);
+            hasher.write_u8(timer.action as u8);
+        }
+
+        if let Some(caravan) = self.caravans.caravan() {
+            hasher.write_u32(caravan.merchant.0);
+            hasher.write_u32(caravan.goods.len() as u32);
+        }
+
+        for job in self.jobs.iter() {
+            hasher.write_u32(job.progress);
+            hasher.write_u32(job.worker.map(|worker| worker.0 + 1).unwrap_or(0));
+        }
+
+        for (id, citizen) in self.citizens.iter() {
+            hasher.write_u32(id.0);
+            hasher.write_u32(citizen.stress);
+            hasher.write_u32(citizen.thoughts.len() as u32);
+            hasher.write_u32(citizen.breakdown.map(|breakdown| breakdown.remaining).unwrap_or(0));
+        }
+
+        for creature in self.creatures.iter() {
+            hasher.write_u32(creature.id.0);
+            hasher.write_u32(creature.position.x);
+            hasher.write_u32(creature.position.y);
+            hasher.write_f32(creature.body.blood_fraction());
+
+            for part in creature.body.parts() {
+                for tissue in &part.tissues {
+                    hasher.write_u8(tissue.health);
+                }
+            }
+        }
+
+        hasher.finish()
+    }
+
+    /// Places a new creature into the world.
+    /// It starts with no skills or equipment; set these through
+    /// `get_creature_mut`.
+    pub fn spawn_creature(
+        &mut self,
+        name: String,
+        symbol: char,
+        faction: Faction,
+        position: Point<u32>,
+        body: Body,
+    ) -> CreatureId {
+        self.creatures.spawn(name, symbol, faction, position, body)
+    }
+
+    /// Returns every creature in the world.
+    pub fn creatures(&self) -> &Creatures {
+        &self.creatures
+    }
+
+    /// Finds the creature with the id given.
+    pub fn get_creature(
+        &self,
+        id: CreatureId,
+    ) -> Option<&Creature> {
+        self.creatures.get(id)
+    }
+
+    /// Finds the creature with the id given, for changing.
+    pub fn get_creature_mut(
+        &mut self,
+        id: CreatureId,
+    ) -> Option<&mut Creature> {
+        self.creatures.get_mut(id)
+    }
+
+    /// Has one creature attack another, with whatever it is holding.
+    ///
+    /// A report of the attack is announced, and returned. If the defender is
+    /// killed then it is removed from the world. Returns None if either
+    /// creature no longer exists.
+    pub fn attack(
+        &mut self,
+        attacker_id: CreatureId,
+        defender_id: CreatureId,
+    ) -> Option<CombatReport> {
+        if attacker_id == defender_id {
+            return None;
+        }
+
+        let (attacker_name, attacker_skills, weapon) = {
+            let attacker = self.creatures.get(attacker_id)?;
+            (attacker.name.clone(), attacker.skills.clone(), attacker.equipment.weapon())
+        };
+
+        let report = {
+            let defender = self.creatures.get_mut(defender_id)?;
+            let outcome = resolve_attack(
+                &mut self.rng,
+                &attacker_skills,
+                weapon,
+                &defender.skills,
+                &defender.equipment,
+                &mut defender.body,
+            );
+
+            CombatReport {
+                attacker: attacker_name.clone(),
+                defender: defender.name.clone(),
+                weapon: weapon.name,
+                outcome: outcome,
+            }
+        };
+
+        self.push_event(GameEvent::Combat {
+            report: report.clone(),
+        });
+
+        if let AttackOutcome::Hit {
+            ..
+        } = report.outcome
+        {
+            self.citizens.think(defender_id, ThoughtKind::WasInjured);
+        }
+
+        if report.is_fatal() {
+            self.kill_creature(
+                defender_id,
+                DeathCause::Slain {
+                    by: attacker_name,
+                },
+            );
+        }
+
+        Some(report)
+    }
+
+    /// Bleeds every wounded creature, and removes any who die from it.
+    fn update_wounds(&mut self) {
+        let mut dead = Vec::new();
+
+        for creature in self.creatures.iter_mut() {
+            creature.body.bleed();
+
+            if creature.is_dead() {
+                dead.push(creature.id);
+            }
+        }
+
+        for id in dead {
+            self.kill_creature(id, DeathCause::BledOut);
+        }
+    }
+
+    /// Brings in new animals, and moves the existing ones along.
+    fn update_wildlife(&mut self) {
+        let season = self.get_season();
+
+        for _ in 0..self.wildlife.spawn_chances(self.ticks) {
+            self.wildlife.try_spawn(&self.map, &mut self.creatures, &mut self.rng, season);
+        }
+
+        let mut threats = vec![self.player.position];
+        threats.extend(
+            self.creatures
+                .iter()
+                .filter(|creature| creature.faction != Faction::Wild)
+                .map(|creature| creature.position),
+        );
+
+        self.wildlife.update(&mut self.map, &mut self.creatures, &mut self.rng, &threats, season);
+    }
+
+    /// Walks new migrants to the fortress, and updates everyone's mood.
+    fn update_citizens(&mut self) {
+        let fortress = self.player.position;
+
+        self.citizens.update(&self.map, &mut self.creatures, fortress);
+
+        let events = self.citizens.update_moods(&self.map, &mut self.creatures, &mut self.rng);
+        for event in events {
+            self.push_event(event);
+        }
+    }
+
+    /// Hands out jobs, and does the work.
+    fn update_jobs(&mut self) {
+        let events = self.jobs.update(
+            &mut self.map,
+            &mut self.creatures,
+            &mut self.citizens,
+            &mut self.items,
+            &mut self.buildings,
+            &mut self.rng,
+        );
+
+        for event in events {
+            self.push_event(event);
+        }
+    }
+
+    /// Returns every job waiting to be done, or being done.
+    pub fn jobs(&self) -> &Jobs {
+        &self.jobs
+    }
+
+    /// Returns every building in the fortress.
+    pub fn buildings(&self) -> &Buildings {
+        &self.buildings
+    }
+
+    /// Grows the crops, and asks for farm plots to be tended.
+    fn update_farms(&mut self) {
+        let season = self.get_season();
+        let events = self.buildings.farms_mut().update(&self.map, &mut self.jobs, season);
+
+        for event in events {
+            self.push_event(event);
+        }
+    }
+
+    /// Returns every farm plot, and the crops which can be grown on them.
+    pub fn farms(&self) -> &Farms {
+        self.buildings.farms()
+    }
+
+    /// Returns every crop which could be grown at the position given, during
+    /// the season given, as indexes into the farms' crops.
+    pub fn growable_crops(
+        &self,
+        position: Point<u32>,
+        season: Season,
+    ) -> Vec<u8> {
+        let farms = self.buildings.farms();
+
+        (0..farms.crops().len() as u8)
+            .filter(|&crop| farms.can_grow(&self.map, position, crop, season))
+            .collect()
+    }
+
+    /// Moves any visiting merchants along.
+    fn update_caravans(&mut self) {
+        let depot = self.buildings.find(BuildingKind::TradeDepot).map(|depot| depot.position);
+        let events = self.caravans.update(&self.map, &mut self.creatures, depot);
+
+        for event in events {
+            self.push_event(event);
+        }
+    }
+
+    /// Returns the caravan visiting the fortress, if there is one.
+    pub fn caravan(&self) -> Option<&Caravan> {
+        self.caravans.caravan()
+    }
+
+    /// Works out what the merchants would make of a deal, without making it.
+    ///
+    /// The masks pick out the fortress' items, and the merchants' goods, the
+    /// same as for `GameCommand::Trade`. Returns None if no merchants are at
+    /// the depot.
+    pub fn evaluate_trade(
+        &self,
+        offered: u64,
+        requested: u64,
+    ) -> Option<OfferEvaluation> {
+        self.caravans.evaluate(&self.items.selected(offered), requested)
+    }
+
+    /// Returns everything which has been made, or built.
+    pub fn items(&self) -> &Items {
+        &self.items
+    }
+
+    /// Returns the records for every citizen of the fortress.
+    pub fn citizens(&self) -> &Citizens {
+        &self.citizens
+    }
+
+    /// Returns how happy the fortress is, from 0.0 (miserable) to 1.0 (joyous).
+    ///
+    /// This is the average mood of every citizen.
+    pub fn happiness(&self) -> f32 {
+        if self.citizens.is_empty() {
+            return CONTENT_HAPPINESS;
+        }
+
+        let total = self
+            .citizens
+            .iter()
+            .map(|(_, citizen)| {
+                let mood = citizen.mood_value().clamp(-FULL_HAPPINESS_MOOD, FULL_HAPPINESS_MOOD);
+
+                (mood + FULL_HAPPINESS_MOOD) as f32 / (2 * FULL_HAPPINESS_MOOD) as f32
+            })
+            .sum::<f32>();
+
+        total / self.citizens.len() as f32
+    }
+
+    /// Moves any invaders along, and carries out the fighting.
+    fn update_invasions(&mut self) {
+        let fortress = self.player.position;
+        let update = self.invasions.update(&self.map, &mut self.creatures, &mut self.rng, fortress);
+
+        for (attacker, defender) in update.attacks {
+            self.attack(attacker, defender);
+        }
+
+        if let Some(outcome) = update.ended {
+            self.push_event(GameEvent::SiegeEnded {
+                outcome: outcome,
+            });
+        }
+    }
+
+    /// Returns true if invaders are attacking the fortress right now.
+    pub fn is_under_siege(&self) -> bool {
+        self.invasions.is_under_siege()
+    }
+
+    /// Returns how much the fortress is worth, in total.
+    ///
+    /// This is the value of everything which has been made, or built.
+    pub fn wealth(&self) -> u32 {
+        self.items.wealth().total()
+    }
+
+    /// Returns how much the fortress is worth, split up by category.
+    pub fn wealth_by_category(&self) -> &Wealth {
+        self.items.wealth()
+    }
+
+    /// Removes the creature from the world, and announces it's death.
+    ///
+    /// Citizens who are close enough to see it are upset. If it was one of
+    /// their own, then the whole fortress mourns.
+    fn kill_creature(
+        &mut self,
+        id: CreatureId,
+        cause: DeathCause,
+    ) {
+        if let Some(creature) = self.creatures.remove(id) {
+            let view_distance = u64::from(self.game_setup.view_distance);
+            let citizens = self.citizens.iter().map(|(&id, _)| id).collect::<Vec<CreatureId>>();
+
+            for citizen in citizens {
+                if creature.faction == Faction::Fortress {
+                    self.citizens.think(citizen, ThoughtKind::LostFriend);
+                }
+
+                let can_see = self.creatures.get(citizen).map(|witness| {
+                    distance_squared(witness.position, creature.position)
+                        <= view_distance * view_distance
+                });
+
+                if can_see == Some(true) {
+                    self.citizens.think(citizen, ThoughtKind::SawDeath);
+                }
+            }
+
+            self.push_event(GameEvent::CreatureDied {
+                name: creature.name,
+                faction: creature.faction,
+                cause: cause,
+            });
+        }
+    }
+
+    /// Announces when the season changes.
+    fn update_season(&mut self) {
+        let season = self.get_season();
+
+        if season != self.season {
+            self.season = season;
+            self.push_event(GameEvent::SeasonChanged {
+                season: season,
+            });
+        }
+    }
+
+    /// Sets up the timers for migrants, merchants, and invaders, to visit at
+    /// the start of each season. Whether they come or not is decided when
+    /// the season starts.
+    fn schedule_seasonal_visits(&mut self) {
+        if let Some(due) = next_season_start(self.world_setup.calendar, self.time) {
+            for &action in &TimedAction::ALL {
+                self.timers.schedule(due, Recurrence::EachSeason, action);
+            }
+        }
+    }
+
+    /// Sets off every timer which is due, in the order they're due.
+    fn update_timers(&mut self) {
+        let actions = self.timers.take_due(self.world_setup.calendar, self.time);
+        let season = self.get_season();
+
+        for action in actions {
+            match action {
+                TimedAction::Migrants => self.arrive_migrants(season),
+                TimedAction::Caravan => self.arrive_caravan(season),
+                TimedAction::Siege => self.begin_siege(season),
+            }
+        }
+    }
+
+    /// Returns every timer waiting to go off, soonest first.
+    pub fn timers(&self) -> &Timers {
+        &self.timers
+    }
+
+    /// Replaces every timer, such as with ones read back in with
+    /// `Timers::read`.
+    pub fn set_timers(
+        &mut self,
+        timers: Timers,
+    ) {
+        self.timers = timers;
+    }
+
+    /// Adds a timer, due to go off at the game time given.
+    pub fn schedule(
+        &mut self,
+        due: u64,
+        recurrence: Recurrence,
+        action: TimedAction,
+    ) {
+        self.timers.schedule(due, recurrence, action);
+    }
+
+    /// Brings in a wave of migrants, if any want to come.
+    fn arrive_migrants(
+        &mut self,
+        season: Season,
+    ) {
+        let wealth = self.wealth();
+        let happiness = self.happiness();
+
+        let size = plan_wave(&mut self.rng, season, wealth, happiness);
+        if size == 0 {
+            return;
+        }
+
+        let migrants =
+            self.citizens.spawn_wave(&self.map, &mut self.creatures, &mut self.rng, size);
+        if !migrants.is_empty() {
+            self.push_event(GameEvent::MigrantsArrived {
+                count: migrants.len(),
+            });
+        }
+    }
+
+    /// Sends merchants, if it's the time of year for them.
+    fn arrive_caravan(
+        &mut self,
+        season: Season,
+    ) {
+        let event =
+            self.caravans.on_season_start(&self.map, &mut self.creatures, &mut self.rng, season);
+
+        if let Some(event) = event {
+            self.push_event(event);
+        }
+    }
+
+    /// Starts a siege, if the invaders decide to come.
+    fn begin_siege(
+        &mut self,
+        season: Season,
+    ) {
+        let wealth = self.wealth();
+        let siege = self.invasions.on_season_start(
+            &self.map,
+            &mut self.creatures,
+            &mut self.rng,
+            season,
+            wealth,
+        );
+
+        if let Some(invaders) = siege {
+            self.push_event(GameEvent::SiegeBegan {
+                invaders: invaders,
+            });
+        }
+    }
+
+    /// Adds an event to the log, stamped with the current time.
+    pub(crate) fn push_event(
+        &mut self,
+        event: GameEvent,
+    ) {
+        self.events.push(self.ticks, self.time, event);
+    }
+
+    /// Removes, and returns, all of the events which have happened since the
+    /// last drain. Oldest first.
+    ///
+    /// Only a limited number of events are held. If they are not drained
+    /// regularly, then the oldest will be lost.
+    pub fn drain_events(&mut self) -> Drain<'_, TimedEvent> {
+        self.events.drain()
+    }
+
+    /// Recalculates the light from the sky, for the current time of day.
+    fn update_lighting(&mut self) {
+        let time = self.get_time();
+        let season = self.get_season();
+
+        self.lighting.update(time, season);
+    }
+
+    /// Moves the weather on, and settles any snow and rain on the map.
+    fn update_weather(&mut self) {
+        let events = self.weathering.update(&mut self.map, self.world_setup.calendar, self.time);
+
+        for event in events {
+            self.push_event(event);
+        }
+
+        if self.weathering.report().weather.precipitation == Precipitation::Rain {
+            self.soak_idle_citizens();
+        }
+    }
+
+    /// Has every citizen who is resting out in the open, whilst it rains,
+    /// think about it. Those busy working, or still arriving, don't mind.
+    fn soak_idle_citizens(&mut self) {
+        let soaked = self
+            .citizens
+            .iter()
+            .map(|(&id, _)| id)
+            .filter(|&id| !self.citizens.is_arriving(id) && self.jobs.job_for(id).is_none())
+            .filter(|&id| {
+                self.creatures
+                    .get(id)
+                    .is_some_and(|creature| is_exposed(&self.map, creature.position))
+            })
+            .collect::<Vec<CreatureId>>();
+
+        for id in soaked {
+            self.citizens.think(id, ThoughtKind::SleptInRain);
+        }
+    }
+
+    /// Returns the current weather, out in the open.
+    pub fn weather(&self) -> &WeatherReport {
+        self.weathering.report()
+    }
+
+    /// Returns the biome the position given is in.
+    pub fn biome_at(
+        &self,
+        x: u32,
+        y: u32,
+    ) -> Biome {
+        self.map.get(x, y).biome
+    }
+
+    /// Returns the current weather at the position given.
+    ///
+    /// This can differ from the weather out in the open. It's colder up on
+    /// the hills, and underground it's sheltered from the sky.
+    pub fn weather_at(
+        &self,
+        x: u32,
+        y: u32,
+    ) -> WeatherReport {
+        let location = self.weathering.location_at(&self.map, Point::new(x, y));
+
+        WeatherReport::generate_report(
+            self.world_setup.calendar,
+            self.game_setup.seed,
+            self.time,
+            &location,
+        )
+    }
+
+    /// Recalculates what the player can see from where they are standing.
+    pub fn update_field_of_view(&mut self) {
+        let map = &self.map;
+
+        self.fog_of_war.update(self.player.position, self.game_setup.view_distance, |x, y| {
+            map.get(x, y).tile.is_opaque()
+        });
+    }
+
+    /// Returns how much the player can see of the tile at the position given.
+    pub fn get_visibility(
+        &self,
+        x: u32,
+        y: u32,
+    ) -> Visibility {
+        self.fog_of_war.get(x, y)
     }
 
     /// Returns the current time in the world.
@@ -76,6 +1091,43 @@ impl<'a> Game<'a> {
         self.world_setup.calendar.get_time(self.time)
     }
 
+    /// Returns the current season in the world.
+    pub fn get_season(&self) -> Season {
+        self.world_setup.calendar.get_season(self.time)
+    }
+
+    /// Describes the current date, for showing to the player.
+    pub fn format_date(&self) -> String {
+        self.world_setup.calendar.format_date(self.time)
+    }
+
+    /// Places a new light, like a torch, into the world.
+    pub fn add_light_source(
+        &mut self,
+        source: LightSource,
+    ) {
+        self.lighting.add_source(source);
+    }
+
+    /// Removes any lights placed at the position given.
+    pub fn remove_light_sources_at(
+        &mut self,
+        x: u32,
+        y: u32,
+    ) {
+        self.lighting.remove_sources_at(x, y);
+    }
+
+    /// Returns how brightly lit the tile is at the position given.
+    /// From 0.0 (pitch black) to 1.0 (full light).
+    pub fn get_light_level(
+        &self,
+        x: u32,
+        y: u32,
+    ) -> f32 {
+        self.lighting.light_at(x, y)
+    }
+
     /// Returns a slice of the maps terrain.
     pub fn slice(
         &self,
@@ -87,3 +1139,100 @@ impl<'a> Game<'a> {
         self.map.slice(x, y, w, h)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scheduler::SystemEntry;
+    use world::calendar::Calendar;
+
+    fn new_game<'a>(
+        calendar: &'a Calendar,
+        systems: Vec<SystemEntry>,
+    ) -> Game<'a> {
+        Game::new(
+            &Map::new(10, 10, Tile::Grass),
+            Player::new(5, 5),
+            WorldSetup {
+                calendar: calendar,
+                biome: Biome::Grassland,
+                biomes: None,
+                latitude: 30.0,
+                elevation: 0.0,
+            },
+            GameSetup {
+                time_tick_speed: 60,
+                view_distance: 8,
+                seed: 3,
+                systems: systems,
+            },
+        )
+    }
+
+    #[test]
+    fn weather_settles_the_same_however_often_it_runs() {
+        let calendar = Calendar::standard();
+        let mut every_tick = new_game(&calendar, vec![SystemEntry::new(System::Weather, 1, 0)]);
+        let mut every_ten = new_game(&calendar, vec![SystemEntry::new(System::Weather, 10, 0)]);
+
+        // Running less often samples the weather less often, so layers can
+        // settle a little earlier, or later, but no more than that.
+        let mut settled = false;
+        for _ in 0..(24 * 60 * 3) {
+            every_tick.tick();
+            every_ten.tick();
+
+            if every_ten.ticks() % 10 == 0 {
+                for y in 0..10 {
+                    for x in 0..10 {
+                        let tile = every_tick.map.get(x, y);
+                        let other = every_ten.map.get(x, y);
+
+                        assert!((i32::from(tile.snow) - i32::from(other.snow)).abs() <= 1);
+                        assert!((i32::from(tile.puddle) - i32::from(other.puddle)).abs() <= 1);
+                        settled |= tile.snow > 0 || tile.puddle > 0;
+                    }
+                }
+            }
+        }
+
+        assert!(settled);
+    }
+
+    #[test]
+    fn citizens_resting_in_the_rain_are_soaked() {
+        let calendar = Calendar::standard();
+        let mut game = new_game(&calendar, vec![SystemEntry::new(System::Weather, 1, 0)]);
+
+        let ids = game.citizens.spawn_wave(&game.map, &mut game.creatures, &mut game.rng, 2);
+        for _ in 0..20 {
+            game.citizens.update(&game.map, &mut game.creatures, Point::new(5, 5));
+        }
+
+        // One shelters in a doorway, out of the rain.
+        let sheltered = game.creatures.get(ids[1]).unwrap().position;
+        game.map.set(sheltered.x, sheltered.y, GameTile::new(Tile::Door));
+
+        let is_soaked = |game: &Game, id| {
+            let citizen = game.citizens.get(id).unwrap();
+
+            citizen.thoughts.iter().any(|thought| thought.kind == ThoughtKind::SleptInRain)
+        };
+
+        let mut rained = false;
+        for _ in 0..(24 * 60 * 30) {
+            game.tick();
+
+            if game.weather().weather.precipitation == Precipitation::Rain {
+                rained = true;
+                break;
+            }
+
+            assert!(!is_soaked(&game, ids[0]));
+        }
+
+        assert!(rained);
+        assert!(is_soaked(&game, ids[0]));
+        assert!(!is_soaked(&game, ids[1]));
+    }
+}
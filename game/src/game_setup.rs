@@ -1,3 +1,5 @@
+use scheduler::SystemEntry;
+
 /// Describes parameters for the running of the game.
 ///
 /// This does not include data like creatures, the player,
@@ -11,4 +13,18 @@ pub struct GameSetup {
     /// This is how much to increment by. In seconds.
     /// 
     pub time_tick_speed: u32,
+
+    /// How far the player can see, in tiles.
+    /// 
+    pub view_distance: u32,
+
+    /// The seed for all of the randomness in the game.
+    /// The same seed, and the same commands, will always play out the same.
+    /// 
+    pub seed: u64,
+
+    /// Which systems run as the game ticks, how often, and in what order.
+    /// `default_systems` gives every system, running every tick.
+    /// 
+    pub systems: Vec<SystemEntry>,
 }
@@ -1,3 +1,4 @@
+use world::biomes::Biome;
 use world::tiles::Tile;
 
 /// A tile within the game. This wraps the worlds tiles in order to add more
@@ -6,13 +7,28 @@ use world::tiles::Tile;
 pub struct GameTile {
     /// The land for this tile.
     pub tile: Tile,
+
+    /// The kind of land this tile is in.
+    pub biome: Biome,
+
+    /// How deep the snow lying on this tile is, in layers.
+    /// Zero if there is no snow.
+    pub snow: u8,
+
+    /// How deep the puddle on this tile is.
+    /// Zero if there is no puddle.
+    pub puddle: u8,
 }
 
 impl GameTile {
     /// Trivial constructor.
+    /// The tile starts out in grassland.
     pub fn new(tile: Tile) -> GameTile {
         GameTile {
             tile: tile,
+            biome: Biome::Grassland,
+            snow: 0,
+            puddle: 0,
         }
     }
 }
@@ -0,0 +1,113 @@
+use combat::Armour;
+use combat::DamageKind;
+use combat::Shield;
+use combat::Weapon;
+
+/// A kind of invader, and what they bring with them.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Invader {
+    /// What they are called.
+    pub name: &'static str,
+
+    /// The character they are drawn with.
+    pub symbol: char,
+
+    /// How big they are. A dwarf is 10.
+    pub size: u8,
+
+    /// What they fight with.
+    pub weapon: Weapon,
+
+    /// What they wear.
+    pub armour: Option<Armour>,
+
+    /// What they block with.
+    pub shield: Option<Shield>,
+
+    /// How good they are at landing blows.
+    pub fighting: u8,
+
+    /// How good they are at getting out of the way.
+    pub dodging: u8,
+
+    /// How good they are with a shield.
+    pub shield_skill: u8,
+}
+
+/// Leads every invasion.
+pub const GOBLIN_LEADER: Invader = Invader {
+    name: "goblin lasher",
+    symbol: 'G',
+    size: 10,
+    weapon: Weapon {
+        name: "whip",
+        damage: 6,
+        kind: DamageKind::Edge,
+    },
+    armour: Some(Armour {
+        name: "mail shirt",
+        protection: 4,
+    }),
+    shield: None,
+    fighting: 10,
+    dodging: 8,
+    shield_skill: 0,
+};
+
+/// The rank and file, picked from at random.
+pub const GOBLIN_SOLDIERS: [Invader; 3] = [
+    Invader {
+        name: "goblin axeman",
+        symbol: 'g',
+        size: 9,
+        weapon: Weapon {
+            name: "axe",
+            damage: 7,
+            kind: DamageKind::Edge,
+        },
+        armour: Some(Armour {
+            name: "leather armour",
+            protection: 2,
+        }),
+        shield: None,
+        fighting: 6,
+        dodging: 4,
+        shield_skill: 0,
+    },
+    Invader {
+        name: "goblin spearman",
+        symbol: 'g',
+        size: 9,
+        weapon: Weapon {
+            name: "spear",
+            damage: 6,
+            kind: DamageKind::Edge,
+        },
+        armour: None,
+        shield: Some(Shield {
+            name: "buckler",
+            block: 15,
+        }),
+        fighting: 5,
+        dodging: 4,
+        shield_skill: 5,
+    },
+    Invader {
+        name: "goblin maceman",
+        symbol: 'g',
+        size: 9,
+        weapon: Weapon {
+            name: "mace",
+            damage: 7,
+            kind: DamageKind::Blunt,
+        },
+        armour: Some(Armour {
+            name: "leather armour",
+            protection: 2,
+        }),
+        shield: None,
+        fighting: 5,
+        dodging: 3,
+        shield_skill: 0,
+    },
+];
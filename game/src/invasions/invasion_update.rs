@@ -0,0 +1,12 @@
+use creatures::CreatureId;
+use invasions::SiegeOutcome;
+
+/// What the invasion wants to happen, after it has been updated.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InvasionUpdate {
+    /// Attacks to carry out, as attacker and defender.
+    pub attacks: Vec<(CreatureId, CreatureId)>,
+
+    /// Set if the siege came to an end.
+    pub ended: Option<SiegeOutcome>,
+}
@@ -0,0 +1,458 @@
+use util::random::Random;
+use util::shapes::Point;
+use world::calendar::Season;
+use world::map::Map;
+
+use combat::Body;
+use combat::BodyPlan;
+use creatures::CreatureId;
+use creatures::Creatures;
+use creatures::Faction;
+use invasions::invader::Invader;
+use invasions::invader::GOBLIN_LEADER;
+use invasions::invader::GOBLIN_SOLDIERS;
+use invasions::siege::Siege;
+use invasions::InvasionUpdate;
+use invasions::SiegeOutcome;
+use navigation::direction_to_nearest_edge;
+use navigation::distance_squared;
+use navigation::find_edge_positions;
+use navigation::free_neighbours;
+use navigation::is_free;
+use navigation::is_on_edge;
+use navigation::step;
+use navigation::step_towards;
+use skills::Skill;
+use GameTile;
+
+/// Fortresses worth less than this are not worth attacking.
+const MIN_SIEGE_WEALTH: u32 = 500;
+
+/// How much wealth adds one percent to the chance of a siege.
+const WEALTH_PER_PERCENT: u32 = 200;
+
+/// The highest chance of a siege, in percent, each season.
+const MAX_SIEGE_CHANCE: u32 = 80;
+
+/// How much wealth adds one more soldier to a siege.
+const WEALTH_PER_SOLDIER: u32 = 1000;
+
+/// The fewest, and the most, soldiers in a siege. Not counting the leader.
+const MIN_SOLDIERS: u32 = 2;
+const MAX_SOLDIERS: u32 = 12;
+
+/// The percentage chance an invader acts each tick.
+const INVADER_ACT_CHANCE: u32 = 50;
+
+/// The percentage chance a citizen next to an invader fights back each tick.
+const DEFENDER_ACT_CHANCE: u32 = 50;
+
+/// How close invaders gather around the fortress, when there is no one to
+/// fight. In tiles.
+const GATHER_DISTANCE: u64 = 3;
+
+/// Decides when invaders arrive, and runs them whilst they are here.
+pub struct Invasions {
+    /// The siege happening right now, if there is one.
+    siege: Option<Siege>,
+
+    /// Invaders who have given up, and are heading for the edge of the map.
+    retreating: Vec<CreatureId>,
+}
+
+impl Invasions {
+    /// Creates a new scheduler, with no invasion happening.
+    pub fn new() -> Invasions {
+        Invasions {
+            siege: None,
+            retreating: Vec::new(),
+        }
+    }
+
+    /// Returns true if there is a siege happening right now.
+    pub fn is_under_siege(&self) -> bool {
+        self.siege.is_some()
+    }
+
+    /// Returns the invaders currently besieging the fortress.
+    pub fn invaders(&self) -> &[CreatureId] {
+        match self.siege {
+            Some(ref siege) => &siege.invaders,
+            None => &[],
+        }
+    }
+
+    /// Called at the start of each season, to decide if a siege begins.
+    ///
+    /// Sieges never start in winter, or against a poor fortress. The richer
+    /// the fortress, the more likely, and the bigger, the siege. Returns the
+    /// number of invaders if one begins.
+    pub fn on_season_start(
+        &mut self,
+        map: &Map<GameTile>,
+        creatures: &mut Creatures,
+        rng: &mut Random,
+        season: Season,
+        wealth: u32,
+    ) -> Option<usize> {
+        if self.siege.is_some() || season == Season::Winter || wealth < MIN_SIEGE_WEALTH {
+            return None;
+        }
+
+        let chance = (wealth / WEALTH_PER_PERCENT).min(MAX_SIEGE_CHANCE);
+        if rng.range(0, 100) >= chance {
+            return None;
+        }
+
+        let group = choose_group(rng, wealth);
+        let invaders = spawn_group(map, creatures, rng, &group);
+        if invaders.is_empty() {
+            return None;
+        }
+
+        let size = invaders.len();
+        self.siege = Some(Siege {
+            invaders: invaders,
+            size: size,
+        });
+
+        Some(size)
+    }
+
+    /// Moves the invaders along.
+    ///
+    /// Invaders head for the nearest citizen, and attack when they are next to
+    /// them. With no citizens about they gather around the fortress. Citizens
+    /// next to an invader fight back. Once half of the invaders are lost, the
+    /// rest retreat off the map.
+    pub fn update(
+        &mut self,
+        map: &Map<GameTile>,
+        creatures: &mut Creatures,
+        rng: &mut Random,
+        fortress: Point<u32>,
+    ) -> InvasionUpdate {
+        let mut attacks = Vec::new();
+        let ended = self.check_siege(creatures);
+
+        self.update_retreating(map, creatures, rng, fortress);
+
+        if let Some(ref siege) = self.siege {
+            for &id in &siege.invaders {
+                if rng.range(0, 100) >= INVADER_ACT_CHANCE {
+                    continue;
+                }
+
+                if let Some(target) = invader_action(map, creatures, id, fortress) {
+                    attacks.push((id, target));
+                }
+            }
+
+            for defender in
+                creatures.iter().filter(|creature| creature.faction == Faction::Fortress)
+            {
+                let target = siege
+                    .invaders
+                    .iter()
+                    .filter_map(|&id| creatures.get(id))
+                    .find(|invader| is_adjacent(invader.position, defender.position));
+
+                if let Some(target) = target {
+                    if rng.range(0, 100) < DEFENDER_ACT_CHANCE {
+                        attacks.push((defender.id, target.id));
+                    }
+                }
+            }
+        }
+
+        InvasionUpdate {
+            attacks: attacks,
+            ended: ended,
+        }
+    }
+
+    /// Removes the dead from the siege, and checks if it has ended.
+    fn check_siege(
+        &mut self,
+        creatures: &Creatures,
+    ) -> Option<SiegeOutcome> {
+        let outcome = {
+            let siege = self.siege.as_mut()?;
+            siege.invaders.retain(|&id| creatures.get(id).is_some());
+
+            if siege.invaders.is_empty() {
+                SiegeOutcome::Defeated
+            } else if siege.losses() * 2 >= siege.size {
+                SiegeOutcome::Retreated
+            } else {
+                return None;
+            }
+        };
+
+        if let Some(siege) = self.siege.take() {
+            self.retreating.extend(siege.invaders);
+        }
+
+        Some(outcome)
+    }
+
+    /// Moves retreating invaders towards the edge, and off the map.
+    fn update_retreating(
+        &mut self,
+        map: &Map<GameTile>,
+        creatures: &mut Creatures,
+        rng: &mut Random,
+        fortress: Point<u32>,
+    ) {
+        let retreating = self.retreating.drain(..).collect::<Vec<CreatureId>>();
+
+        for id in retreating {
+            let position = match creatures.get(id) {
+                Some(creature) => creature.position,
+                None => continue,
+            };
+
+            if is_on_edge(map, position) {
+                creatures.remove(id);
+                continue;
+            }
+
+            let blocked = [fortress];
+            let next = match step(map, position, direction_to_nearest_edge(map, position)) {
+                Some(next) if is_free(map, creatures, &blocked, next) => next,
+                _ => {
+                    rng.pick(&free_neighbours(map, creatures, &blocked, position))
+                        .cloned()
+                        .unwrap_or(position)
+                },
+            };
+
+            if let Some(creature) = creatures.get_mut(id) {
+                creature.position = next;
+            }
+
+            self.retreating.push(id);
+        }
+    }
+}
+
+/// Picks who will be in the siege.
+/// There is always a leader, and some number of soldiers.
+fn choose_group(
+    rng: &mut Random,
+    wealth: u32,
+) -> Vec<Invader> {
+    let soldiers = (MIN_SOLDIERS + wealth / WEALTH_PER_SOLDIER).min(MAX_SOLDIERS);
+    let mut group = vec![GOBLIN_LEADER];
+
+    for _ in 0..soldiers {
+        if let Some(&soldier) = rng.pick(&GOBLIN_SOLDIERS) {
+            group.push(soldier);
+        }
+    }
+
+    group
+}
+
+/// Places the group along one edge of the map, near to each other.
+/// Returns the ids of those who could be placed.
+fn spawn_group(
+    map: &Map<GameTile>,
+    creatures: &mut Creatures,
+    rng: &mut Random,
+    group: &[Invader],
+) -> Vec<CreatureId> {
+    let positions = find_edge_positions(map, creatures, rng, group.len());
+    let mut placed = Vec::new();
+
+    for (invader, position) in group.iter().zip(positions) {
+        let id = creatures.spawn(
+            invader.name.to_string(),
+            invader.symbol,
+            Faction::Hostile,
+            position,
+            Body::new(BodyPlan::Humanoid, invader.size),
+        );
+
+        if let Some(creature) = creatures.get_mut(id) {
+            creature.equipment.weapon = Some(invader.weapon);
+            creature.equipment.armour = invader.armour;
+            creature.equipment.shield = invader.shield;
+            creature.skills.set_level(Skill::Fighting, invader.fighting);
+            creature.skills.set_level(Skill::Dodging, invader.dodging);
+            creature.skills.set_level(Skill::Shield, invader.shield_skill);
+        }
+
+        placed.push(id);
+    }
+
+    placed
+}
+
+/// Moves an invader towards it's target.
+/// Returns who it attacks, if it is close enough to attack.
+fn invader_action(
+    map: &Map<GameTile>,
+    creatures: &mut Creatures,
+    id: CreatureId,
+    fortress: Point<u32>,
+) -> Option<CreatureId> {
+    let position = creatures.get(id)?.position;
+
+    let target = creatures
+        .iter()
+        .filter(|creature| creature.faction == Faction::Fortress)
+        .min_by_key(|creature| distance_squared(position, creature.position))
+        .map(|creature| (creature.id, creature.position));
+
+    let destination = match target {
+        Some((target_id, target_position)) => {
+            if is_adjacent(position, target_position) {
+                return Some(target_id);
+            }
+
+            target_position
+        },
+        None if distance_squared(position, fortress) <= GATHER_DISTANCE * GATHER_DISTANCE => {
+            return None
+        },
+        None => fortress,
+    };
+
+    if let Some(next) = step_towards(map, creatures, &[fortress], position, destination) {
+        if let Some(creature) = creatures.get_mut(id) {
+            creature.position = next;
+        }
+    }
+
+    None
+}
+
+/// Returns true if the two positions are next to each other, including
+/// diagonally.
+fn is_adjacent(
+    a: Point<u32>,
+    b: Point<u32>,
+) -> bool {
+    a != b && distance_squared(a, b) <= 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use world::tiles::Tile;
+
+    fn open_map() -> Map<GameTile> {
+        Map::new(30, 30, GameTile::new(Tile::Ground))
+    }
+
+    /// Keeps trying seasons until a siege starts.
+    fn begin_siege(
+        invasions: &mut Invasions,
+        map: &Map<GameTile>,
+        creatures: &mut Creatures,
+        rng: &mut Random,
+    ) -> usize {
+        for _ in 0..100 {
+            if let Some(size) = invasions.on_season_start(map, creatures, rng, Season::Summer, 5000)
+            {
+                return size;
+            }
+        }
+
+        panic!("no siege started");
+    }
+
+    #[test]
+    fn no_sieges_in_winter_or_against_the_poor() {
+        let map = open_map();
+        let mut creatures = Creatures::new();
+        let mut invasions = Invasions::new();
+        let mut rng = Random::new(5);
+
+        for _ in 0..100 {
+            assert!(invasions
+                .on_season_start(&map, &mut creatures, &mut rng, Season::Winter, 50000)
+                .is_none());
+            assert!(invasions
+                .on_season_start(&map, &mut creatures, &mut rng, Season::Summer, 100)
+                .is_none());
+        }
+
+        assert!(creatures.is_empty());
+    }
+
+    #[test]
+    fn richer_fortresses_get_bigger_sieges() {
+        let map = open_map();
+        let mut rng = Random::new(5);
+
+        let poor = choose_group(&mut rng, MIN_SIEGE_WEALTH).len();
+        let rich = choose_group(&mut rng, 10000).len();
+
+        assert!(rich > poor);
+        assert_eq!(poor as u32, MIN_SOLDIERS + 1);
+
+        let mut creatures = Creatures::new();
+        let mut invasions = Invasions::new();
+        let size = begin_siege(&mut invasions, &map, &mut creatures, &mut rng);
+
+        assert_eq!(creatures.len(), size);
+        assert!(creatures.iter().all(|creature| {
+            creature.faction == Faction::Hostile && is_on_edge(&map, creature.position)
+        }));
+    }
+
+    #[test]
+    fn invaders_head_for_the_fortress() {
+        let map = open_map();
+        let mut creatures = Creatures::new();
+        let mut invasions = Invasions::new();
+        let mut rng = Random::new(9);
+        let fortress = Point::new(15, 15);
+        begin_siege(&mut invasions, &map, &mut creatures, &mut rng);
+
+        let starts = creatures
+            .iter()
+            .map(|creature| distance_squared(creature.position, fortress))
+            .collect::<Vec<u64>>();
+
+        for _ in 0..200 {
+            invasions.update(&map, &mut creatures, &mut rng, fortress);
+        }
+
+        let ends = creatures
+            .iter()
+            .map(|creature| distance_squared(creature.position, fortress))
+            .collect::<Vec<u64>>();
+
+        assert!(ends.iter().zip(starts.iter()).all(|(end, start)| end < start));
+        assert!(ends.iter().any(|&end| end <= GATHER_DISTANCE * GATHER_DISTANCE));
+    }
+
+    #[test]
+    fn invaders_retreat_after_heavy_losses() {
+        let map = open_map();
+        let mut creatures = Creatures::new();
+        let mut invasions = Invasions::new();
+        let mut rng = Random::new(9);
+        let fortress = Point::new(15, 15);
+        let size = begin_siege(&mut invasions, &map, &mut creatures, &mut rng);
+
+        let fallen = invasions.invaders()[..size.div_ceil(2)].to_vec();
+        for id in fallen {
+            creatures.remove(id);
+        }
+
+        let update = invasions.update(&map, &mut creatures, &mut rng, fortress);
+        assert_eq!(update.ended, Some(SiegeOutcome::Retreated));
+        assert!(!invasions.is_under_siege());
+
+        for _ in 0..100 {
+            invasions.update(&map, &mut creatures, &mut rng, fortress);
+        }
+
+        assert!(creatures.is_empty());
+    }
+}
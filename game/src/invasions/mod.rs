@@ -0,0 +1,15 @@
+//! Invasions.
+//!
+//! Deciding when hostile groups turn up to besiege the fortress, who is in
+//! them, and how they fight.
+//!
+
+mod invader;
+mod invasion_update;
+mod invasions;
+mod siege;
+mod siege_outcome;
+
+pub use self::invasion_update::InvasionUpdate;
+pub use self::invasions::Invasions;
+pub use self::siege_outcome::SiegeOutcome;
@@ -0,0 +1,18 @@
+use creatures::CreatureId;
+
+/// A group of invaders currently attacking the fortress.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Siege {
+    /// The invaders who are still alive.
+    pub invaders: Vec<CreatureId>,
+
+    /// How many invaders there were at the start.
+    pub size: usize,
+}
+
+impl Siege {
+    /// Returns how many invaders have been lost so far.
+    pub fn losses(&self) -> usize {
+        self.size - self.invaders.len()
+    }
+}
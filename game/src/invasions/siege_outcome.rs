@@ -0,0 +1,9 @@
+/// How a siege came to an end.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SiegeOutcome {
+    /// Every invader was killed.
+    Defeated,
+
+    /// The invaders lost too many, and ran.
+    Retreated,
+}
@@ -0,0 +1,24 @@
+use util::shapes::Point;
+
+use items::ConstructionKind;
+use items::Quality;
+
+/// Something which has been built into the map.
+#[derive(Copy, Clone, Debug)]
+pub struct Construction {
+    /// What was built.
+    pub kind: ConstructionKind,
+
+    /// How well it was built.
+    pub quality: Quality,
+
+    /// Where it is.
+    pub position: Point<u32>,
+}
+
+impl Construction {
+    /// How much it's worth, taking it's quality into account.
+    pub fn value(&self) -> u32 {
+        self.kind.base_value() * self.quality.value_multiplier()
+    }
+}
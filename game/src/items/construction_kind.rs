@@ -0,0 +1,47 @@
+use world::tiles::Tile;
+
+use wealth::WealthCategory;
+
+/// The different things which can be built into the map.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ConstructionKind {
+    /// A stone wall.
+    Wall,
+}
+
+impl ConstructionKind {
+    /// Finds the construction with the name given.
+    /// i.e. 'wall'.
+    pub fn from_name(name: &str) -> Option<ConstructionKind> {
+        match name {
+            "wall" => Some(ConstructionKind::Wall),
+            _ => None,
+        }
+    }
+
+    /// A human readable name for the construction.
+    pub fn name(self) -> &'static str {
+        match self {
+            ConstructionKind::Wall => "wall",
+        }
+    }
+
+    /// How much an ordinary one is worth.
+    pub fn base_value(self) -> u32 {
+        match self {
+            ConstructionKind::Wall => 5,
+        }
+    }
+
+    /// Which part of the fortress' wealth this counts towards.
+    pub fn wealth_category(self) -> WealthCategory {
+        WealthCategory::Architecture
+    }
+
+    /// The tile this turns into, once built.
+    pub fn tile(self) -> Tile {
+        match self {
+            ConstructionKind::Wall => Tile::Wall,
+        }
+    }
+}
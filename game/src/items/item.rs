@@ -0,0 +1,24 @@
+use util::shapes::Point;
+
+use items::ItemKind;
+use items::Quality;
+
+/// A single thing lying somewhere in the world.
+#[derive(Copy, Clone, Debug)]
+pub struct Item {
+    /// What it is.
+    pub kind: ItemKind,
+
+    /// How well it was made.
+    pub quality: Quality,
+
+    /// Where it is.
+    pub position: Point<u32>,
+}
+
+impl Item {
+    /// How much it's worth, taking it's quality into account.
+    pub fn value(&self) -> u32 {
+        self.kind.base_value() * self.quality.value_multiplier()
+    }
+}
@@ -0,0 +1,92 @@
+use wealth::WealthCategory;
+
+/// The different things which can be made, or found.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ItemKind {
+    /// A rough stone, from mining.
+    Stone,
+
+    /// A log, from chopping down a tree.
+    Log,
+
+    /// Something to eat at.
+    Table,
+
+    /// Something to sit on.
+    Chair,
+
+    /// Something to sleep in.
+    Bed,
+
+    /// Something to eat, harvested from a farm plot.
+    Food,
+}
+
+impl ItemKind {
+    /// Every kind of item, in order.
+    pub const ALL: [ItemKind; 6] = [
+        ItemKind::Stone,
+        ItemKind::Log,
+        ItemKind::Table,
+        ItemKind::Chair,
+        ItemKind::Bed,
+        ItemKind::Food,
+    ];
+
+    /// Finds the item kind with the name given.
+    /// i.e. 'table'.
+    pub fn from_name(name: &str) -> Option<ItemKind> {
+        match name {
+            "stone" => Some(ItemKind::Stone),
+            "log" => Some(ItemKind::Log),
+            "table" => Some(ItemKind::Table),
+            "chair" => Some(ItemKind::Chair),
+            "bed" => Some(ItemKind::Bed),
+            "food" => Some(ItemKind::Food),
+            _ => None,
+        }
+    }
+
+    /// A human readable name for the item.
+    pub fn name(self) -> &'static str {
+        match self {
+            ItemKind::Stone => "stone",
+            ItemKind::Log => "log",
+            ItemKind::Table => "table",
+            ItemKind::Chair => "chair",
+            ItemKind::Bed => "bed",
+            ItemKind::Food => "food",
+        }
+    }
+
+    /// How much an ordinary one is worth.
+    pub fn base_value(self) -> u32 {
+        match self {
+            ItemKind::Stone => 1,
+            ItemKind::Log => 2,
+            ItemKind::Table => 10,
+            ItemKind::Chair => 8,
+            ItemKind::Bed => 12,
+            ItemKind::Food => 2,
+        }
+    }
+
+    /// Which part of the fortress' wealth this counts towards.
+    pub fn wealth_category(self) -> WealthCategory {
+        if self == ItemKind::Food {
+            WealthCategory::Food
+        } else if self.is_furniture() {
+            WealthCategory::Furniture
+        } else {
+            WealthCategory::Goods
+        }
+    }
+
+    /// Returns true if this is furniture.
+    pub fn is_furniture(self) -> bool {
+        match self {
+            ItemKind::Table | ItemKind::Chair | ItemKind::Bed => true,
+            ItemKind::Stone | ItemKind::Log | ItemKind::Food => false,
+        }
+    }
+}
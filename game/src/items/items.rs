@@ -0,0 +1,202 @@
+use std::slice::Iter;
+
+use util::shapes::Point;
+
+use items::Construction;
+use items::Item;
+use items::ItemKind;
+use wealth::Wealth;
+
+/// Every item lying around, and everything which has been built.
+pub struct Items {
+    /// The loose items.
+    items: Vec<Item>,
+
+    /// What has been built into the map.
+    constructions: Vec<Construction>,
+
+    /// How much all of the items, and constructions, are worth.
+    /// Kept up to date as they are added and removed.
+    wealth: Wealth,
+}
+
+impl Items {
+    /// Creates an empty set of items.
+    pub fn new() -> Items {
+        Items {
+            items: Vec::new(),
+            constructions: Vec::new(),
+            wealth: Wealth::new(),
+        }
+    }
+
+    /// Adds an item.
+    pub fn add(
+        &mut self,
+        item: Item,
+    ) {
+        self.wealth.add(item.kind.wealth_category(), item.value());
+        self.items.push(item);
+    }
+
+    /// Removes, and returns, the item of the kind given which is closest to
+    /// the position.
+    pub fn take_nearest(
+        &mut self,
+        kind: ItemKind,
+        position: Point<u32>,
+    ) -> Option<Item> {
+        let distance = |item: &Item| {
+            let dx = i64::from(item.position.x) - i64::from(position.x);
+            let dy = i64::from(item.position.y) - i64::from(position.y);
+
+            dx * dx + dy * dy
+        };
+
+        let index = (0..self.items.len())
+            .filter(|&i| self.items[i].kind == kind)
+            .min_by_key(|&i| distance(&self.items[i]))?;
+
+        let item = self.items.remove(index);
+        self.wealth.remove(item.kind.wealth_category(), item.value());
+
+        Some(item)
+    }
+
+    /// Returns copies of the items picked out by the mask given.
+    ///
+    /// Bit 0 of the mask is the first item, bit 1 the second, and so on. Only
+    /// the first 64 items can be picked.
+    pub fn selected(
+        &self,
+        mask: u64,
+    ) -> Vec<Item> {
+        self.items
+            .iter()
+            .take(64)
+            .enumerate()
+            .filter(|&(i, _)| mask & (1 << i) != 0)
+            .map(|(_, &item)| item)
+            .collect()
+    }
+
+    /// Removes, and returns, the items picked out by the mask given.
+    /// The mask works the same as for `selected`.
+    pub fn take_selected(
+        &mut self,
+        mask: u64,
+    ) -> Vec<Item> {
+        let mut taken = Vec::new();
+
+        for i in (0..self.items.len().min(64)).rev() {
+            if mask & (1 << i) != 0 {
+                let item = self.items.remove(i);
+                self.wealth.remove(item.kind.wealth_category(), item.value());
+                taken.push(item);
+            }
+        }
+
+        taken.reverse();
+        taken
+    }
+
+    /// Returns how many items there are of the kind given.
+    pub fn count(
+        &self,
+        kind: ItemKind,
+    ) -> usize {
+        self.items.iter().filter(|item| item.kind == kind).count()
+    }
+
+    /// Returns the number of loose items.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns true if there are no loose items.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Iterates over every loose item.
+    pub fn iter(&self) -> Iter<'_, Item> {
+        self.items.iter()
+    }
+
+    /// Records something being built.
+    pub fn add_construction(
+        &mut self,
+        construction: Construction,
+    ) {
+        self.wealth.add(construction.kind.wealth_category(), construction.value());
+        self.constructions.push(construction);
+    }
+
+    /// Removes anything built at the position given, like when a wall is dug
+    /// out.
+    pub fn remove_constructions_at(
+        &mut self,
+        position: Point<u32>,
+    ) {
+        let wealth = &mut self.wealth;
+
+        self.constructions.retain(|construction| {
+            if construction.position == position {
+                wealth.remove(construction.kind.wealth_category(), construction.value());
+                false
+            } else {
+                true
+            }
+        });
+    }
+
+    /// Iterates over everything which has been built.
+    pub fn constructions(&self) -> Iter<'_, Construction> {
+        self.constructions.iter()
+    }
+
+    /// Returns how much everything is worth, by category.
+    pub fn wealth(&self) -> &Wealth {
+        &self.wealth
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use items::ConstructionKind;
+    use items::Quality;
+    use wealth::WealthCategory;
+
+    #[test]
+    fn wealth_follows_what_is_added_and_removed() {
+        let mut items = Items::new();
+        let here = Point::new(2, 2);
+
+        items.add(Item {
+            kind: ItemKind::Table,
+            quality: Quality::Masterwork,
+            position: here,
+        });
+        items.add(Item {
+            kind: ItemKind::Stone,
+            quality: Quality::Ordinary,
+            position: here,
+        });
+        items.add_construction(Construction {
+            kind: ConstructionKind::Wall,
+            quality: Quality::WellCrafted,
+            position: here,
+        });
+
+        assert_eq!(items.wealth().get(WealthCategory::Furniture), 120);
+        assert_eq!(items.wealth().get(WealthCategory::Goods), 1);
+        assert_eq!(items.wealth().get(WealthCategory::Architecture), 10);
+
+        items.take_nearest(ItemKind::Stone, here);
+        items.remove_constructions_at(here);
+
+        assert_eq!(items.wealth().total(), 120);
+    }
+}
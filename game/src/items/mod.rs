@@ -0,0 +1,18 @@
+//! Items.
+//!
+//! Things which have been made, or dug up, and what has been built.
+//!
+
+mod construction;
+mod construction_kind;
+mod item;
+mod item_kind;
+mod items;
+mod quality;
+
+pub use self::construction::Construction;
+pub use self::construction_kind::ConstructionKind;
+pub use self::item::Item;
+pub use self::item_kind::ItemKind;
+pub use self::items::Items;
+pub use self::quality::Quality;
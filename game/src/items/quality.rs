@@ -0,0 +1,90 @@
+use util::random::Random;
+
+/// How well something has been made.
+///
+/// These are ordered, from the worst to the best.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Quality {
+    /// Nothing special.
+    Ordinary,
+
+    /// A little better than most.
+    WellCrafted,
+
+    /// Made with care.
+    FinelyCrafted,
+
+    /// Better than most could manage.
+    Superior,
+
+    /// Only a master could make this.
+    Exceptional,
+
+    /// The very best.
+    Masterwork,
+}
+
+impl Quality {
+    /// Every quality, from the worst to the best.
+    pub const ALL: [Quality; 6] = [
+        Quality::Ordinary,
+        Quality::WellCrafted,
+        Quality::FinelyCrafted,
+        Quality::Superior,
+        Quality::Exceptional,
+        Quality::Masterwork,
+    ];
+
+    /// Works out the quality of something made by someone with the skill level
+    /// given.
+    ///
+    /// Higher skill gives better quality, but there is some luck to it.
+    pub fn roll(
+        rng: &mut Random,
+        skill_level: u8,
+    ) -> Quality {
+        let roll = (u32::from(skill_level) + rng.range(0, 5)) / 4;
+        let index = (roll as usize).min(Quality::ALL.len() - 1);
+
+        Quality::ALL[index]
+    }
+
+    /// How much more this is worth, than something ordinary.
+    pub fn value_multiplier(self) -> u32 {
+        match self {
+            Quality::Ordinary => 1,
+            Quality::WellCrafted => 2,
+            Quality::FinelyCrafted => 3,
+            Quality::Superior => 4,
+            Quality::Exceptional => 5,
+            Quality::Masterwork => 12,
+        }
+    }
+
+    /// A human readable name for the quality.
+    pub fn name(self) -> &'static str {
+        match self {
+            Quality::Ordinary => "ordinary",
+            Quality::WellCrafted => "well crafted",
+            Quality::FinelyCrafted => "finely crafted",
+            Quality::Superior => "superior",
+            Quality::Exceptional => "exceptional",
+            Quality::Masterwork => "masterwork",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skill_improves_quality() {
+        let mut rng = Random::new(8);
+
+        for _ in 0..50 {
+            assert!(Quality::roll(&mut rng, 0) <= Quality::WellCrafted);
+            assert!(Quality::roll(&mut rng, 20) >= Quality::Exceptional);
+        }
+    }
+}
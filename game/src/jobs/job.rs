@@ -0,0 +1,43 @@
+use util::shapes::Point;
+
+use creatures::CreatureId;
+use jobs::JobKind;
+
+/// A job waiting to be done, or being done.
+#[derive(Clone, Debug)]
+pub struct Job {
+    /// What needs doing.
+    pub kind: JobKind,
+
+    /// Who is doing it. None if no one has picked it up yet.
+    pub worker: Option<CreatureId>,
+
+    /// How much work has been done so far.
+    pub progress: u32,
+
+    /// The steps the worker is following to get to the job.
+    path: Vec<Point<u32>>,
+}
+
+impl Job {
+    /// Creates a new job, which no one is doing yet.
+    pub fn new(kind: JobKind) -> Job {
+        Job {
+            kind: kind,
+            worker: None,
+            progress: 0,
+            path: Vec::new(),
+        }
+    }
+
+    /// Takes the worker off the job, so someone else can pick it up.
+    /// Any work done so far is kept.
+    pub fn unassign(&mut self) {
+        self.worker = None;
+        self.path.clear();
+    }
+
+    pub(crate) fn path_mut(&mut self) -> &mut Vec<Point<u32>> {
+        &mut self.path
+    }
+}
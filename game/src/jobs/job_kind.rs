@@ -0,0 +1,390 @@
+use util::shapes::Point;
+
+use buildings::BuildingKind;
+use items::ConstructionKind;
+use items::ItemKind;
+use jobs::Labour;
+
+/// A piece of work which needs doing.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum JobKind {
+    /// Dig out the wall, or rocks, at the position.
+    /// This leaves a stone behind.
+    Mine {
+        /// Across.
+        x: u32,
+
+        /// Down.
+        y: u32,
+    },
+
+    /// Chop down the tree at the position.
+    /// This leaves a log behind.
+    ChopTree {
+        /// Across.
+        x: u32,
+
+        /// Down.
+        y: u32,
+    },
+
+    /// Build something at the position, using a stone.
+    Build {
+        /// What to build.
+        construction: ConstructionKind,
+
+        /// Across.
+        x: u32,
+
+        /// Down.
+        y: u32,
+    },
+
+    /// Put up a building at the position, using up it's material.
+    Erect {
+        /// What to put up.
+        building: BuildingKind,
+
+        /// Across.
+        x: u32,
+
+        /// Down.
+        y: u32,
+    },
+
+    /// Make a piece of furniture at the position, using a log.
+    Make {
+        /// What to make.
+        item: ItemKind,
+
+        /// Across.
+        x: u32,
+
+        /// Down.
+        y: u32,
+    },
+
+    /// Sow a crop on the farm plot at the position.
+    Plant {
+        /// Which crop to sow, as an index into the farms' crops.
+        crop: u8,
+
+        /// Across.
+        x: u32,
+
+        /// Down.
+        y: u32,
+    },
+
+    /// Gather the ripe crop on the farm plot at the position.
+    /// This leaves food behind.
+    Harvest {
+        /// Across.
+        x: u32,
+
+        /// Down.
+        y: u32,
+    },
+}
+
+impl JobKind {
+    /// The labour a citizen must have turned on to do this job.
+    pub fn labour(&self) -> Labour {
+        match *self {
+            JobKind::Mine {
+                ..
+            } => Labour::Mining,
+            JobKind::ChopTree {
+                ..
+            } => Labour::Woodcutting,
+            JobKind::Build {
+                ..
+            }
+            | JobKind::Erect {
+                ..
+            } => Labour::Masonry,
+            JobKind::Make {
+                ..
+            } => Labour::Carpentry,
+            JobKind::Plant {
+                ..
+            }
+            | JobKind::Harvest {
+                ..
+            } => Labour::Farming,
+        }
+    }
+
+    /// Where the job is.
+    pub fn site(&self) -> Point<u32> {
+        match *self {
+            JobKind::Mine {
+                x,
+                y,
+            }
+            | JobKind::ChopTree {
+                x,
+                y,
+            }
+            | JobKind::Build {
+                x,
+                y,
+                ..
+            }
+            | JobKind::Erect {
+                x,
+                y,
+                ..
+            }
+            | JobKind::Make {
+                x,
+                y,
+                ..
+            }
+            | JobKind::Plant {
+                x,
+                y,
+                ..
+            }
+            | JobKind::Harvest {
+                x,
+                y,
+            } => Point::new(x, y),
+        }
+    }
+
+    /// How much work it takes to finish the job.
+    pub fn work_required(&self) -> u32 {
+        match *self {
+            JobKind::Mine {
+                ..
+            } => 150,
+            JobKind::ChopTree {
+                ..
+            } => 120,
+            JobKind::Build {
+                ..
+            } => 150,
+            JobKind::Erect {
+                ..
+            } => 300,
+            JobKind::Make {
+                ..
+            } => 200,
+            JobKind::Plant {
+                ..
+            } => 80,
+            JobKind::Harvest {
+                ..
+            } => 100,
+        }
+    }
+
+    /// Returns true if a worker can do the job whilst stood at the position.
+    ///
+    /// Digging and building happen from the tile next to the site. Making
+    /// things, and farming, happens on the site itself.
+    pub fn is_work_position(
+        &self,
+        position: Point<u32>,
+    ) -> bool {
+        let site = self.site();
+
+        match *self {
+            JobKind::Make {
+                ..
+            }
+            | JobKind::Plant {
+                ..
+            }
+            | JobKind::Harvest {
+                ..
+            } => position == site,
+            _ => {
+                let dx = (i64::from(position.x) - i64::from(site.x)).abs();
+                let dy = (i64::from(position.y) - i64::from(site.y)).abs();
+
+                position != site && dx <= 1 && dy <= 1
+            },
+        }
+    }
+
+    /// Describes the job, for showing to the player.
+    /// i.e. 'build wall'.
+    pub fn describe(&self) -> String {
+        match *self {
+            JobKind::Mine {
+                ..
+            } => "mine".to_string(),
+            JobKind::ChopTree {
+                ..
+            } => "chop tree".to_string(),
+            JobKind::Build {
+                construction,
+                ..
+            } => format!("build {}", construction.name()),
+            JobKind::Erect {
+                building,
+                ..
+            } => format!("build {}", building.name()),
+            JobKind::Make {
+                item,
+                ..
+            } => format!("make {}", item.name()),
+            JobKind::Plant {
+                ..
+            } => "plant crop".to_string(),
+            JobKind::Harvest {
+                ..
+            } => "harvest crop".to_string(),
+        }
+    }
+
+    /// Converts the job into text, for saving.
+    /// You can get the job back by passing the words to `decode`.
+    pub fn encode(&self) -> String {
+        match *self {
+            JobKind::Mine {
+                x,
+                y,
+            } => format!("mine {} {}", x, y),
+            JobKind::ChopTree {
+                x,
+                y,
+            } => format!("chop {} {}", x, y),
+            JobKind::Build {
+                construction,
+                x,
+                y,
+            } => format!("build {} {} {}", construction.name(), x, y),
+            JobKind::Erect {
+                building,
+                x,
+                y,
+            } => format!("erect {} {} {}", building.key(), x, y),
+            JobKind::Make {
+                item,
+                x,
+                y,
+            } => format!("make {} {} {}", item.name(), x, y),
+            JobKind::Plant {
+                crop,
+                x,
+                y,
+            } => format!("plant {} {} {}", crop, x, y),
+            JobKind::Harvest {
+                x,
+                y,
+            } => format!("harvest {} {}", x, y),
+        }
+    }
+
+    /// Converts the words from an encoded job back into a job.
+    /// Returns None if the words are not a valid job.
+    pub fn decode(words: &[&str]) -> Option<JobKind> {
+        match words {
+            ["mine", x, y] => {
+                Some(JobKind::Mine {
+                    x: x.parse().ok()?,
+                    y: y.parse().ok()?,
+                })
+            },
+            ["chop", x, y] => {
+                Some(JobKind::ChopTree {
+                    x: x.parse().ok()?,
+                    y: y.parse().ok()?,
+                })
+            },
+            ["build", construction, x, y] => {
+                Some(JobKind::Build {
+                    construction: ConstructionKind::from_name(construction)?,
+                    x: x.parse().ok()?,
+                    y: y.parse().ok()?,
+                })
+            },
+            ["erect", building, x, y] => {
+                Some(JobKind::Erect {
+                    building: BuildingKind::from_key(building)?,
+                    x: x.parse().ok()?,
+                    y: y.parse().ok()?,
+                })
+            },
+            ["make", item, x, y] => {
+                let item = ItemKind::from_name(item)?;
+                if !item.is_furniture() {
+                    return None;
+                }
+
+                Some(JobKind::Make {
+                    item: item,
+                    x: x.parse().ok()?,
+                    y: y.parse().ok()?,
+                })
+            },
+            ["plant", crop, x, y] => {
+                Some(JobKind::Plant {
+                    crop: crop.parse().ok()?,
+                    x: x.parse().ok()?,
+                    y: y.parse().ok()?,
+                })
+            },
+            ["harvest", x, y] => {
+                Some(JobKind::Harvest {
+                    x: x.parse().ok()?,
+                    y: y.parse().ok()?,
+                })
+            },
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_and_decodes() {
+        let jobs = [
+            JobKind::Mine {
+                x: 1,
+                y: 2,
+            },
+            JobKind::ChopTree {
+                x: 3,
+                y: 4,
+            },
+            JobKind::Build {
+                construction: ConstructionKind::Wall,
+                x: 5,
+                y: 6,
+            },
+            JobKind::Erect {
+                building: BuildingKind::TradeDepot,
+                x: 9,
+                y: 10,
+            },
+            JobKind::Make {
+                item: ItemKind::Bed,
+                x: 7,
+                y: 8,
+            },
+            JobKind::Plant {
+                crop: 2,
+                x: 11,
+                y: 12,
+            },
+            JobKind::Harvest {
+                x: 13,
+                y: 14,
+            },
+        ];
+
+        for job in jobs.iter() {
+            let text = job.encode();
+            let words = text.split_whitespace().collect::<Vec<&str>>();
+
+            assert_eq!(JobKind::decode(&words), Some(*job));
+        }
+    }
+}
@@ -0,0 +1,731 @@
+use util::random::Random;
+use util::shapes::Point;
+use world::map::Map;
+use world::tiles::Tile;
+
+use buildings::Building;
+use buildings::Buildings;
+use citizens::Citizens;
+use citizens::ThoughtKind;
+use creatures::CreatureId;
+use creatures::Creatures;
+use events::GameEvent;
+use farming::GrowthStage;
+use items::Construction;
+use items::Item;
+use items::ItemKind;
+use items::Items;
+use items::Quality;
+use jobs::Job;
+use jobs::JobKind;
+use navigation::distance_squared;
+use navigation::find_path;
+use GameTile;
+
+/// How much work an unskilled worker does each tick.
+const BASE_WORK_RATE: u32 = 5;
+
+/// How much more work is done each tick, for each level of skill.
+const WORK_RATE_PER_LEVEL: u32 = 2;
+
+/// How much experience is gained from finishing a job.
+const JOB_EXPERIENCE: u32 = 40;
+
+/// Every job waiting to be done, or being done.
+pub struct Jobs {
+    /// The jobs, in the order they were added.
+    queue: Vec<Job>,
+}
+
+impl Jobs {
+    /// Creates an empty list of jobs.
+    pub fn new() -> Jobs {
+        Jobs {
+            queue: Vec::new(),
+        }
+    }
+
+    /// Adds a job to the end of the queue.
+    pub fn add(
+        &mut self,
+        kind: JobKind,
+    ) {
+        self.queue.push(Job::new(kind));
+    }
+
+    /// Returns the number of jobs.
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Returns true if there are no jobs.
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// Iterates over every job, in the order they were added.
+    pub fn iter(&self) -> impl Iterator<Item = &Job> + '_ {
+        self.queue.iter()
+    }
+
+    /// Returns the job the creature is doing, if it has one.
+    pub fn job_for(
+        &self,
+        worker: CreatureId,
+    ) -> Option<&Job> {
+        self.queue.iter().find(|job| job.worker == Some(worker))
+    }
+
+    /// Hands out jobs to idle citizens, moves workers to their jobs, and does
+    /// the work.
+    ///
+    /// Citizens only pick up jobs for labours they have turned on, and not
+    /// whilst they are having a breakdown. The nearest idle citizen gets the
+    /// job. How fast they work depends on their skill, and their mood.
+    /// Returns anything worth telling the player about.
+    pub fn update(
+        &mut self,
+        map: &mut Map<GameTile>,
+        creatures: &mut Creatures,
+        citizens: &mut Citizens,
+        items: &mut Items,
+        buildings: &mut Buildings,
+        rng: &mut Random,
+    ) -> Vec<GameEvent> {
+        let mut events = Vec::new();
+
+        self.release_missing_workers(creatures, citizens);
+        self.assign_workers(creatures, citizens);
+
+        let mut i = 0;
+        while i < self.queue.len() {
+            let outcome =
+                work_on(map, creatures, citizens, items, buildings, rng, &mut self.queue[i]);
+
+            match outcome {
+                Outcome::Working => i += 1,
+                Outcome::Done(mut done_events) => {
+                    events.append(&mut done_events);
+                    self.queue.remove(i);
+                },
+                Outcome::Cancelled(reason) => {
+                    let job = self.queue.remove(i);
+                    let worker = job
+                        .worker
+                        .and_then(|id| creatures.get(id))
+                        .map(|creature| creature.name.clone());
+
+                    events.push(GameEvent::JobCancelled {
+                        worker: worker,
+                        job: job.kind.describe(),
+                        reason: reason,
+                    });
+                },
+            }
+        }
+
+        events
+    }
+
+    /// Takes workers off of jobs if they have died, can no longer walk, or
+    /// have broken down.
+    fn release_missing_workers(
+        &mut self,
+        creatures: &Creatures,
+        citizens: &Citizens,
+    ) {
+        for job in self.queue.iter_mut() {
+            let can_work = job.worker.and_then(|id| creatures.get(id)).map(|creature| {
+                let is_fit = citizens.get(creature.id).map(|citizen| citizen.can_work());
+
+                creature.body.can_walk() && is_fit != Some(false)
+            });
+
+            if can_work == Some(false) || (job.worker.is_some() && can_work.is_none()) {
+                job.unassign();
+            }
+        }
+    }
+
+    /// Gives each job no one is doing to the nearest idle citizen who has it's
+    /// labour turned on.
+    fn assign_workers(
+        &mut self,
+        creatures: &Creatures,
+        citizens: &Citizens,
+    ) {
+        let mut busy = self.queue.iter().filter_map(|job| job.worker).collect::<Vec<CreatureId>>();
+
+        for job in self.queue.iter_mut().filter(|job| job.worker.is_none()) {
+            let labour = job.kind.labour();
+            let site = job.kind.site();
+
+            let worker = citizens
+                .iter()
+                .filter(|&(id, citizen)| {
+                    citizen.labours.is_enabled(labour)
+                        && citizen.can_work()
+                        && !citizens.is_arriving(*id)
+                        && !busy.contains(id)
+                })
+                .filter_map(|(&id, _)| creatures.get(id))
+                .filter(|creature| creature.body.can_walk())
+                .min_by_key(|creature| distance_squared(creature.position, site))
+                .map(|creature| creature.id);
+
+            if let Some(worker) = worker {
+                job.worker = Some(worker);
+                busy.push(worker);
+            }
+        }
+    }
+}
+
+/// What happened when a job was worked on.
+enum Outcome {
+    /// It's still going, or no one is doing it.
+    Working,
+
+    /// It's finished.
+    Done(Vec<GameEvent>),
+
+    /// It can't be done.
+    Cancelled(&'static str),
+}
+
+/// Moves the worker towards the job, or does some work if they are there.
+fn work_on(
+    map: &mut Map<GameTile>,
+    creatures: &mut Creatures,
+    citizens: &mut Citizens,
+    items: &mut Items,
+    buildings: &mut Buildings,
+    rng: &mut Random,
+    job: &mut Job,
+) -> Outcome {
+    if let Some(reason) = check_job(map, items, buildings, &job.kind) {
+        return Outcome::Cancelled(reason);
+    }
+
+    let worker_id = match job.worker {
+        Some(worker_id) => worker_id,
+        None => return Outcome::Working,
+    };
+
+    let position = match creatures.get(worker_id) {
+        Some(creature) => creature.position,
+        None => return Outcome::Working,
+    };
+
+    if !job.kind.is_work_position(position) {
+        return walk_to_job(map, creatures, worker_id, position, job);
+    }
+
+    let level = {
+        let creature = match creatures.get(worker_id) {
+            Some(creature) => creature,
+            None => return Outcome::Working,
+        };
+
+        creature.skills.level(job.kind.labour().skill())
+    };
+
+    let mood_rate =
+        citizens.get(worker_id).map(|citizen| citizen.mood().work_rate()).unwrap_or(100);
+    job.progress += (BASE_WORK_RATE + u32::from(level) * WORK_RATE_PER_LEVEL) * mood_rate / 100;
+    if job.progress < job.kind.work_required() {
+        return Outcome::Working;
+    }
+
+    if let JobKind::Build {
+        ..
+    } = job.kind
+    {
+        let site = job.kind.site();
+
+        if creatures.at(site.x, site.y).is_some() {
+            return Outcome::Working;
+        }
+    }
+
+    let mut events = Vec::new();
+    let quality = finish_job(map, items, buildings, rng, &job.kind, level);
+
+    if let Some(creature) = creatures.get_mut(worker_id) {
+        let skill = job.kind.labour().skill();
+
+        if creature.skills.add_experience(skill, JOB_EXPERIENCE) {
+            citizens.think(worker_id, ThoughtKind::ImprovedSkill);
+            events.push(GameEvent::SkillImproved {
+                name: creature.name.clone(),
+                skill: skill,
+                level: creature.skills.level(skill),
+            });
+        }
+
+        match job.kind {
+            JobKind::Harvest {
+                ..
+            } => {
+                if let Some(percent) = quality.and_then(meal_percent) {
+                    citizens.think_scaled(worker_id, ThoughtKind::AteFineMeal, percent);
+                }
+            },
+            _ if quality == Some(Quality::Masterwork) => {
+                citizens.think(worker_id, ThoughtKind::CreatedMasterwork);
+                events.push(GameEvent::MasterworkCreated {
+                    name: creature.name.clone(),
+                    what: job.kind.describe(),
+                });
+            },
+            _ => {},
+        }
+    }
+
+    Outcome::Done(events)
+}
+
+/// How strongly a farmer enjoys tasting a harvest of the quality given, as a
+/// percent of a fine meal. Ordinary food isn't worth thinking about.
+fn meal_percent(quality: Quality) -> Option<i32> {
+    match quality {
+        Quality::Ordinary => None,
+        Quality::WellCrafted => Some(50),
+        Quality::FinelyCrafted => Some(75),
+        Quality::Superior => Some(100),
+        Quality::Exceptional => Some(150),
+        Quality::Masterwork => Some(200),
+    }
+}
+
+/// Returns why the job can't be done, if it can't.
+fn check_job(
+    map: &Map<GameTile>,
+    items: &Items,
+    buildings: &Buildings,
+    kind: &JobKind,
+) -> Option<&'static str> {
+    let site = kind.site();
+    if site.x >= map.width || site.y >= map.height {
+        return Some("it is off the map");
+    }
+
+    let tile = map.get(site.x, site.y).tile;
+
+    match *kind {
+        JobKind::Mine {
+            ..
+        } if tile != Tile::Wall && tile != Tile::Rocks => Some("there is nothing to dig"),
+        JobKind::ChopTree {
+            ..
+        } if tile != Tile::TreeStump => Some("there is no tree"),
+        JobKind::Build {
+            ..
+        }
+        | JobKind::Erect {
+            ..
+        } if !tile.is_passable() => Some("the site is blocked"),
+        JobKind::Build {
+            ..
+        }
+        | JobKind::Erect {
+            ..
+        } if buildings.at(site).is_some() => Some("there is already a building there"),
+        JobKind::Build {
+            ..
+        } if items.count(ItemKind::Stone) == 0 => Some("there is no stone"),
+        JobKind::Erect {
+            building,
+            ..
+        } if building.needs_soil() && !tile.is_soil() => Some("the ground cannot be farmed"),
+        JobKind::Erect {
+            building,
+            ..
+        } if building.material().is_some_and(|material| items.count(material) == 0) => {
+            Some("there are no materials")
+        },
+        JobKind::Make {
+            ..
+        } if items.count(ItemKind::Log) == 0 => Some("there are no logs"),
+        JobKind::Plant {
+            ..
+        } => {
+            match buildings.farms().plot(site) {
+                Some(plot) if plot.planted.is_some() => Some("something is already growing there"),
+                Some(_) => None,
+                None => Some("there is no farm plot"),
+            }
+        },
+        JobKind::Harvest {
+            ..
+        } => {
+            match buildings.farms().growth_at(site) {
+                Some((_, GrowthStage::Ripe)) => None,
+                _ => Some("there is nothing to harvest"),
+            }
+        },
+        _ => None,
+    }
+}
+
+/// Moves the worker one step along it's path to the job.
+fn walk_to_job(
+    map: &Map<GameTile>,
+    creatures: &mut Creatures,
+    worker_id: CreatureId,
+    position: Point<u32>,
+    job: &mut Job,
+) -> Outcome {
+    let kind = job.kind;
+    let path = job.path_mut();
+
+    let is_path_blocked =
+        path.first().map(|next| !map.get(next.x, next.y).tile.is_passable()).unwrap_or(true);
+    if is_path_blocked {
+        match find_path(map, position, |p| kind.is_work_position(p)) {
+            Some(new_path) => *path = new_path,
+            None => return Outcome::Cancelled("the site cannot be reached"),
+        }
+    }
+
+    if let Some(&next) = path.first() {
+        if creatures.at(next.x, next.y).is_none() {
+            path.remove(0);
+
+            if let Some(creature) = creatures.get_mut(worker_id) {
+                creature.position = next;
+            }
+        }
+    }
+
+    Outcome::Working
+}
+
+/// Changes the world now the job is done.
+/// Returns the quality of whatever was made, if anything was made.
+fn finish_job(
+    map: &mut Map<GameTile>,
+    items: &mut Items,
+    buildings: &mut Buildings,
+    rng: &mut Random,
+    kind: &JobKind,
+    level: u8,
+) -> Option<Quality> {
+    let site = kind.site();
+    let mut game_tile = map.get(site.x, site.y);
+
+    match *kind {
+        JobKind::Mine {
+            ..
+        } => {
+            game_tile.tile = Tile::Ground;
+            map.set(site.x, site.y, game_tile);
+            items.remove_constructions_at(site);
+            items.add(Item {
+                kind: ItemKind::Stone,
+                quality: Quality::Ordinary,
+                position: site,
+            });
+
+            None
+        },
+        JobKind::ChopTree {
+            ..
+        } => {
+            game_tile.tile = Tile::Ground;
+            map.set(site.x, site.y, game_tile);
+            items.add(Item {
+                kind: ItemKind::Log,
+                quality: Quality::Ordinary,
+                position: site,
+            });
+
+            None
+        },
+        JobKind::Build {
+            construction,
+            ..
+        } => {
+            items.take_nearest(ItemKind::Stone, site)?;
+            let quality = Quality::roll(rng, level);
+
+            game_tile.tile = construction.tile();
+            map.set(site.x, site.y, game_tile);
+            items.add_construction(Construction {
+                kind: construction,
+                quality: quality,
+                position: site,
+            });
+
+            Some(quality)
+        },
+        JobKind::Erect {
+            building,
+            ..
+        } => {
+            if let Some(material) = building.material() {
+                items.take_nearest(material, site)?;
+            }
+
+            buildings.add(Building {
+                kind: building,
+                position: site,
+            });
+
+            None
+        },
+        JobKind::Make {
+            item,
+            ..
+        } => {
+            items.take_nearest(ItemKind::Log, site)?;
+            let quality = Quality::roll(rng, level);
+
+            items.add(Item {
+                kind: item,
+                quality: quality,
+                position: site,
+            });
+
+            Some(quality)
+        },
+        JobKind::Plant {
+            crop,
+            ..
+        } => {
+            buildings.farms_mut().plant(site, crop);
+
+            None
+        },
+        JobKind::Harvest {
+            ..
+        } => {
+            let amount = buildings.farms_mut().harvest(site)?;
+            let quality = Quality::roll(rng, level);
+
+            for _ in 0..amount {
+                items.add(Item {
+                    kind: ItemKind::Food,
+                    quality: quality,
+                    position: site,
+                });
+            }
+
+            Some(quality)
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use world::calendar::Season;
+
+    use buildings::BuildingKind;
+    use citizens::Citizen;
+    use farming::parse_crops;
+    use farming::Farms;
+    use jobs::Labour;
+    use jobs::Labours;
+
+    /// Makes a map with a single citizen, who can do the labour given.
+    fn setup(labour: Option<Labour>) -> (Map<GameTile>, Creatures, Citizens, CreatureId) {
+        let map = Map::new(20, 20, GameTile::new(Tile::Ground));
+        let mut creatures = Creatures::new();
+        let mut citizens = Citizens::new();
+        let mut rng = Random::new(4);
+
+        let id = citizens.spawn_wave(&map, &mut creatures, &mut rng, 1)[0];
+        for _ in 0..50 {
+            citizens.update(&map, &mut creatures, Point::new(10, 10));
+        }
+
+        let mut labours = Labours::none();
+        if let Some(labour) = labour {
+            labours.set(labour, true);
+        }
+        citizens.get_mut(id).unwrap().labours = labours;
+
+        (map, creatures, citizens, id)
+    }
+
+    fn set_tile(
+        map: &mut Map<GameTile>,
+        x: u32,
+        y: u32,
+        tile: Tile,
+    ) {
+        map.set(x, y, GameTile::new(tile));
+    }
+
+    #[test]
+    fn miners_dig_out_stone_and_learn() {
+        let (mut map, mut creatures, mut citizens, id) = setup(Some(Labour::Mining));
+        let mut items = Items::new();
+        let mut buildings = Buildings::new();
+        let mut rng = Random::new(9);
+        let mut jobs = Jobs::new();
+
+        set_tile(&mut map, 3, 3, Tile::Wall);
+        jobs.add(JobKind::Mine {
+            x: 3,
+            y: 3,
+        });
+
+        let skill = Labour::Mining.skill();
+        let level = creatures.get(id).unwrap().skills.level(skill);
+        let experience = creatures.get(id).unwrap().skills.experience(skill);
+        for _ in 0..200 {
+            jobs.update(
+                &mut map,
+                &mut creatures,
+                &mut citizens,
+                &mut items,
+                &mut buildings,
+                &mut rng,
+            );
+        }
+
+        assert!(jobs.is_empty());
+        assert_eq!(map.get(3, 3).tile, Tile::Ground);
+        assert_eq!(items.count(ItemKind::Stone), 1);
+
+        let skills = &creatures.get(id).unwrap().skills;
+        assert_eq!(skills.level(skill), level);
+        assert_eq!(skills.experience(skill), experience + JOB_EXPERIENCE);
+    }
+
+    #[test]
+    fn jobs_wait_for_someone_with_the_labour() {
+        let (mut map, mut creatures, mut citizens, _) = setup(None);
+        let mut items = Items::new();
+        let mut buildings = Buildings::new();
+        let mut rng = Random::new(9);
+        let mut jobs = Jobs::new();
+
+        set_tile(&mut map, 3, 3, Tile::Wall);
+        jobs.add(JobKind::Mine {
+            x: 3,
+            y: 3,
+        });
+
+        for _ in 0..200 {
+            jobs.update(
+                &mut map,
+                &mut creatures,
+                &mut citizens,
+                &mut items,
+                &mut buildings,
+                &mut rng,
+            );
+        }
+
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs.iter().next().unwrap().worker, None);
+        assert_eq!(map.get(3, 3).tile, Tile::Wall);
+    }
+
+    /// Plants wheat on a farm plot, and has the farmers tend it until it has
+    /// been harvested.
+    fn grow_and_harvest(
+        map: &mut Map<GameTile>,
+        creatures: &mut Creatures,
+        citizens: &mut Citizens,
+    ) -> (Items, Buildings) {
+        let mut items = Items::new();
+        let mut rng = Random::new(9);
+        let mut jobs = Jobs::new();
+
+        let crops = parse_crops("crop wheat\nsymbol w\ngrowth_time 5\nseasons spring\nyield 3\n");
+        let mut buildings = Buildings::with_farms(Farms::with_crops(crops.unwrap()));
+        let site = Point::new(3, 3);
+
+        buildings.add(Building {
+            kind: BuildingKind::FarmPlot,
+            position: site,
+        });
+        buildings.farms_mut().set_crop(map, site, Season::Spring, Some(0));
+        for _ in 0..400 {
+            buildings.farms_mut().update(map, &mut jobs, Season::Spring);
+            jobs.update(map, creatures, citizens, &mut items, &mut buildings, &mut rng);
+
+            if items.count(ItemKind::Food) > 0 {
+                break;
+            }
+        }
+
+        (items, buildings)
+    }
+
+    #[test]
+    fn farmers_harvest_ripe_crops() {
+        let (mut map, mut creatures, mut citizens, _) = setup(Some(Labour::Farming));
+        let (items, buildings) = grow_and_harvest(&mut map, &mut creatures, &mut citizens);
+
+        assert_eq!(items.count(ItemKind::Food), 3);
+        assert!(buildings.farms().growth_at(Point::new(3, 3)).is_none());
+    }
+
+    #[test]
+    fn skilled_farmers_enjoy_a_fine_harvest() {
+        let (mut map, mut creatures, mut citizens, id) = setup(Some(Labour::Farming));
+        creatures.get_mut(id).unwrap().skills.set_level(Labour::Farming.skill(), 20);
+        let (items, _) = grow_and_harvest(&mut map, &mut creatures, &mut citizens);
+
+        assert_eq!(items.count(ItemKind::Food), 3);
+        let thought = citizens
+            .get(id)
+            .unwrap()
+            .thoughts
+            .iter()
+            .find(|thought| thought.kind == ThoughtKind::AteFineMeal)
+            .cloned()
+            .unwrap();
+
+        // Food this good is enjoyed more than an ordinary fine meal.
+        let mut ordinary = Citizen::new(citizens.get(id).unwrap().personality, Labours::none());
+        ordinary.think(ThoughtKind::AteFineMeal);
+        assert!(thought.effect > ordinary.thoughts.iter().next().unwrap().effect);
+    }
+
+    #[test]
+    fn unreachable_jobs_are_cancelled() {
+        let (mut map, mut creatures, mut citizens, _) = setup(Some(Labour::Mining));
+        let mut items = Items::new();
+        let mut buildings = Buildings::new();
+        let mut rng = Random::new(9);
+        let mut jobs = Jobs::new();
+
+        // A wall in the middle of a lake.
+        for x in 1..6 {
+            for y in 1..6 {
+                set_tile(&mut map, x, y, Tile::Water);
+            }
+        }
+        set_tile(&mut map, 3, 3, Tile::Wall);
+        jobs.add(JobKind::Mine {
+            x: 3,
+            y: 3,
+        });
+
+        let events = jobs.update(
+            &mut map,
+            &mut creatures,
+            &mut citizens,
+            &mut items,
+            &mut buildings,
+            &mut rng,
+        );
+
+        assert!(jobs.is_empty());
+        assert_eq!(events.len(), 1);
+        match events[0] {
+            GameEvent::JobCancelled {
+                reason,
+                ..
+            } => assert_eq!(reason, "the site cannot be reached"),
+            ref event => panic!("expected a cancelled job, got {:?}", event),
+        }
+    }
+}
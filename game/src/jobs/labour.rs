@@ -0,0 +1,54 @@
+use skills::Skill;
+
+/// A kind of work a citizen can be allowed, or not allowed, to do.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Labour {
+    /// Digging out rock.
+    Mining,
+
+    /// Chopping down trees.
+    Woodcutting,
+
+    /// Building with stone.
+    Masonry,
+
+    /// Making furniture out of wood.
+    Carpentry,
+
+    /// Planting and harvesting crops.
+    Farming,
+}
+
+impl Labour {
+    /// Every labour, in order.
+    pub const ALL: [Labour; 5] =
+        [Labour::Mining, Labour::Woodcutting, Labour::Masonry, Labour::Carpentry, Labour::Farming];
+
+    /// The skill used when doing this labour.
+    pub fn skill(self) -> Skill {
+        match self {
+            Labour::Mining => Skill::Mining,
+            Labour::Woodcutting => Skill::Woodcutting,
+            Labour::Masonry => Skill::Masonry,
+            Labour::Carpentry => Skill::Carpentry,
+            Labour::Farming => Skill::Farming,
+        }
+    }
+
+    /// Finds the labour with the name given.
+    /// i.e. 'mining'.
+    pub fn from_name(name: &str) -> Option<Labour> {
+        Labour::ALL.iter().cloned().find(|labour| labour.name() == name)
+    }
+
+    /// A human readable name for the labour.
+    pub fn name(self) -> &'static str {
+        match self {
+            Labour::Mining => "mining",
+            Labour::Woodcutting => "woodcutting",
+            Labour::Masonry => "masonry",
+            Labour::Carpentry => "carpentry",
+            Labour::Farming => "farming",
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use jobs::Labour;
+
+/// Which labours a citizen is allowed to do.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Labours {
+    /// One bit for each labour, in the order of `Labour::ALL`.
+    enabled: u8,
+}
+
+impl Labours {
+    /// Creates a set with every labour turned off.
+    pub fn none() -> Labours {
+        Labours {
+            enabled: 0,
+        }
+    }
+
+    /// Returns true if the labour is allowed.
+    pub fn is_enabled(
+        &self,
+        labour: Labour,
+    ) -> bool {
+        self.enabled & bit(labour) != 0
+    }
+
+    /// Turns the labour on, or off.
+    pub fn set(
+        &mut self,
+        labour: Labour,
+        enabled: bool,
+    ) {
+        if enabled {
+            self.enabled |= bit(labour);
+        } else {
+            self.enabled &= !bit(labour);
+        }
+    }
+
+    /// Iterates over every labour which is turned on.
+    pub fn iter(&self) -> impl Iterator<Item = Labour> + '_ {
+        Labour::ALL.iter().cloned().filter(move |&labour| self.is_enabled(labour))
+    }
+}
+
+fn bit(labour: Labour) -> u8 {
+    1 << (labour as u8)
+}
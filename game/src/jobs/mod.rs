@@ -0,0 +1,17 @@
+//! Jobs.
+//!
+//! Work which needs doing around the fortress, who is allowed to do it, and
+//! carrying it out.
+//!
+
+mod job;
+mod job_kind;
+mod jobs;
+mod labour;
+mod labours;
+
+pub use self::job::Job;
+pub use self::job_kind::JobKind;
+pub use self::jobs::Jobs;
+pub use self::labour::Labour;
+pub use self::labours::Labours;
@@ -5,12 +5,74 @@
 //! This does the update from one to the next.
 //! 
 
+extern crate util;
 extern crate world;
 
+mod command;
+mod data_file;
+mod fov;
 mod game;
-mod game_tile;
 mod game_setup;
+mod game_tile;
+mod lighting;
+mod navigation;
+mod weather;
+
+/// The dwarves who live in the fortress.
+pub mod citizens;
+
+/// Places set aside for something, like trading.
+pub mod buildings;
+
+/// Bodies, wounds, and fighting.
+pub mod combat;
+
+/// Everything alive in the world.
+pub mod creatures;
+
+/// How good creatures are at things.
+pub mod skills;
+
+/// Sieges, and the invaders who bring them.
+pub mod invasions;
+
+/// Things which have been made, and built.
+pub mod items;
+
+/// Work, and who does it.
+pub mod jobs;
 
+/// How much the fortress is worth.
+pub mod wealth;
+
+/// Merchants, and trading with them.
+pub mod trade;
+
+/// Farm plots, and the crops grown on them.
+pub mod farming;
+
+/// Wild animals, and how they behave.
+pub mod wildlife;
+
+/// Things which happen in the game, for telling the player about.
+pub mod events;
+
+/// Recording and replaying games.
+pub mod replay;
+
+/// Which systems run on each tick, and how long they take.
+pub mod scheduler;
+
+/// Things due to happen at set times in the game.
+pub mod timers;
+
+pub use self::command::GameCommand;
+pub use self::data_file::DataFileError;
 pub use self::game::Game;
 pub use self::game_setup::GameSetup;
 pub use self::game_tile::GameTile;
+
+pub use self::fov::Visibility;
+
+pub use self::lighting::LightSource;
+pub use self::lighting::LightSourceKind;
@@ -0,0 +1,70 @@
+use world::calendar::Season;
+use world::calendar::WorldTime;
+
+/// The light level in the dead of night.
+const NIGHT_LIGHT: f32 = 0.15;
+
+/// The light level in full daylight.
+const DAY_LIGHT: f32 = 1.0;
+
+/// How many hours it takes to go from night to day, and back again.
+const TWILIGHT_HOURS: f32 = 1.5;
+
+/// Returns the light coming from the sky, at the time given.
+///
+/// This is from 0.0 (pitch black) to 1.0 (full daylight). It never goes fully
+/// black, as there is always a little moonlight.
+pub fn ambient_light(
+    time: WorldTime,
+    season: Season,
+) -> f32 {
+    let hour = f32::from(time.hour) + f32::from(time.minute) / 60.0;
+    let (sunrise, sunset) = daylight_hours(season);
+
+    let dawn = (hour - sunrise) / TWILIGHT_HOURS + 0.5;
+    let dusk = (sunset - hour) / TWILIGHT_HOURS + 0.5;
+    let daylight = dawn.min(dusk).clamp(0.0, 1.0);
+
+    NIGHT_LIGHT + (DAY_LIGHT - NIGHT_LIGHT) * daylight
+}
+
+/// Returns the hour of sunrise and sunset for the season given.
+/// The days are longer in the summer, and shorter in the winter.
+fn daylight_hours(season: Season) -> (f32, f32) {
+    match season {
+        Season::Spring => (6.0, 19.0),
+        Season::Summer => (5.0, 21.0),
+        Season::Autumn => (7.0, 18.0),
+        Season::Winter => (8.0, 16.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at_hour(hour: u8) -> WorldTime {
+        WorldTime {
+            second: 0,
+            minute: 0,
+            hour: hour,
+            day: 0,
+            month: 0,
+            year: 0,
+        }
+    }
+
+    #[test]
+    fn midnight_is_dark_and_noon_is_bright() {
+        assert_eq!(ambient_light(at_hour(0), Season::Summer), NIGHT_LIGHT);
+        assert_eq!(ambient_light(at_hour(12), Season::Winter), DAY_LIGHT);
+    }
+
+    #[test]
+    fn winter_evenings_are_darker() {
+        let summer = ambient_light(at_hour(17), Season::Summer);
+        let winter = ambient_light(at_hour(17), Season::Winter);
+
+        assert!(winter < summer);
+    }
+}
@@ -0,0 +1,76 @@
+use util::shapes::Point;
+
+/// The different things which can give off light.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LightSourceKind {
+    /// A torch on a wall, or held in a hand.
+    /// Gives a small pool of light.
+    Torch,
+
+    /// A camp fire, or a burning building.
+    /// Brighter, and reaches further, than a torch.
+    Fire,
+}
+
+impl LightSourceKind {
+    /// How many tiles away the light reaches.
+    pub fn radius(self) -> u32 {
+        match self {
+            LightSourceKind::Torch => 5,
+            LightSourceKind::Fire => 8,
+        }
+    }
+
+    /// How bright the light is at it's centre.
+    /// From 0.0 to 1.0.
+    pub fn intensity(self) -> f32 {
+        match self {
+            LightSourceKind::Torch => 0.8,
+            LightSourceKind::Fire => 1.0,
+        }
+    }
+}
+
+/// Something in the world which gives off light.
+#[derive(Copy, Clone, Debug)]
+pub struct LightSource {
+    /// Where the light is.
+    pub position: Point<u32>,
+
+    /// What is giving off the light.
+    pub kind: LightSourceKind,
+}
+
+impl LightSource {
+    /// Trivial constructor.
+    pub fn new(
+        position: Point<u32>,
+        kind: LightSourceKind,
+    ) -> LightSource {
+        LightSource {
+            position: position,
+            kind: kind,
+        }
+    }
+
+    /// Returns how much light reaches the position given.
+    ///
+    /// The light falls off with distance, reaching nothing at the edge of
+    /// it's radius.
+    pub fn light_at(
+        &self,
+        x: u32,
+        y: u32,
+    ) -> f32 {
+        let dx = x as f32 - self.position.x as f32;
+        let dy = y as f32 - self.position.y as f32;
+        let distance = (dx * dx + dy * dy).sqrt();
+        let radius = self.kind.radius() as f32;
+
+        if distance >= radius {
+            0.0
+        } else {
+            self.kind.intensity() * (1.0 - distance / radius)
+        }
+    }
+}
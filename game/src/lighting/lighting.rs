@@ -0,0 +1,75 @@
+use world::calendar::Season;
+use world::calendar::WorldTime;
+
+use lighting::ambient_light;
+use lighting::LightSource;
+
+/// Holds all of the light in the game.
+pub struct Lighting {
+    /// The current light coming from the sky.
+    ambient: f32,
+
+    /// All of the lights placed in the world.
+    sources: Vec<LightSource>,
+}
+
+impl Lighting {
+    /// Creates a new lighting, for the time given.
+    /// It starts with no light sources.
+    pub fn new(
+        time: WorldTime,
+        season: Season,
+    ) -> Lighting {
+        Lighting {
+            ambient: ambient_light(time, season),
+            sources: Vec::new(),
+        }
+    }
+
+    /// Updates the ambient light to match the time given.
+    pub fn update(
+        &mut self,
+        time: WorldTime,
+        season: Season,
+    ) {
+        self.ambient = ambient_light(time, season);
+    }
+
+    /// Adds a new light into the world.
+    pub fn add_source(
+        &mut self,
+        source: LightSource,
+    ) {
+        self.sources.push(source);
+    }
+
+    /// Removes all light sources at the position given.
+    pub fn remove_sources_at(
+        &mut self,
+        x: u32,
+        y: u32,
+    ) {
+        self.sources.retain(|source| source.position.x != x || source.position.y != y);
+    }
+
+    /// Returns all of the lights placed in the world.
+    pub fn sources(&self) -> &[LightSource] {
+        &self.sources
+    }
+
+    /// Returns how brightly lit the position is.
+    ///
+    /// This is whichever is brightest out of the sky, and the light sources.
+    /// From 0.0 (pitch black) to 1.0 (full light).
+    pub fn light_at(
+        &self,
+        x: u32,
+        y: u32,
+    ) -> f32 {
+        self.sources
+            .iter()
+            .map(|source| source.light_at(x, y))
+            .fold(self.ambient, f32::max)
+            .min(1.0)
+    }
+}
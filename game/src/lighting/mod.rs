@@ -0,0 +1,14 @@
+//! Lighting.
+//!
+//! Works out how brightly lit each tile is. This is a mix of the ambient light
+//! from the sky, and light given off by things like torches.
+//!
+
+mod ambient;
+mod light_source;
+mod lighting;
+
+pub use self::ambient::ambient_light;
+pub use self::light_source::LightSource;
+pub use self::light_source::LightSourceKind;
+pub use self::lighting::Lighting;
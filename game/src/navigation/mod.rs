@@ -0,0 +1,18 @@
+//! Navigation.
+//!
+//! Helpers for creatures moving around the map.
+//!
+
+mod path;
+mod steps;
+
+pub use self::path::find_path;
+pub use self::steps::direction_to_nearest_edge;
+pub use self::steps::distance_squared;
+pub use self::steps::find_edge_positions;
+pub use self::steps::free_neighbours;
+pub use self::steps::is_free;
+pub use self::steps::is_on_edge;
+pub use self::steps::step;
+pub use self::steps::step_towards;
+pub use self::steps::DIRECTIONS;
@@ -0,0 +1,99 @@
+use std::collections::VecDeque;
+
+use util::shapes::Point;
+use world::map::Map;
+
+use navigation::step;
+use navigation::DIRECTIONS;
+use GameTile;
+
+/// Finds the shortest walk from the start, to any passable tile the goal
+/// accepts.
+///
+/// This is a breadth first search, which ignores other creatures. The path
+/// returned doesn't include the start. If the start is already a goal then
+/// the path is empty. Returns None if no goal can be reached.
+pub fn find_path<G>(
+    map: &Map<GameTile>,
+    start: Point<u32>,
+    is_goal: G,
+) -> Option<Vec<Point<u32>>>
+where
+    G: Fn(Point<u32>) -> bool,
+{
+    if is_goal(start) {
+        return Some(Vec::new());
+    }
+
+    let index = |position: Point<u32>| (position.y * map.width + position.x) as usize;
+    let mut came_from: Vec<Option<Point<u32>>> = vec![None; (map.width * map.height) as usize];
+    let mut open = VecDeque::new();
+
+    came_from[index(start)] = Some(start);
+    open.push_back(start);
+
+    while let Some(position) = open.pop_front() {
+        for &direction in DIRECTIONS.iter() {
+            let next = match step(map, position, direction) {
+                Some(next) => next,
+                None => continue,
+            };
+
+            if came_from[index(next)].is_some() || !map.get(next.x, next.y).tile.is_passable() {
+                continue;
+            }
+
+            came_from[index(next)] = Some(position);
+
+            if is_goal(next) {
+                let mut path = vec![next];
+                let mut current = position;
+
+                while current != start {
+                    path.push(current);
+                    current = came_from[index(current)].unwrap_or(start);
+                }
+
+                path.reverse();
+                return Some(path);
+            }
+
+            open.push_back(next);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use world::tiles::Tile;
+
+    #[test]
+    fn walks_around_walls() {
+        // A wall down x = 2, with a gap at the bottom.
+        let mut map = Map::new(5, 5, GameTile::new(Tile::Ground));
+        for y in 0..4 {
+            map.set(2, y, GameTile::new(Tile::Wall));
+        }
+
+        let goal = Point::new(4, 0);
+        let path = find_path(&map, Point::new(0, 0), |position| position == goal).unwrap();
+
+        assert_eq!(path.last(), Some(&goal));
+        assert!(path.contains(&Point::new(2, 4)));
+        assert!(path.iter().all(|p| map.get(p.x, p.y).tile.is_passable()));
+    }
+
+    #[test]
+    fn unreachable_goals_have_no_path() {
+        let mut map = Map::new(5, 5, GameTile::new(Tile::Ground));
+        for y in 0..5 {
+            map.set(2, y, GameTile::new(Tile::Wall));
+        }
+
+        assert!(find_path(&map, Point::new(0, 0), |position| position.x == 4).is_none());
+    }
+}
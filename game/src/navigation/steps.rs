@@ -0,0 +1,159 @@
+use util::random::Random;
+use util::shapes::Point;
+use world::map::Map;
+
+use creatures::Creatures;
+use GameTile;
+
+/// The eight directions a creature can step in.
+pub const DIRECTIONS: [(i32, i32); 8] =
+    [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)];
+
+/// Returns the position one step in the direction given.
+/// Returns None if this would go off the map.
+pub fn step(
+    map: &Map<GameTile>,
+    position: Point<u32>,
+    (dx, dy): (i32, i32),
+) -> Option<Point<u32>> {
+    let x = position.x as i64 + i64::from(dx);
+    let y = position.y as i64 + i64::from(dy);
+
+    if x < 0 || y < 0 || x >= i64::from(map.width) || y >= i64::from(map.height) {
+        return None;
+    }
+
+    Some(Point::new(x as u32, y as u32))
+}
+
+/// Returns true if a creature can stand at the position given.
+///
+/// The tile must be passable, and not have another creature, or any of the
+/// blocked positions, on it.
+pub fn is_free(
+    map: &Map<GameTile>,
+    creatures: &Creatures,
+    blocked: &[Point<u32>],
+    position: Point<u32>,
+) -> bool {
+    map.get(position.x, position.y).tile.is_passable()
+        && creatures.at(position.x, position.y).is_none()
+        && !blocked.contains(&position)
+}
+
+/// Returns the tiles around the position which can be stepped on to.
+pub fn free_neighbours(
+    map: &Map<GameTile>,
+    creatures: &Creatures,
+    blocked: &[Point<u32>],
+    position: Point<u32>,
+) -> Vec<Point<u32>> {
+    DIRECTIONS
+        .iter()
+        .filter_map(|&direction| step(map, position, direction))
+        .filter(|&next| is_free(map, creatures, blocked, next))
+        .collect()
+}
+
+/// Returns the free tile next to the position which is closest to the target.
+/// Returns None if there is no free tile which gets any closer.
+pub fn step_towards(
+    map: &Map<GameTile>,
+    creatures: &Creatures,
+    blocked: &[Point<u32>],
+    position: Point<u32>,
+    target: Point<u32>,
+) -> Option<Point<u32>> {
+    free_neighbours(map, creatures, blocked, position)
+        .into_iter()
+        .min_by_key(|&next| distance_squared(next, target))
+        .filter(|&next| distance_squared(next, target) < distance_squared(position, target))
+}
+
+/// Returns true if the position is on the outer edge of the map.
+pub fn is_on_edge(
+    map: &Map<GameTile>,
+    position: Point<u32>,
+) -> bool {
+    position.x == 0
+        || position.y == 0
+        || position.x + 1 >= map.width
+        || position.y + 1 >= map.height
+}
+
+/// Finds free tiles along one edge of the map, near to each other.
+///
+/// A random edge, and a random place along it, is picked. Tiles are then
+/// taken going along the edge from there. Fewer than the count are returned if
+/// the edge runs out of free tiles.
+pub fn find_edge_positions(
+    map: &Map<GameTile>,
+    creatures: &Creatures,
+    rng: &mut Random,
+    count: usize,
+) -> Vec<Point<u32>> {
+    if map.width == 0 || map.height == 0 {
+        return Vec::new();
+    }
+
+    let horizontal = rng.chance(0.5);
+    let far_side = rng.chance(0.5);
+    let length = if horizontal {
+        map.width
+    } else {
+        map.height
+    };
+    let start = rng.range(0, length);
+
+    let fixed = match (horizontal, far_side) {
+        (true, true) => map.height - 1,
+        (false, true) => map.width - 1,
+        (_, false) => 0,
+    };
+
+    (0..length)
+        .map(|i| (start + i) % length)
+        .map(|offset| {
+            if horizontal {
+                Point::new(offset, fixed)
+            } else {
+                Point::new(fixed, offset)
+            }
+        })
+        .filter(|&position| is_free(map, creatures, &[], position))
+        .take(count)
+        .collect()
+}
+
+/// Returns which way to step to reach the nearest edge of the map soonest.
+pub fn direction_to_nearest_edge(
+    map: &Map<GameTile>,
+    position: Point<u32>,
+) -> (i32, i32) {
+    let to_left = position.x;
+    let to_right = map.width.saturating_sub(position.x + 1);
+    let to_top = position.y;
+    let to_bottom = map.height.saturating_sub(position.y + 1);
+    let nearest = to_left.min(to_right).min(to_top).min(to_bottom);
+
+    if nearest == to_left {
+        (-1, 0)
+    } else if nearest == to_right {
+        (1, 0)
+    } else if nearest == to_top {
+        (0, -1)
+    } else {
+        (0, 1)
+    }
+}
+
+/// The straight line distance between two points, squared.
+pub fn distance_squared(
+    a: Point<u32>,
+    b: Point<u32>,
+) -> u64 {
+    let dx = i64::from(a.x) - i64::from(b.x);
+    let dy = i64::from(a.y) - i64::from(b.y);
+
+    (dx * dx + dy * dy) as u64
+}
@@ -0,0 +1,190 @@
+use std::io::BufRead;
+use std::io::Error;
+use std::io::ErrorKind;
+use std::io::Result;
+use std::io::Write;
+
+use GameCommand;
+
+/// How many ticks between each hash of the game state.
+pub const STATE_HASH_INTERVAL: u64 = 100;
+
+/// A single thing which happened during a game.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LogEntry {
+    /// A command was given to the game.
+    Command {
+        /// The number of ticks which had passed when the command was given.
+        tick: u64,
+
+        /// The command itself.
+        command: GameCommand,
+    },
+
+    /// The game state was hashed.
+    Hash {
+        /// The number of ticks which had passed when the hash was taken.
+        tick: u64,
+
+        /// The hash of the whole game state.
+        hash: u64,
+    },
+}
+
+impl LogEntry {
+    /// Returns the tick this entry happened on.
+    pub fn tick(&self) -> u64 {
+        match *self {
+            LogEntry::Command {
+                tick,
+                ..
+            } => tick,
+            LogEntry::Hash {
+                tick,
+                ..
+            } => tick,
+        }
+    }
+}
+
+/// A record of a game being played.
+///
+/// This holds everything needed to play it again. The entries are stored in
+/// the order they happened.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InputLog {
+    /// The seed the game was started with.
+    pub seed: u64,
+
+    /// Every command given to the game, and every hash taken.
+    pub entries: Vec<LogEntry>,
+}
+
+impl InputLog {
+    /// Creates a new empty log, for a game using the seed given.
+    pub fn new(seed: u64) -> InputLog {
+        InputLog {
+            seed: seed,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Adds a command to the end of the log.
+    pub fn push_command(
+        &mut self,
+        tick: u64,
+        command: GameCommand,
+    ) {
+        self.entries.push(LogEntry::Command {
+            tick: tick,
+            command: command,
+        });
+    }
+
+    /// Adds a hash of the game state to the end of the log.
+    pub fn push_hash(
+        &mut self,
+        tick: u64,
+        hash: u64,
+    ) {
+        self.entries.push(LogEntry::Hash {
+            tick: tick,
+            hash: hash,
+        });
+    }
+
+    /// Writes the log out as text.
+    ///
+    /// It's written one entry per line, so it can be attached to bug reports
+    /// and read by a human.
+    pub fn write(
+        &self,
+        out: &mut Write,
+    ) -> Result<()> {
+        writeln!(out, "seed {}", self.seed)?;
+
+        for entry in &self.entries {
+            match *entry {
+                LogEntry::Command {
+                    tick,
+                    command,
+                } => writeln!(out, "command {} {}", tick, command.encode())?,
+                LogEntry::Hash {
+                    tick,
+                    hash,
+                } => writeln!(out, "hash {} {:016x}", tick, hash)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reads in a log previously written with `write`.
+    pub fn read(read_in: &mut BufRead) -> Result<InputLog> {
+        let mut log = InputLog::new(0);
+
+        for line in read_in.lines() {
+            let line = line?;
+            let words = line.split_whitespace().collect::<Vec<&str>>();
+
+            match words.as_slice() {
+                [] => {},
+                ["seed", seed] => {
+                    log.seed = seed.parse().map_err(|_| invalid_line(&line))?;
+                },
+                ["command", tick, command @ ..] => {
+                    let tick = tick.parse().map_err(|_| invalid_line(&line))?;
+                    let command =
+                        GameCommand::decode(command).ok_or_else(|| invalid_line(&line))?;
+
+                    log.push_command(tick, command);
+                },
+                ["hash", tick, hash] => {
+                    let tick = tick.parse().map_err(|_| invalid_line(&line))?;
+                    let hash = u64::from_str_radix(hash, 16).map_err(|_| invalid_line(&line))?;
+
+                    log.push_hash(tick, hash);
+                },
+                _ => return Err(invalid_line(&line)),
+            }
+        }
+
+        Ok(log)
+    }
+}
+
+fn invalid_line(line: &str) -> Error {
+    Error::new(ErrorKind::InvalidData, format!("Invalid line in input log, '{}'", line))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn write_then_read() {
+        let mut log = InputLog::new(42);
+        log.push_command(
+            3,
+            GameCommand::MovePlayer {
+                x: 1,
+                y: -1,
+            },
+        );
+        log.push_hash(100, 0xDEAD_BEEF);
+
+        let mut buffer = Vec::new();
+        log.write(&mut buffer).unwrap();
+        let read = InputLog::read(&mut Cursor::new(buffer)).unwrap();
+
+        assert_eq!(read, log);
+    }
+
+    #[test]
+    fn rejects_unknown_lines() {
+        let text = "seed 1\nbanana 2\n";
+
+        assert!(InputLog::read(&mut Cursor::new(text)).is_err());
+    }
+}
@@ -0,0 +1,18 @@
+//! Recording and replaying games.
+//!
+//! Every command given to the game is logged, along with the seed, and a hash
+//! of the game state every so often. Replaying the log against a new game with
+//! the same seed should give exactly the same hashes. If it doesn't, then the
+//! simulation has diverged.
+//!
+
+mod input_log;
+mod replay;
+mod state_hasher;
+
+pub use self::input_log::InputLog;
+pub use self::input_log::LogEntry;
+pub use self::input_log::STATE_HASH_INTERVAL;
+pub use self::replay::replay;
+pub use self::replay::ReplayError;
+pub use self::state_hasher::StateHasher;
@@ -0,0 +1,217 @@
+use std::error::Error;
+use std::fmt;
+
+use replay::InputLog;
+use replay::LogEntry;
+use Game;
+
+/// The ways a replay can fail.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ReplayError {
+    /// The game was not started with the same seed as the log.
+    SeedMismatch {
+        /// The seed in the log.
+        expected: u64,
+
+        /// The seed the game was started with.
+        actual: u64,
+    },
+
+    /// The game had already been played, before the replay started.
+    NotNewGame,
+
+    /// The game state hash did not match the hash in the log.
+    /// The replay has gone out of step with the original game.
+    Diverged {
+        /// The tick where the hashes differed.
+        tick: u64,
+
+        /// The hash from the log.
+        expected: u64,
+
+        /// The hash from the replay.
+        actual: u64,
+    },
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        match *self {
+            ReplayError::SeedMismatch {
+                expected,
+                actual,
+            } => write!(f, "game seed {} does not match log seed {}", actual, expected),
+            ReplayError::NotNewGame => write!(f, "replays must start from a new game"),
+            ReplayError::Diverged {
+                tick,
+                expected,
+                actual,
+            } => {
+                write!(
+                    f,
+                    "simulation diverged at tick {}, expected hash {:016x}, found {:016x}",
+                    tick, expected, actual
+                )
+            },
+        }
+    }
+}
+
+impl Error for ReplayError {
+}
+
+/// Plays the log given against the game, and checks it ends up the same.
+///
+/// The game must be brand new, and must be set up in the same way as the game
+/// which made the log. Same map, same seed, and same setup. Every command is
+/// applied on the same tick, and in the same order, as before. Every hash in
+/// the log is checked along the way.
+pub fn replay(
+    game: &mut Game,
+    log: &InputLog,
+) -> Result<(), ReplayError> {
+    if game.seed() != log.seed {
+        return Err(ReplayError::SeedMismatch {
+            expected: log.seed,
+            actual: game.seed(),
+        });
+    }
+
+    if game.ticks() != 0 {
+        return Err(ReplayError::NotNewGame);
+    }
+
+    for entry in &log.entries {
+        while game.ticks() < entry.tick() {
+            game.tick();
+        }
+
+        match *entry {
+            LogEntry::Command {
+                command,
+                ..
+            } => game.apply(command),
+            LogEntry::Hash {
+                tick,
+                hash,
+            } => {
+                let actual = game.state_hash();
+
+                if hash != actual {
+                    return Err(ReplayError::Diverged {
+                        tick: tick,
+                        expected: hash,
+                        actual: actual,
+                    });
+                }
+            },
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use world::biomes::Biome;
+    use world::calendar::WorldCalendar;
+    use world::calendar::WorldTime;
+    use world::map::Map;
+    use world::player::Player;
+    use world::tiles::Tile;
+    use world::world_setup::WorldSetup;
+    use scheduler::default_systems;
+    use GameCommand;
+    use GameSetup;
+
+    struct TestCalendar {}
+
+    impl WorldCalendar for TestCalendar {
+        fn get_time(
+            &self,
+            time: u64,
+        ) -> WorldTime {
+            WorldTime {
+                second: (time % 60) as u8,
+                minute: 0,
+                hour: 12,
+                day: 0,
+                month: 0,
+                year: 0,
+            }
+        }
+
+        fn to_timestamp(
+            &self,
+            time: WorldTime,
+        ) -> u64 {
+            u64::from(time.second)
+        }
+    }
+
+    fn new_game(map: &Map<Tile>) -> Game<'static> {
+        Game::new(
+            map,
+            Player::new(5, 5),
+            WorldSetup {
+                calendar: &TestCalendar {},
+                biome: Biome::Grassland,
+                biomes: None,
+                latitude: 30.0,
+                elevation: 0.0,
+            },
+            GameSetup {
+                time_tick_speed: 5,
+                view_distance: 8,
+                seed: 7,
+                systems: default_systems(),
+            },
+        )
+    }
+
+    fn play(map: &Map<Tile>) -> InputLog {
+        let mut game = new_game(map);
+
+        for i in 0..250 {
+            if i % 10 == 0 {
+                game.apply(GameCommand::MovePlayer {
+                    x: 1,
+                    y: 0,
+                });
+            }
+
+            game.tick();
+        }
+
+        game.record_state_hash();
+        game.input_log().clone()
+    }
+
+    #[test]
+    fn replay_matches_original() {
+        let map = Map::new(20, 20, Tile::Grass);
+        let log = play(&map);
+
+        assert_eq!(replay(&mut new_game(&map), &log), Ok(()));
+    }
+
+    #[test]
+    fn replay_detects_divergence() {
+        let map = Map::new(20, 20, Tile::Grass);
+        let mut log = play(&map);
+
+        // Drop the first command, so the player ends up somewhere else.
+        log.entries.remove(0);
+
+        match replay(&mut new_game(&map), &log) {
+            Err(ReplayError::Diverged {
+                ..
+            }) => {},
+            result => panic!("expected divergence, got {:?}", result),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+/// Builds a hash of the game state.
+///
+/// This uses FNV-1a, rather than the standard library hasher. The standard
+/// hasher is free to change between versions of Rust, and we need hashes
+/// which stay the same so old logs can still be replayed.
+pub struct StateHasher {
+    hash: u64,
+}
+
+/// The FNV-1a 64 bit offset basis.
+const FNV_OFFSET: u64 = 0xCBF2_9CE4_8422_2325;
+
+/// The FNV-1a 64 bit prime.
+const FNV_PRIME: u64 = 0x0000_0100_0000_01B3;
+
+impl StateHasher {
+    /// Trivial constructor.
+    pub fn new() -> StateHasher {
+        StateHasher {
+            hash: FNV_OFFSET,
+        }
+    }
+
+    /// Returns the hash of everything written so far.
+    pub fn finish(&self) -> u64 {
+        self.hash
+    }
+
+    /// Adds a byte to the hash.
+    pub fn write_u8(
+        &mut self,
+        n: u8,
+    ) {
+        self.hash ^= u64::from(n);
+        self.hash = self.hash.wrapping_mul(FNV_PRIME);
+    }
+
+    /// Adds a bool to the hash.
+    pub fn write_bool(
+        &mut self,
+        b: bool,
+    ) {
+        self.write_u8(b as u8);
+    }
+
+    /// Adds a u32 to the hash.
+    pub fn write_u32(
+        &mut self,
+        n: u32,
+    ) {
+        self.write_u64(u64::from(n));
+    }
+
+    /// Adds a u64 to the hash.
+    pub fn write_u64(
+        &mut self,
+        n: u64,
+    ) {
+        for i in 0..8 {
+            self.write_u8((n >> (i * 8)) as u8);
+        }
+    }
+
+    /// Adds an i32 to the hash.
+    pub fn write_i32(
+        &mut self,
+        n: i32,
+    ) {
+        self.write_u32(n as u32);
+    }
+
+    /// Adds an f32 to the hash, using it's exact bits.
+    pub fn write_f32(
+        &mut self,
+        n: f32,
+    ) {
+        self.write_u32(n.to_bits());
+    }
+}
@@ -0,0 +1,17 @@
+//! Scheduling.
+//!
+//! Which parts of the game are updated on each tick, how often, and in what
+//! order. Each system is given a period and a priority through the
+//! `GameSetup`, and how long they take is recorded for profiling.
+//!
+
+mod scheduler;
+mod system;
+mod system_entry;
+mod system_timing;
+
+pub use self::scheduler::Scheduler;
+pub use self::system::System;
+pub use self::system_entry::default_systems;
+pub use self::system_entry::SystemEntry;
+pub use self::system_timing::SystemTiming;
@@ -0,0 +1,109 @@
+use std::time::Duration;
+
+use scheduler::System;
+use scheduler::SystemEntry;
+use scheduler::SystemTiming;
+
+/// Decides which systems run on each tick, and in what order, and keeps track
+/// of how long they take.
+pub struct Scheduler {
+    /// Every system, sorted by priority.
+    entries: Vec<SystemEntry>,
+
+    /// How long each system has taken, in the same order as the entries.
+    timings: Vec<SystemTiming>,
+}
+
+impl Scheduler {
+    /// Creates a scheduler for the systems given.
+    ///
+    /// Systems with the same priority run in the order they are given.
+    pub fn new(entries: &[SystemEntry]) -> Scheduler {
+        let mut entries = entries.to_vec();
+        entries.sort_by_key(|entry| entry.priority);
+
+        let timings = entries.iter().map(|entry| SystemTiming::new(entry.system)).collect();
+
+        Scheduler {
+            entries: entries,
+            timings: timings,
+        }
+    }
+
+    /// Returns the systems to run on the tick given, in the order they should
+    /// be run.
+    ///
+    /// This also starts a new tick for the timings.
+    pub fn begin_tick(
+        &mut self,
+        tick: u64,
+    ) -> Vec<System> {
+        for timing in self.timings.iter_mut() {
+            timing.last = Duration::from_secs(0);
+        }
+
+        self.entries.iter().filter(|entry| entry.is_due(tick)).map(|entry| entry.system).collect()
+    }
+
+    /// Notes down how long a system took to run.
+    pub fn record(
+        &mut self,
+        system: System,
+        elapsed: Duration,
+    ) {
+        if let Some(timing) = self.timings.iter_mut().find(|timing| timing.system == system) {
+            timing.runs += 1;
+            timing.total += elapsed;
+            timing.slowest = timing.slowest.max(elapsed);
+            timing.last += elapsed;
+        }
+    }
+
+    /// Returns every system, in the order they run.
+    pub fn entries(&self) -> &[SystemEntry] {
+        &self.entries
+    }
+
+    /// Returns how long every system has taken, in the order they run.
+    pub fn timings(&self) -> &[SystemTiming] {
+        &self.timings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runs_due_systems_by_priority() {
+        let mut disabled = SystemEntry::new(System::Farms, 1, 0);
+        disabled.enabled = false;
+
+        let mut scheduler = Scheduler::new(&[
+            SystemEntry::new(System::Jobs, 1, 10),
+            SystemEntry::new(System::Wildlife, 3, 1),
+            disabled,
+            SystemEntry::new(System::Lighting, 1, 2),
+        ]);
+
+        assert_eq!(scheduler.begin_tick(1), vec![System::Lighting, System::Jobs]);
+        assert_eq!(scheduler.begin_tick(3), vec![System::Wildlife, System::Lighting, System::Jobs]);
+    }
+
+    #[test]
+    fn records_timings() {
+        let mut scheduler = Scheduler::new(&[SystemEntry::new(System::Jobs, 1, 0)]);
+
+        scheduler.begin_tick(1);
+        scheduler.record(System::Jobs, Duration::from_millis(4));
+        scheduler.begin_tick(2);
+        scheduler.record(System::Jobs, Duration::from_millis(2));
+
+        let timing = scheduler.timings()[0];
+        assert_eq!(timing.runs, 2);
+        assert_eq!(timing.total, Duration::from_millis(6));
+        assert_eq!(timing.slowest, Duration::from_millis(4));
+        assert_eq!(timing.last, Duration::from_millis(2));
+        assert_eq!(timing.average(), Duration::from_millis(3));
+    }
+}
@@ -0,0 +1,81 @@
+/// One part of the game which is updated as the game ticks.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum System {
+    /// Works out what the player can see.
+    FieldOfView,
+
+    /// Moves the light from the sky along with the time of day.
+    Lighting,
+
+    /// Changes the weather, and settles snow and rain on the map.
+    Weather,
+
+    /// Spots the change of season.
+    Seasons,
+
+    /// Sets off timers which are due, like caravans arriving.
+    Timers,
+
+    /// Bleeding, and dying from wounds.
+    Wounds,
+
+    /// Migrants arriving, and citizens' moods.
+    Citizens,
+
+    /// Crops growing, and farm plots asking to be tended.
+    Farms,
+
+    /// Handing out jobs, and working on them.
+    Jobs,
+
+    /// Wild animals arriving, grazing, and fleeing.
+    Wildlife,
+
+    /// Invaders attacking the fortress.
+    Invasions,
+
+    /// Merchants travelling to, and from, the trade depot.
+    Caravans,
+}
+
+impl System {
+    /// Every system, in the order they run by default.
+    pub const ALL: [System; 12] = [
+        System::FieldOfView,
+        System::Lighting,
+        System::Weather,
+        System::Seasons,
+        System::Timers,
+        System::Wounds,
+        System::Citizens,
+        System::Farms,
+        System::Jobs,
+        System::Wildlife,
+        System::Invasions,
+        System::Caravans,
+    ];
+
+    /// Finds the system with the name given.
+    /// i.e. 'field_of_view'.
+    pub fn from_name(name: &str) -> Option<System> {
+        System::ALL.iter().cloned().find(|system| system.name() == name)
+    }
+
+    /// A single word naming the system.
+    pub fn name(self) -> &'static str {
+        match self {
+            System::FieldOfView => "field_of_view",
+            System::Lighting => "lighting",
+            System::Weather => "weather",
+            System::Seasons => "seasons",
+            System::Timers => "timers",
+            System::Wounds => "wounds",
+            System::Citizens => "citizens",
+            System::Farms => "farms",
+            System::Jobs => "jobs",
+            System::Wildlife => "wildlife",
+            System::Invasions => "invasions",
+            System::Caravans => "caravans",
+        }
+    }
+}
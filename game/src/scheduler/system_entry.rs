@@ -0,0 +1,51 @@
+use scheduler::System;
+
+/// How, and when, a system is run.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SystemEntry {
+    /// The system to run.
+    pub system: System,
+
+    /// How many ticks between each run. 1 runs it every tick.
+    pub period: u64,
+
+    /// Systems with a lower priority run first, within a tick.
+    pub priority: u32,
+
+    /// If it's run at all.
+    pub enabled: bool,
+}
+
+impl SystemEntry {
+    /// Creates an entry for a system which is turned on.
+    pub fn new(
+        system: System,
+        period: u64,
+        priority: u32,
+    ) -> SystemEntry {
+        SystemEntry {
+            system: system,
+            period: period,
+            priority: priority,
+            enabled: true,
+        }
+    }
+
+    /// Returns true if the system should run on the tick given.
+    pub fn is_due(
+        &self,
+        tick: u64,
+    ) -> bool {
+        self.enabled && tick % self.period.max(1) == 0
+    }
+}
+
+/// Returns an entry for every system, running each tick, in the order of
+/// `System::ALL`.
+pub fn default_systems() -> Vec<SystemEntry> {
+    System::ALL
+        .iter()
+        .enumerate()
+        .map(|(i, &system)| SystemEntry::new(system, 1, i as u32 * 10))
+        .collect()
+}
@@ -0,0 +1,45 @@
+use std::time::Duration;
+
+use scheduler::System;
+
+/// How long a system has taken to run, for profiling.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SystemTiming {
+    /// The system timed.
+    pub system: System,
+
+    /// How many times it has run.
+    pub runs: u64,
+
+    /// How long it has taken, over every run.
+    pub total: Duration,
+
+    /// How long the slowest run took.
+    pub slowest: Duration,
+
+    /// How long it took on the last tick.
+    /// This is zero if it didn't run on the last tick.
+    pub last: Duration,
+}
+
+impl SystemTiming {
+    /// Creates a timing for a system which hasn't run yet.
+    pub fn new(system: System) -> SystemTiming {
+        SystemTiming {
+            system: system,
+            runs: 0,
+            total: Duration::from_secs(0),
+            slowest: Duration::from_secs(0),
+            last: Duration::from_secs(0),
+        }
+    }
+
+    /// Returns how long it takes on average, for each run.
+    pub fn average(&self) -> Duration {
+        if self.runs == 0 {
+            Duration::from_secs(0)
+        } else {
+            Duration::from_nanos((self.total.as_nanos() / u128::from(self.runs)) as u64)
+        }
+    }
+}
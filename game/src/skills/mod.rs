@@ -0,0 +1,10 @@
+//! Skills.
+//!
+//! How good a creature is at things.
+//!
+
+mod skill;
+mod skills;
+
+pub use self::skill::Skill;
+pub use self::skills::Skills;
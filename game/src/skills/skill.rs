@@ -0,0 +1,55 @@
+/// Something a creature can be good, or bad, at.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Skill {
+    /// Landing blows on an opponent.
+    Fighting,
+
+    /// Getting out of the way of blows.
+    Dodging,
+
+    /// Blocking blows with a shield.
+    Shield,
+
+    /// Digging through rock.
+    Mining,
+
+    /// Felling trees.
+    Woodcutting,
+
+    /// Building with stone.
+    Masonry,
+
+    /// Making things out of wood.
+    Carpentry,
+
+    /// Planting and harvesting crops.
+    Farming,
+}
+
+impl Skill {
+    /// Every skill, in order.
+    pub const ALL: [Skill; 8] = [
+        Skill::Fighting,
+        Skill::Dodging,
+        Skill::Shield,
+        Skill::Mining,
+        Skill::Woodcutting,
+        Skill::Masonry,
+        Skill::Carpentry,
+        Skill::Farming,
+    ];
+
+    /// A human readable name for the skill.
+    pub fn name(self) -> &'static str {
+        match self {
+            Skill::Fighting => "fighting",
+            Skill::Dodging => "dodging",
+            Skill::Shield => "shield",
+            Skill::Mining => "mining",
+            Skill::Woodcutting => "woodcutting",
+            Skill::Masonry => "masonry",
+            Skill::Carpentry => "carpentry",
+            Skill::Farming => "farming",
+        }
+    }
+}
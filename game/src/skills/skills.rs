@@ -0,0 +1,129 @@
+use std::collections::BTreeMap;
+
+use skills::Skill;
+
+/// The highest level a skill can reach.
+pub const MAX_SKILL_LEVEL: u8 = 20;
+
+/// How much experience it takes to go from level 0 to level 1.
+/// Each level after that takes this much more again.
+const EXPERIENCE_PER_LEVEL: u32 = 100;
+
+/// How skilled a creature is at each skill.
+///
+/// Any skill not set is at level 0.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Skills {
+    levels: BTreeMap<Skill, u8>,
+
+    /// Experience gained towards the next level of each skill.
+    experience: BTreeMap<Skill, u32>,
+}
+
+impl Skills {
+    /// Creates a new set of skills, all at level 0.
+    pub fn new() -> Skills {
+        Skills {
+            levels: BTreeMap::new(),
+            experience: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the level for the skill given.
+    pub fn level(
+        &self,
+        skill: Skill,
+    ) -> u8 {
+        self.levels.get(&skill).cloned().unwrap_or(0)
+    }
+
+    /// Sets the level for the skill given.
+    /// Anything above the max level is capped.
+    pub fn set_level(
+        &mut self,
+        skill: Skill,
+        level: u8,
+    ) {
+        self.levels.insert(skill, level.min(MAX_SKILL_LEVEL));
+    }
+
+    /// Returns the experience gained towards the next level of the skill.
+    pub fn experience(
+        &self,
+        skill: Skill,
+    ) -> u32 {
+        self.experience.get(&skill).cloned().unwrap_or(0)
+    }
+
+    /// Adds experience to the skill, levelling it up if there is enough.
+    /// Returns true if the skill went up a level.
+    pub fn add_experience(
+        &mut self,
+        skill: Skill,
+        amount: u32,
+    ) -> bool {
+        let mut level = self.level(skill);
+        if level >= MAX_SKILL_LEVEL {
+            return false;
+        }
+
+        let mut experience = self.experience(skill) + amount;
+        let mut levelled_up = false;
+
+        while level < MAX_SKILL_LEVEL && experience >= experience_for_level(level + 1) {
+            experience -= experience_for_level(level + 1);
+            level += 1;
+            levelled_up = true;
+        }
+
+        if level >= MAX_SKILL_LEVEL {
+            experience = 0;
+        }
+
+        self.set_level(skill, level);
+        self.experience.insert(skill, experience);
+
+        levelled_up
+    }
+
+    /// Iterates over every skill with a level above 0.
+    pub fn iter(&self) -> impl Iterator<Item = (Skill, u8)> + '_ {
+        self.levels.iter().filter(|&(_, &level)| level > 0).map(|(&skill, &level)| (skill, level))
+    }
+}
+
+/// Returns the experience needed to reach the level given, from the level
+/// before it.
+pub fn experience_for_level(level: u8) -> u32 {
+    EXPERIENCE_PER_LEVEL * u32::from(level)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn experience_levels_up_skills() {
+        let mut skills = Skills::new();
+
+        assert!(!skills.add_experience(Skill::Mining, 99));
+        assert_eq!(skills.level(Skill::Mining), 0);
+
+        assert!(skills.add_experience(Skill::Mining, 1));
+        assert_eq!(skills.level(Skill::Mining), 1);
+        assert_eq!(skills.experience(Skill::Mining), 0);
+
+        assert!(skills.add_experience(Skill::Mining, 350));
+        assert_eq!(skills.level(Skill::Mining), 2);
+        assert_eq!(skills.experience(Skill::Mining), 150);
+    }
+
+    #[test]
+    fn skills_stop_at_the_max_level() {
+        let mut skills = Skills::new();
+        skills.add_experience(Skill::Masonry, 1_000_000);
+
+        assert_eq!(skills.level(Skill::Masonry), MAX_SKILL_LEVEL);
+        assert!(!skills.add_experience(Skill::Masonry, 1_000_000));
+    }
+}
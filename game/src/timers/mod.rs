@@ -0,0 +1,17 @@
+//! Timers.
+//!
+//! Things which are due to happen at a set time in the game, like caravans
+//! arriving. Timers can go off once, or keep going off on a schedule
+//! measured in days, or seasons.
+//!
+
+mod recurrence;
+mod timed_action;
+mod timer;
+mod timers;
+
+pub use self::recurrence::next_season_start;
+pub use self::recurrence::Recurrence;
+pub use self::timed_action::TimedAction;
+pub use self::timer::Timer;
+pub use self::timers::Timers;
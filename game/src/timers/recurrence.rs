@@ -0,0 +1,110 @@
+use world::calendar::WorldCalendar;
+use world::calendar::WorldTime;
+
+/// The most days searched through, when looking for the start of the next
+/// season. This is enough for any sensible calendar.
+const MAX_SEASON_SEARCH_DAYS: u64 = 10_000;
+
+/// How often a timer goes off.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Recurrence {
+    /// It goes off once, and is then gone.
+    Once,
+
+    /// It goes off again every so many days.
+    EveryDays {
+        /// The number of days between each time it goes off.
+        days: u32,
+    },
+
+    /// It goes off at the start of the first day of each season.
+    EachSeason,
+}
+
+impl Recurrence {
+    /// Returns when a timer which has just gone off, at the time given,
+    /// should go off next. Returns None if it shouldn't go off again.
+    pub fn next_due(
+        self,
+        calendar: &WorldCalendar,
+        due: u64,
+    ) -> Option<u64> {
+        let next = match self {
+            Recurrence::Once => None,
+            Recurrence::EveryDays {
+                days,
+            } => Some(due + u64::from(days) * day_length(calendar)),
+            Recurrence::EachSeason => next_season_start(calendar, due),
+        };
+
+        next.filter(|&next| next > due)
+    }
+
+    /// Writes the recurrence out as words.
+    /// i.e. 'every_days 3'.
+    pub fn encode(self) -> String {
+        match self {
+            Recurrence::Once => "once".to_string(),
+            Recurrence::EveryDays {
+                days,
+            } => format!("every_days {}", days),
+            Recurrence::EachSeason => "each_season".to_string(),
+        }
+    }
+
+    /// Reads a recurrence previously written with `encode`.
+    pub fn decode(words: &[&str]) -> Option<Recurrence> {
+        match words {
+            ["once"] => Some(Recurrence::Once),
+            ["every_days", days] => {
+                Some(Recurrence::EveryDays {
+                    days: days.parse().ok()?,
+                })
+            },
+            ["each_season"] => Some(Recurrence::EachSeason),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the start of the first day of the next season, after the time
+/// given. Returns None if the season never changes.
+pub fn next_season_start(
+    calendar: &WorldCalendar,
+    time: u64,
+) -> Option<u64> {
+    let day = day_length(calendar);
+    if day == 0 {
+        return None;
+    }
+
+    let season = calendar.get_season(time);
+    let day_start = calendar.to_timestamp(WorldTime {
+        second: 0,
+        minute: 0,
+        hour: 0,
+        ..calendar.get_time(time)
+    });
+
+    (1..MAX_SEASON_SEARCH_DAYS)
+        .map(|days| day_start + days * day)
+        .find(|&start| calendar.get_season(start) != season)
+}
+
+/// Returns how many seconds there are in a day.
+fn day_length(calendar: &WorldCalendar) -> u64 {
+    let start = WorldTime {
+        second: 0,
+        minute: 0,
+        hour: 0,
+        day: 0,
+        month: 0,
+        year: 0,
+    };
+    let next_day = WorldTime {
+        day: 1,
+        ..start
+    };
+
+    calendar.to_timestamp(next_day).saturating_sub(calendar.to_timestamp(start))
+}
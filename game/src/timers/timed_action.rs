@@ -0,0 +1,33 @@
+/// Something which happens when a timer goes off.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TimedAction {
+    /// A wave of migrants may arrive.
+    Migrants,
+
+    /// Merchants may arrive at the trade depot.
+    Caravan,
+
+    /// Invaders may lay siege to the fortress.
+    Siege,
+}
+
+impl TimedAction {
+    /// Every action.
+    pub const ALL: [TimedAction; 3] =
+        [TimedAction::Migrants, TimedAction::Caravan, TimedAction::Siege];
+
+    /// Finds the action with the name given.
+    /// i.e. 'migrants'.
+    pub fn from_name(name: &str) -> Option<TimedAction> {
+        TimedAction::ALL.iter().cloned().find(|action| action.name() == name)
+    }
+
+    /// A single word naming the action.
+    pub fn name(self) -> &'static str {
+        match self {
+            TimedAction::Migrants => "migrants",
+            TimedAction::Caravan => "caravan",
+            TimedAction::Siege => "siege",
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use timers::Recurrence;
+use timers::TimedAction;
+
+/// Something due to happen at a set time.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Timer {
+    /// The game time it goes off at, in seconds.
+    pub due: u64,
+
+    /// When it goes off again, after it has gone off.
+    pub recurrence: Recurrence,
+
+    /// What happens when it goes off.
+    pub action: TimedAction,
+}
@@ -0,0 +1,214 @@
+use std::io::BufRead;
+use std::io::Error;
+use std::io::ErrorKind;
+use std::io::Result;
+use std::io::Write;
+
+use world::calendar::WorldCalendar;
+
+use timers::Recurrence;
+use timers::TimedAction;
+use timers::Timer;
+
+/// Every timer waiting to go off, in the order they will go off.
+///
+/// Timers due at the same time go off in the order they were added.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Timers {
+    /// The timers, soonest first.
+    timers: Vec<Timer>,
+}
+
+impl Timers {
+    /// Creates a new empty set of timers.
+    pub fn new() -> Timers {
+        Timers {
+            timers: Vec::new(),
+        }
+    }
+
+    /// Adds a timer, due to go off at the game time given.
+    pub fn schedule(
+        &mut self,
+        due: u64,
+        recurrence: Recurrence,
+        action: TimedAction,
+    ) {
+        self.insert(Timer {
+            due: due,
+            recurrence: recurrence,
+            action: action,
+        });
+    }
+
+    /// Iterates over every timer, soonest first.
+    pub fn iter(&self) -> impl Iterator<Item = &Timer> + '_ {
+        self.timers.iter()
+    }
+
+    /// Returns the number of timers waiting.
+    pub fn len(&self) -> usize {
+        self.timers.len()
+    }
+
+    /// Returns true if there are no timers waiting.
+    pub fn is_empty(&self) -> bool {
+        self.timers.is_empty()
+    }
+
+    /// Takes out every timer due by the time given, and returns what they do,
+    /// in the order they go off.
+    ///
+    /// Recurring timers are added back in, for the next time they're due.
+    pub fn take_due(
+        &mut self,
+        calendar: &WorldCalendar,
+        time: u64,
+    ) -> Vec<TimedAction> {
+        let mut actions = Vec::new();
+
+        while self.timers.first().is_some_and(|timer| timer.due <= time) {
+            let timer = self.timers.remove(0);
+            actions.push(timer.action);
+
+            if let Some(due) = timer.recurrence.next_due(calendar, timer.due) {
+                self.insert(Timer {
+                    due: due,
+                    ..timer
+                });
+            }
+        }
+
+        actions
+    }
+
+    /// Writes the timers out as text, one per line.
+    pub fn write(
+        &self,
+        out: &mut Write,
+    ) -> Result<()> {
+        for timer in &self.timers {
+            writeln!(
+                out,
+                "timer {} {} {}",
+                timer.due,
+                timer.action.name(),
+                timer.recurrence.encode()
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Reads in timers previously written with `write`.
+    pub fn read(read_in: &mut BufRead) -> Result<Timers> {
+        let mut timers = Timers::new();
+
+        for line in read_in.lines() {
+            let line = line?;
+            let words = line.split_whitespace().collect::<Vec<&str>>();
+
+            match words.as_slice() {
+                [] => {},
+                ["timer", due, action, recurrence @ ..] => {
+                    let due = due.parse().map_err(|_| invalid_line(&line))?;
+                    let action =
+                        TimedAction::from_name(action).ok_or_else(|| invalid_line(&line))?;
+                    let recurrence =
+                        Recurrence::decode(recurrence).ok_or_else(|| invalid_line(&line))?;
+
+                    timers.schedule(due, recurrence, action);
+                },
+                _ => return Err(invalid_line(&line)),
+            }
+        }
+
+        Ok(timers)
+    }
+
+    /// Adds the timer after every other timer due at, or before, the same
+    /// time.
+    fn insert(
+        &mut self,
+        timer: Timer,
+    ) {
+        let index = self.timers.iter().take_while(|other| other.due <= timer.due).count();
+
+        self.timers.insert(index, timer);
+    }
+}
+
+fn invalid_line(line: &str) -> Error {
+    Error::new(ErrorKind::InvalidData, format!("Invalid line in timers, '{}'", line))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use timers::next_season_start;
+    use world::calendar::Calendar;
+
+    const DAY: u64 = 24 * 60 * 60;
+
+    #[test]
+    fn timers_go_off_in_order() {
+        let calendar = Calendar::standard();
+        let mut timers = Timers::new();
+        timers.schedule(20, Recurrence::Once, TimedAction::Siege);
+        timers.schedule(10, Recurrence::Once, TimedAction::Caravan);
+        timers.schedule(20, Recurrence::Once, TimedAction::Migrants);
+
+        assert!(timers.take_due(&calendar, 5).is_empty());
+        assert_eq!(
+            timers.take_due(&calendar, 20),
+            vec![TimedAction::Caravan, TimedAction::Siege, TimedAction::Migrants]
+        );
+        assert!(timers.is_empty());
+    }
+
+    #[test]
+    fn recurring_timers_go_off_again() {
+        let calendar = Calendar::standard();
+        let mut timers = Timers::new();
+        timers.schedule(
+            DAY,
+            Recurrence::EveryDays {
+                days: 3,
+            },
+            TimedAction::Caravan,
+        );
+
+        let summer = next_season_start(&calendar, 0).unwrap();
+        timers.schedule(summer, Recurrence::EachSeason, TimedAction::Migrants);
+
+        assert_eq!(summer, 3 * 28 * DAY);
+        assert_eq!(timers.take_due(&calendar, DAY).len(), 1);
+        assert_eq!(timers.take_due(&calendar, 4 * DAY - 1).len(), 0);
+        assert_eq!(timers.take_due(&calendar, 4 * DAY).len(), 1);
+
+        timers.take_due(&calendar, summer);
+        let autumn = timers.iter().find(|timer| timer.action == TimedAction::Migrants).unwrap().due;
+        assert_eq!(autumn, 6 * 28 * DAY);
+    }
+
+    #[test]
+    fn write_then_read() {
+        let mut timers = Timers::new();
+        timers.schedule(100, Recurrence::Once, TimedAction::Siege);
+        timers.schedule(
+            50,
+            Recurrence::EveryDays {
+                days: 7,
+            },
+            TimedAction::Caravan,
+        );
+        timers.schedule(200, Recurrence::EachSeason, TimedAction::Migrants);
+
+        let mut buffer = Vec::new();
+        timers.write(&mut buffer).unwrap();
+        let read = Timers::read(&mut Cursor::new(buffer)).unwrap();
+
+        assert_eq!(read, timers);
+    }
+}
@@ -0,0 +1,55 @@
+use util::shapes::Point;
+
+use creatures::CreatureId;
+use items::Item;
+use items::ItemKind;
+
+/// What a caravan is doing.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CaravanState {
+    /// On the way to the trade depot.
+    Travelling,
+
+    /// At the depot, and willing to trade.
+    Trading {
+        /// How many ticks until they pack up.
+        remaining: u32,
+    },
+
+    /// Heading back off the map.
+    Leaving,
+}
+
+/// A group of merchants visiting the fortress.
+#[derive(Clone, Debug)]
+pub struct Caravan {
+    /// The merchant leading the caravan.
+    pub merchant: CreatureId,
+
+    /// What they have for sale.
+    pub goods: Vec<Item>,
+
+    /// The kind of goods they will pay extra for.
+    pub likes: ItemKind,
+
+    /// The kind of goods they don't want.
+    pub dislikes: ItemKind,
+
+    /// What they are doing right now.
+    pub state: CaravanState,
+
+    /// The steps to wherever they are going next.
+    pub(crate) path: Vec<Point<u32>>,
+}
+
+impl Caravan {
+    /// Returns true if they are at the depot, and willing to trade.
+    pub fn is_trading(&self) -> bool {
+        match self.state {
+            CaravanState::Trading {
+                ..
+            } => true,
+            CaravanState::Travelling | CaravanState::Leaving => false,
+        }
+    }
+}
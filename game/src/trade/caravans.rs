@@ -0,0 +1,396 @@
+use util::random::Random;
+use util::shapes::Point;
+use world::calendar::Season;
+use world::map::Map;
+
+use combat::Body;
+use combat::BodyPlan;
+use creatures::CreatureId;
+use creatures::Creatures;
+use creatures::Faction;
+use events::GameEvent;
+use items::Item;
+use items::ItemKind;
+use items::Quality;
+use navigation::find_edge_positions;
+use navigation::find_path;
+use navigation::is_on_edge;
+use trade::evaluate_offer;
+use trade::Caravan;
+use trade::CaravanState;
+use trade::OfferEvaluation;
+use GameTile;
+
+/// The character merchants are drawn with.
+const MERCHANT_SYMBOL: char = 'M';
+
+/// How big a merchant is.
+const MERCHANT_SIZE: u8 = 10;
+
+/// The fewest goods a caravan brings.
+const MIN_GOODS: u32 = 4;
+
+/// The most goods a caravan brings.
+const MAX_GOODS: u32 = 10;
+
+/// The best skill the makers of the merchants' goods can have.
+const MAX_MAKER_LEVEL: u32 = 16;
+
+/// How long merchants stay at the depot, in ticks.
+const TRADING_TIME: u32 = 2_000;
+
+/// Merchants who visit the fortress, one caravan at a time.
+pub struct Caravans {
+    /// The caravan visiting right now, if there is one.
+    caravan: Option<Caravan>,
+}
+
+impl Caravans {
+    /// Creates a fortress no one is visiting.
+    pub fn new() -> Caravans {
+        Caravans {
+            caravan: None,
+        }
+    }
+
+    /// Returns the caravan visiting right now, if there is one.
+    pub fn caravan(&self) -> Option<&Caravan> {
+        self.caravan.as_ref()
+    }
+
+    /// Sends a caravan, if it's the right time of year for one.
+    ///
+    /// Merchants travel in summer and autumn. They arrive at the edge of the
+    /// map with a random load of goods, and a taste for one kind of goods over
+    /// another. Returns the announcement for their arrival, if they came.
+    pub fn on_season_start(
+        &mut self,
+        map: &Map<GameTile>,
+        creatures: &mut Creatures,
+        rng: &mut Random,
+        season: Season,
+    ) -> Option<GameEvent> {
+        if self.caravan.is_some() || (season != Season::Summer && season != Season::Autumn) {
+            return None;
+        }
+
+        let position = *find_edge_positions(map, creatures, rng, 1).first()?;
+        let merchant = creatures.spawn(
+            "merchant".to_string(),
+            MERCHANT_SYMBOL,
+            Faction::Visitor,
+            position,
+            Body::new(BodyPlan::Humanoid, MERCHANT_SIZE),
+        );
+
+        let goods = (0..rng.range(MIN_GOODS, MAX_GOODS + 1))
+            .filter_map(|_| {
+                let kind = *rng.pick(&ItemKind::ALL)?;
+                let level = rng.range(0, MAX_MAKER_LEVEL + 1) as u8;
+
+                Some(Item {
+                    kind: kind,
+                    quality: Quality::roll(rng, level),
+                    position: position,
+                })
+            })
+            .collect::<Vec<Item>>();
+
+        let likes = *rng.pick(&ItemKind::ALL)?;
+        let others =
+            ItemKind::ALL.iter().cloned().filter(|&kind| kind != likes).collect::<Vec<ItemKind>>();
+        let dislikes = *rng.pick(&others)?;
+
+        let count = goods.len();
+        self.caravan = Some(Caravan {
+            merchant: merchant,
+            goods: goods,
+            likes: likes,
+            dislikes: dislikes,
+            state: CaravanState::Travelling,
+            path: Vec::new(),
+        });
+
+        Some(GameEvent::CaravanArrived {
+            goods: count,
+        })
+    }
+
+    /// Moves the caravan along.
+    ///
+    /// Merchants walk to the depot, trade for a while, and then walk off the
+    /// map. If there is no depot, or it cannot be reached, they turn around
+    /// and leave. Returns anything worth telling the player about.
+    pub fn update(
+        &mut self,
+        map: &Map<GameTile>,
+        creatures: &mut Creatures,
+        depot: Option<Point<u32>>,
+    ) -> Vec<GameEvent> {
+        let mut events = Vec::new();
+
+        let is_gone = match self.caravan {
+            Some(ref mut caravan) => update_caravan(map, creatures, depot, caravan, &mut events),
+            None => return events,
+        };
+
+        if is_gone {
+            self.caravan = None;
+        }
+
+        events
+    }
+
+    /// Works out what the merchants make of a deal.
+    ///
+    /// The fortress offers the items given, and asks for the merchants' goods
+    /// picked by the mask. Bit 0 is their first item, bit 1 the second, and so
+    /// on. Returns None if no one is at the depot to trade.
+    pub fn evaluate(
+        &self,
+        offered: &[Item],
+        requested: u64,
+    ) -> Option<OfferEvaluation> {
+        let caravan = self.caravan.as_ref().filter(|caravan| caravan.is_trading())?;
+        let requested = select(&caravan.goods, requested);
+
+        Some(evaluate_offer(offered, &requested, caravan.likes, caravan.dislikes))
+    }
+
+    /// Swaps the offered items for the merchants' goods picked by the mask.
+    ///
+    /// This doesn't check the merchants agree; use `evaluate` for that.
+    /// Returns the goods the fortress receives.
+    pub fn exchange(
+        &mut self,
+        offered: Vec<Item>,
+        requested: u64,
+    ) -> Vec<Item> {
+        let caravan = match self.caravan {
+            Some(ref mut caravan) => caravan,
+            None => return Vec::new(),
+        };
+
+        let mut received = Vec::new();
+        for i in (0..caravan.goods.len().min(64)).rev() {
+            if requested & (1 << i) != 0 {
+                received.push(caravan.goods.remove(i));
+            }
+        }
+
+        received.reverse();
+        caravan.goods.extend(offered);
+        received
+    }
+}
+
+/// Returns copies of the goods picked out by the mask.
+fn select(
+    goods: &[Item],
+    mask: u64,
+) -> Vec<Item> {
+    goods
+        .iter()
+        .take(64)
+        .enumerate()
+        .filter(|&(i, _)| mask & (1 << i) != 0)
+        .map(|(_, &item)| item)
+        .collect()
+}
+
+/// Moves the caravan along.
+/// Returns true if it has left the map, and should be forgotten.
+fn update_caravan(
+    map: &Map<GameTile>,
+    creatures: &mut Creatures,
+    depot: Option<Point<u32>>,
+    caravan: &mut Caravan,
+    events: &mut Vec<GameEvent>,
+) -> bool {
+    let position = match creatures.get(caravan.merchant) {
+        Some(creature) => creature.position,
+        None => return true,
+    };
+
+    match caravan.state {
+        CaravanState::Travelling => {
+            let depot = match depot {
+                Some(depot) => depot,
+                None => {
+                    leave(caravan, events, "there is no trade depot");
+                    return false;
+                },
+            };
+
+            if position == depot {
+                caravan.state = CaravanState::Trading {
+                    remaining: TRADING_TIME,
+                };
+                events.push(GameEvent::CaravanReady);
+                return false;
+            }
+
+            if !walk(map, creatures, caravan.merchant, position, &mut caravan.path, |p| p == depot)
+            {
+                leave(caravan, events, "the trade depot cannot be reached");
+            }
+
+            false
+        },
+        CaravanState::Trading {
+            remaining,
+        } => {
+            if depot != Some(position) {
+                leave(caravan, events, "the trade depot is gone");
+            } else if remaining <= 1 {
+                leave(caravan, events, "they have finished trading");
+            } else {
+                caravan.state = CaravanState::Trading {
+                    remaining: remaining - 1,
+                };
+            }
+
+            false
+        },
+        CaravanState::Leaving => {
+            if is_on_edge(map, position)
+                || !walk(map, creatures, caravan.merchant, position, &mut caravan.path, |p| {
+                    is_on_edge(map, p)
+                })
+            {
+                creatures.remove(caravan.merchant);
+                return true;
+            }
+
+            false
+        },
+    }
+}
+
+/// Turns the caravan around, and announces why.
+fn leave(
+    caravan: &mut Caravan,
+    events: &mut Vec<GameEvent>,
+    reason: &'static str,
+) {
+    caravan.state = CaravanState::Leaving;
+    caravan.path.clear();
+
+    events.push(GameEvent::CaravanLeft {
+        reason: reason,
+    });
+}
+
+/// Moves the merchant one step along the path, finding a new path if there
+/// isn't one, or it's blocked.
+/// Returns false if there is no way to the goal.
+fn walk<F>(
+    map: &Map<GameTile>,
+    creatures: &mut Creatures,
+    merchant: CreatureId,
+    position: Point<u32>,
+    path: &mut Vec<Point<u32>>,
+    is_goal: F,
+) -> bool
+where
+    F: Fn(Point<u32>) -> bool,
+{
+    let is_path_blocked =
+        path.first().map(|next| !map.get(next.x, next.y).tile.is_passable()).unwrap_or(true);
+    if is_path_blocked {
+        match find_path(map, position, is_goal) {
+            Some(new_path) => *path = new_path,
+            None => return false,
+        }
+    }
+
+    if let Some(&next) = path.first() {
+        if creatures.at(next.x, next.y).is_none() {
+            path.remove(0);
+
+            if let Some(creature) = creatures.get_mut(merchant) {
+                creature.position = next;
+            }
+        }
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use world::tiles::Tile;
+
+    fn arrive(map: &Map<GameTile>) -> (Caravans, Creatures) {
+        let mut caravans = Caravans::new();
+        let mut creatures = Creatures::new();
+        let mut rng = Random::new(3);
+
+        let event = caravans.on_season_start(map, &mut creatures, &mut rng, Season::Summer);
+        assert!(event.is_some());
+
+        (caravans, creatures)
+    }
+
+    #[test]
+    fn merchants_only_travel_in_summer_and_autumn() {
+        let map = Map::new(20, 20, GameTile::new(Tile::Ground));
+        let mut caravans = Caravans::new();
+        let mut creatures = Creatures::new();
+        let mut rng = Random::new(3);
+
+        assert!(caravans.on_season_start(&map, &mut creatures, &mut rng, Season::Winter).is_none());
+        assert!(caravans.on_season_start(&map, &mut creatures, &mut rng, Season::Spring).is_none());
+        assert!(caravans.on_season_start(&map, &mut creatures, &mut rng, Season::Autumn).is_some());
+        assert!(!caravans.caravan().unwrap().goods.is_empty());
+    }
+
+    #[test]
+    fn merchants_walk_to_the_depot_and_trade() {
+        let map = Map::new(20, 20, GameTile::new(Tile::Ground));
+        let (mut caravans, mut creatures) = arrive(&map);
+        let depot = Point::new(10, 10);
+
+        let mut events = Vec::new();
+        for _ in 0..50 {
+            events.extend(caravans.update(&map, &mut creatures, Some(depot)));
+        }
+
+        assert!(caravans.caravan().unwrap().is_trading());
+        assert_eq!(events, vec![GameEvent::CaravanReady]);
+        assert!(caravans.evaluate(&[], 1).is_some());
+    }
+
+    #[test]
+    fn merchants_leave_if_the_depot_cannot_be_reached() {
+        let mut map = Map::new(20, 20, GameTile::new(Tile::Ground));
+        let (mut caravans, mut creatures) = arrive(&map);
+
+        // A depot walled in on every side.
+        let depot = Point::new(10, 10);
+        for x in 9..12 {
+            for y in 9..12 {
+                if x != 10 || y != 10 {
+                    map.set(x, y, GameTile::new(Tile::Wall));
+                }
+            }
+        }
+
+        let events = caravans.update(&map, &mut creatures, Some(depot));
+        assert_eq!(
+            events,
+            vec![GameEvent::CaravanLeft {
+                reason: "the trade depot cannot be reached",
+            }]
+        );
+
+        for _ in 0..50 {
+            caravans.update(&map, &mut creatures, Some(depot));
+        }
+
+        assert!(caravans.caravan().is_none());
+        assert!(creatures.is_empty());
+    }
+}
@@ -0,0 +1,15 @@
+//! Trade.
+//!
+//! Merchant caravans which visit the fortress, and the deals made with them.
+//!
+
+mod caravan;
+mod caravans;
+mod offer;
+
+pub use self::caravan::Caravan;
+pub use self::caravan::CaravanState;
+pub use self::caravans::Caravans;
+pub use self::offer::evaluate_offer;
+pub use self::offer::price;
+pub use self::offer::OfferEvaluation;
@@ -0,0 +1,109 @@
+use items::Item;
+use items::ItemKind;
+
+/// How much more merchants charge for their goods, than they are worth.
+/// As a percentage.
+const MARKUP_PERCENT: u32 = 25;
+
+/// How much merchants value the kind of goods they like, as a percentage.
+const LIKED_PERCENT: u32 = 150;
+
+/// How much merchants value the kind of goods they dislike, as a percentage.
+const DISLIKED_PERCENT: u32 = 50;
+
+/// What the merchants make of a deal.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct OfferEvaluation {
+    /// How much the merchants think the fortress' goods are worth.
+    pub offered: u32,
+
+    /// How much the merchants want, for the goods asked for.
+    pub requested: u32,
+
+    /// If the merchants will agree to the deal.
+    pub accepted: bool,
+}
+
+/// The price merchants ask for the item.
+pub fn price(item: &Item) -> u32 {
+    (item.value() * (100 + MARKUP_PERCENT) / 100).max(1)
+}
+
+/// Works out if merchants will give the requested goods, in return for those
+/// offered.
+///
+/// Merchants pay more for the kind of goods they like, and less for the kind
+/// they dislike. They accept any deal where what they get is worth at least
+/// the price of what they give. An empty deal is never accepted.
+pub fn evaluate_offer(
+    offered: &[Item],
+    requested: &[Item],
+    likes: ItemKind,
+    dislikes: ItemKind,
+) -> OfferEvaluation {
+    let offered_value = offered
+        .iter()
+        .map(|item| {
+            let percent = if item.kind == likes {
+                LIKED_PERCENT
+            } else if item.kind == dislikes {
+                DISLIKED_PERCENT
+            } else {
+                100
+            };
+
+            item.value() * percent / 100
+        })
+        .sum::<u32>();
+    let requested_price = requested.iter().map(price).sum::<u32>();
+
+    OfferEvaluation {
+        offered: offered_value,
+        requested: requested_price,
+        accepted: !(offered.is_empty() && requested.is_empty()) && offered_value >= requested_price,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use util::shapes::Point;
+
+    use items::Quality;
+
+    fn item(kind: ItemKind) -> Item {
+        Item {
+            kind: kind,
+            quality: Quality::Ordinary,
+            position: Point::new(0, 0),
+        }
+    }
+
+    #[test]
+    fn merchants_want_a_profit() {
+        let tables = [item(ItemKind::Table)];
+
+        let fair = evaluate_offer(&tables, &tables, ItemKind::Stone, ItemKind::Log);
+        assert!(!fair.accepted);
+        assert!(fair.requested > fair.offered);
+
+        let generous = evaluate_offer(
+            &[item(ItemKind::Table), item(ItemKind::Chair)],
+            &tables,
+            ItemKind::Stone,
+            ItemKind::Log,
+        );
+        assert!(generous.accepted);
+    }
+
+    #[test]
+    fn preferences_change_the_value() {
+        let beds = [item(ItemKind::Bed)];
+        let liked = evaluate_offer(&beds, &[], ItemKind::Bed, ItemKind::Log);
+        let disliked = evaluate_offer(&beds, &[], ItemKind::Log, ItemKind::Bed);
+
+        assert!(liked.offered > disliked.offered);
+        assert!(!evaluate_offer(&[], &[], ItemKind::Bed, ItemKind::Log).accepted);
+    }
+}
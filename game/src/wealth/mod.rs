@@ -0,0 +1,11 @@
+//! Wealth.
+//!
+//! How much everything in the fortress is worth, split up by what sort of
+//! thing it is.
+//!
+
+mod wealth;
+mod wealth_category;
+
+pub use self::wealth::Wealth;
+pub use self::wealth_category::WealthCategory;
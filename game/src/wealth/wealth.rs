@@ -0,0 +1,89 @@
+use wealth::WealthCategory;
+
+/// A running total of how much the fortress is worth, for each category.
+///
+/// This is kept up to date as things are made and destroyed, rather than
+/// being worked out from scratch.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Wealth {
+    /// The value held in each category.
+    /// In the same order as `WealthCategory::ALL`.
+    values: [u32; 4],
+}
+
+impl Wealth {
+    /// Creates a ledger with nothing in it.
+    pub fn new() -> Wealth {
+        Wealth {
+            values: [0; 4],
+        }
+    }
+
+    /// Records something of the value given being created.
+    pub fn add(
+        &mut self,
+        category: WealthCategory,
+        value: u32,
+    ) {
+        let total = &mut self.values[category as usize];
+
+        *total = total.saturating_add(value);
+    }
+
+    /// Records something of the value given being lost, or used up.
+    pub fn remove(
+        &mut self,
+        category: WealthCategory,
+        value: u32,
+    ) {
+        let total = &mut self.values[category as usize];
+
+        *total = total.saturating_sub(value);
+    }
+
+    /// Returns how much the category is worth.
+    pub fn get(
+        &self,
+        category: WealthCategory,
+    ) -> u32 {
+        self.values[category as usize]
+    }
+
+    /// Returns how much everything is worth, together.
+    pub fn total(&self) -> u32 {
+        self.values.iter().fold(0, |total, &value| total.saturating_add(value))
+    }
+
+    /// Iterates over every category, with how much it's worth.
+    pub fn iter(&self) -> impl Iterator<Item = (WealthCategory, u32)> + '_ {
+        WealthCategory::ALL.iter().map(move |&category| (category, self.get(category)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tracks_each_category() {
+        let mut wealth = Wealth::new();
+        wealth.add(WealthCategory::Furniture, 30);
+        wealth.add(WealthCategory::Architecture, 5);
+        wealth.add(WealthCategory::Furniture, 10);
+        wealth.remove(WealthCategory::Furniture, 30);
+
+        assert_eq!(wealth.get(WealthCategory::Furniture), 10);
+        assert_eq!(wealth.get(WealthCategory::Architecture), 5);
+        assert_eq!(wealth.get(WealthCategory::Food), 0);
+        assert_eq!(wealth.total(), 15);
+    }
+
+    #[test]
+    fn never_goes_below_nothing() {
+        let mut wealth = Wealth::new();
+        wealth.add(WealthCategory::Goods, 3);
+        wealth.remove(WealthCategory::Goods, 10);
+
+        assert_eq!(wealth.get(WealthCategory::Goods), 0);
+    }
+}
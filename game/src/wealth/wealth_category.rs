@@ -0,0 +1,35 @@
+/// The kinds of wealth a fortress can have.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WealthCategory {
+    /// Walls, floors, and anything else built into the map.
+    Architecture,
+
+    /// Tables, chairs, beds, and the like.
+    Furniture,
+
+    /// Raw materials, and anything else which can be carried about.
+    Goods,
+
+    /// Anything which can be eaten.
+    Food,
+}
+
+impl WealthCategory {
+    /// Every category, in order.
+    pub const ALL: [WealthCategory; 4] = [
+        WealthCategory::Architecture,
+        WealthCategory::Furniture,
+        WealthCategory::Goods,
+        WealthCategory::Food,
+    ];
+
+    /// A human readable name for the category.
+    pub fn name(self) -> &'static str {
+        match self {
+            WealthCategory::Architecture => "architecture",
+            WealthCategory::Furniture => "furniture",
+            WealthCategory::Goods => "goods",
+            WealthCategory::Food => "food",
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use util::random::Random;
+use util::shapes::Point;
+use world::map::Map;
+use world::tiles::Tile;
+
+use navigation::step;
+use navigation::DIRECTIONS;
+use GameTile;
+
+/// How many of the eight tiles around a position must be walls, for it to
+/// count as being dug into the rock.
+const UNDERGROUND_WALLS: usize = 4;
+
+/// One in this many tiles is low enough to collect a puddle.
+const HOLLOW_CHANCE: u32 = 6;
+
+/// Returns true if the position is dug into the rock.
+/// This is when enough of the tiles around it are walls.
+pub fn is_underground(
+    map: &Map<GameTile>,
+    position: Point<u32>,
+) -> bool {
+    let walls = DIRECTIONS
+        .iter()
+        .filter_map(|&direction| step(map, position, direction))
+        .filter(|next| map.get(next.x, next.y).tile == Tile::Wall)
+        .count();
+
+    walls >= UNDERGROUND_WALLS
+}
+
+/// Returns true if the position is out in the open, where rain and snow
+/// can fall on it.
+pub fn is_exposed(
+    map: &Map<GameTile>,
+    position: Point<u32>,
+) -> bool {
+    match map.get(position.x, position.y).tile {
+        Tile::Empty | Tile::Water | Tile::Wall | Tile::Door => false,
+        _ => !is_underground(map, position),
+    }
+}
+
+/// Returns true if the position is a dip in the ground, where rain collects.
+///
+/// Which tiles are hollows is decided by the seed, so it's the same every
+/// time the game is played with that seed.
+pub fn is_hollow(
+    seed: u64,
+    position: Point<u32>,
+) -> bool {
+    let position = (u64::from(position.x) << 32) | u64::from(position.y);
+    let mut random = Random::new(seed ^ position);
+
+    random.next_u32() % HOLLOW_CHANCE == 0
+}
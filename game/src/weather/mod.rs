@@ -0,0 +1,13 @@
+//! Weather.
+//!
+//! What the weather leaves behind on the map. Snow settles, and puddles fill
+//! up, on the tiles out in the open.
+//!
+
+mod exposure;
+mod weathering;
+
+pub use self::exposure::is_exposed;
+pub use self::exposure::is_hollow;
+pub use self::exposure::is_underground;
+pub use self::weathering::Weathering;
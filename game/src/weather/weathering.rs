@@ -0,0 +1,429 @@
+use util::shapes::Point;
+use world::calendar::WorldCalendar;
+use world::map::Map;
+use world::tiles::Tile;
+use world::weather::Location;
+use world::weather::Precipitation;
+use world::weather::WeatherReport;
+
+use events::GameEvent;
+use navigation::step;
+use navigation::DIRECTIONS;
+use weather::is_exposed;
+use weather::is_hollow;
+use weather::is_underground;
+use GameTile;
+
+/// The deepest snow can get, in layers.
+const MAX_SNOW: u8 = 5;
+
+/// The deepest a puddle can get.
+const MAX_PUDDLE: u8 = 3;
+
+/// How long it takes for a layer of snow to settle, in the heaviest snow.
+/// In seconds.
+const SNOW_LAYER_SECONDS: f32 = 30.0 * 60.0;
+
+/// How long it takes for puddles to deepen, in the heaviest rain.
+/// In seconds.
+const PUDDLE_SECONDS: f32 = 20.0 * 60.0;
+
+/// How long it takes for a layer of snow to melt, at one degree above
+/// freezing. Warmer weather melts it quicker.
+/// In seconds.
+const THAW_SECONDS: f32 = 10.0 * 60.0 * 60.0;
+
+/// How long it takes for puddles to dry up, once the rain has stopped.
+/// In seconds.
+const DRYING_SECONDS: f32 = 2.0 * 60.0 * 60.0;
+
+/// How often water is checked to see if it freezes, or thaws.
+/// In seconds.
+const FREEZE_CHECK_SECONDS: f32 = 60.0 * 60.0;
+
+/// How much higher up the ground on, or next to, a hill is, in metres.
+const HILL_ELEVATION: f32 = 500.0;
+
+/// Keeps track of the weather, and the snow and puddles it leaves on the
+/// map.
+///
+/// Snow and rain build up gradually. Once enough has fallen, every tile in
+/// the open gets another layer of snow, or every hollow gets deeper.
+pub struct Weathering {
+    /// Picks the weather, and which tiles are hollows.
+    seed: u64,
+
+    /// Where the fortress is, out in the open on flat ground.
+    location: Location,
+
+    /// The weather out in the open, as of the last update.
+    report: WeatherReport,
+
+    /// The time of the last update.
+    /// In seconds.
+    time: u64,
+
+    /// How much snow has fallen towards the next layer.
+    /// From 0.0 to 1.0.
+    snowfall: f32,
+
+    /// How much rain has fallen towards deepening the puddles.
+    /// From 0.0 to 1.0.
+    rainfall: f32,
+
+    /// How far the next layer of snow has melted.
+    /// From 0.0 to 1.0.
+    thaw: f32,
+
+    /// How far the puddles have dried up.
+    /// From 0.0 to 1.0.
+    drying: f32,
+
+    /// How long it's been since water was last checked for freezing.
+    /// From 0.0 to 1.0.
+    freeze_check: f32,
+}
+
+impl Weathering {
+    /// Creates the weather at the time given, for a fortress at the
+    /// location given. Nothing has settled yet.
+    pub fn new(
+        calendar: &WorldCalendar,
+        seed: u64,
+        time: u64,
+        location: Location,
+    ) -> Weathering {
+        Weathering {
+            seed: seed,
+            location: location,
+            report: WeatherReport::generate_report(calendar, seed, time, &location),
+            time: time,
+            snowfall: 0.0,
+            rainfall: 0.0,
+            thaw: 0.0,
+            drying: 0.0,
+            freeze_check: 0.0,
+        }
+    }
+
+    /// Returns the weather out in the open, as of the last update.
+    pub fn report(&self) -> &WeatherReport {
+        &self.report
+    }
+
+    /// Returns where the position on the map is in the world.
+    ///
+    /// Each position is in the biome of its tile. Ground on, or next to, a
+    /// hill is higher up. Positions dug into the rock are underground.
+    pub fn location_at(
+        &self,
+        map: &Map<GameTile>,
+        position: Point<u32>,
+    ) -> Location {
+        let on_hill = map.get(position.x, position.y).tile == Tile::Hill
+            || DIRECTIONS
+                .iter()
+                .filter_map(|&direction| step(map, position, direction))
+                .any(|next| map.get(next.x, next.y).tile == Tile::Hill);
+
+        Location {
+            biome: map.get(position.x, position.y).biome,
+            elevation: if on_hill {
+                self.location.elevation + HILL_ELEVATION
+            } else {
+                self.location.elevation
+            },
+            underground: is_underground(map, position),
+            ..self.location
+        }
+    }
+
+    /// Moves the weather on to the time given, and settles whatever has
+    /// fallen over the seconds since the last update.
+    ///
+    /// Returns anything worth telling the player about.
+    pub fn update(
+        &mut self,
+        map: &mut Map<GameTile>,
+        calendar: &WorldCalendar,
+        time: u64,
+    ) -> Vec<GameEvent> {
+        let mut events = Vec::new();
+        let report = WeatherReport::generate_report(calendar, self.seed, time, &self.location);
+
+        if report.weather.precipitation != self.report.weather.precipitation {
+            events.push(GameEvent::WeatherChanged {
+                precipitation: report.weather.precipitation,
+            });
+        }
+
+        let seconds = time.saturating_sub(self.time) as f32;
+        self.report = report;
+        self.time = time;
+
+        let weather = report.weather;
+        let celcius = report.temperature.as_celcius();
+
+        match weather.precipitation {
+            Precipitation::Snow => {
+                self.snowfall += weather.intensity * seconds / SNOW_LAYER_SECONDS
+            },
+            Precipitation::Rain => self.rainfall += weather.intensity * seconds / PUDDLE_SECONDS,
+            Precipitation::Clear if celcius > 0.0 => self.drying += seconds / DRYING_SECONDS,
+            Precipitation::Clear => {},
+        }
+
+        if celcius > 0.0 {
+            self.thaw += celcius * seconds / THAW_SECONDS;
+        }
+
+        self.freeze_check += seconds / FREEZE_CHECK_SECONDS;
+        if self.freeze_check >= 1.0 {
+            self.freeze_check = 0.0;
+            self.freeze_and_thaw(map, calendar, time);
+        }
+
+        while self.snowfall >= 1.0 {
+            self.snowfall -= 1.0;
+            settle_snow(map);
+        }
+
+        while self.rainfall >= 1.0 {
+            self.rainfall -= 1.0;
+            self.fill_puddles(map);
+        }
+
+        while self.thaw >= 1.0 {
+            self.thaw -= 1.0;
+            self.melt_snow(map);
+        }
+
+        while self.drying >= 1.0 {
+            self.drying -= 1.0;
+            dry_puddles(map);
+        }
+
+        events
+    }
+
+    /// Deepens the puddles in every hollow out in the open.
+    fn fill_puddles(
+        &self,
+        map: &mut Map<GameTile>,
+    ) {
+        let seed = self.seed;
+
+        for_each_exposed(map, |map, position| {
+            let mut tile = map.get(position.x, position.y);
+            if tile.tile.is_passable() && is_hollow(seed, position) {
+                tile.puddle = (tile.puddle + 1).min(MAX_PUDDLE);
+                map.set(position.x, position.y, tile);
+            }
+        });
+    }
+
+    /// Freezes water where it's below freezing, and thaws ice where it's
+    /// above. Each tile goes by the temperature where it is, so water up on
+    /// the hills can freeze whilst water lower down stays liquid.
+    fn freeze_and_thaw(
+        &self,
+        map: &mut Map<GameTile>,
+        calendar: &WorldCalendar,
+        time: u64,
+    ) {
+        let mut temperatures: Vec<(Location, f32)> = Vec::new();
+
+        for y in 0..map.height {
+            for x in 0..map.width {
+                let mut tile = map.get(x, y);
+                if tile.tile != Tile::Water && tile.tile != Tile::Ice {
+                    continue;
+                }
+
+                let location = self.location_at(map, Point::new(x, y));
+                let celcius = match temperatures.iter().find(|&&(known, _)| known == location) {
+                    Some(&(_, celcius)) => celcius,
+                    None => {
+                        let report =
+                            WeatherReport::generate_report(calendar, self.seed, time, &location);
+                        let celcius = report.temperature.as_celcius();
+                        temperatures.push((location, celcius));
+                        celcius
+                    },
+                };
+
+                tile.tile = match tile.tile {
+                    Tile::Water if celcius < 0.0 => Tile::Ice,
+                    Tile::Ice if celcius > 0.0 => Tile::Water,
+                    other => other,
+                };
+
+                map.set(x, y, tile);
+            }
+        }
+    }
+
+    /// Melts a layer of snow from every tile. Melting snow runs into the
+    /// hollows.
+    fn melt_snow(
+        &self,
+        map: &mut Map<GameTile>,
+    ) {
+        for y in 0..map.height {
+            for x in 0..map.width {
+                let mut tile = map.get(x, y);
+                if tile.snow == 0 {
+                    continue;
+                }
+
+                tile.snow -= 1;
+                if tile.tile.is_passable() && is_hollow(self.seed, Point::new(x, y)) {
+                    tile.puddle = (tile.puddle + 1).min(MAX_PUDDLE);
+                }
+
+                map.set(x, y, tile);
+            }
+        }
+    }
+}
+
+/// Adds a layer of snow to every tile out in the open.
+fn settle_snow(map: &mut Map<GameTile>) {
+    for_each_exposed(map, |map, position| {
+        let mut tile = map.get(position.x, position.y);
+        tile.snow = (tile.snow + 1).min(MAX_SNOW);
+        map.set(position.x, position.y, tile);
+    });
+}
+
+/// Dries up a little of every puddle.
+fn dry_puddles(map: &mut Map<GameTile>) {
+    map.fill(|&tile, _, _| {
+        GameTile {
+            puddle: tile.puddle.saturating_sub(1),
+            ..tile
+        }
+    });
+}
+
+/// Calls the function for every position out in the open.
+fn for_each_exposed<F>(
+    map: &mut Map<GameTile>,
+    mut on_exposed: F,
+) where
+    F: FnMut(&mut Map<GameTile>, Point<u32>),
+{
+    for y in 0..map.height {
+        for x in 0..map.width {
+            let position = Point::new(x, y);
+
+            if is_exposed(map, position) {
+                on_exposed(map, position);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use world::biomes::Biome;
+    use world::calendar::Calendar;
+
+    fn grassland() -> Location {
+        Location {
+            biome: Biome::Grassland,
+            latitude: 30.0,
+            elevation: 0.0,
+            underground: false,
+        }
+    }
+
+    /// Finds a time when it's raining, or snowing, heavily.
+    fn find_time(
+        calendar: &Calendar,
+        precipitation: Precipitation,
+    ) -> u64 {
+        (0..10_000)
+            .map(|hour| hour * 60 * 60)
+            .find(|&time| {
+                let weather =
+                    WeatherReport::generate_report(calendar, 1, time, &grassland()).weather;
+                weather.precipitation == precipitation && weather.intensity > 0.5
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn snow_settles_in_the_open() {
+        let calendar = Calendar::standard();
+        let mut map = Map::new(10, 10, GameTile::new(Tile::Grass));
+        map.set(5, 5, GameTile::new(Tile::Wall));
+
+        let time = find_time(&calendar, Precipitation::Snow);
+        let mut weathering = Weathering::new(&calendar, 1, time, grassland());
+        for step in 1..=10 {
+            weathering.update(&mut map, &calendar, time + step * 10 * 60);
+        }
+
+        assert!(map.get(1, 1).snow > 0);
+        assert_eq!(map.get(5, 5).snow, 0);
+    }
+
+    #[test]
+    fn rain_fills_puddles_in_hollows() {
+        let calendar = Calendar::standard();
+        let mut map = Map::new(20, 20, GameTile::new(Tile::Grass));
+
+        let time = find_time(&calendar, Precipitation::Rain);
+        let mut weathering = Weathering::new(&calendar, 1, time, grassland());
+        for step in 1..=10 {
+            weathering.update(&mut map, &calendar, time + step * 10 * 60);
+        }
+
+        for y in 0..20 {
+            for x in 0..20 {
+                let hollow = is_hollow(1, Point::new(x, y));
+                assert_eq!(map.get(x, y).puddle > 0, hollow);
+            }
+        }
+    }
+
+    #[test]
+    fn water_freezes_in_the_open_but_not_underground() {
+        let calendar = Calendar::standard();
+        let mut map = Map::new(10, 10, GameTile::new(Tile::Grass));
+        map.set(1, 1, GameTile::new(Tile::Water));
+        map.set(6, 6, GameTile::new(Tile::Water));
+        for x in 5..8 {
+            map.set(x, 5, GameTile::new(Tile::Wall));
+            map.set(x, 7, GameTile::new(Tile::Wall));
+        }
+
+        let winter_midnight = 9 * 28 * 24 * 60 * 60;
+        let mut weathering = Weathering::new(&calendar, 1, winter_midnight - 60 * 60, grassland());
+        weathering.update(&mut map, &calendar, winter_midnight);
+
+        assert_eq!(map.get(1, 1).tile, Tile::Ice);
+        assert_eq!(map.get(6, 6).tile, Tile::Water);
+    }
+
+    #[test]
+    fn each_tile_is_in_its_own_biome() {
+        let calendar = Calendar::standard();
+        let mut map = Map::new(10, 10, GameTile::new(Tile::Grass));
+        map.set(
+            3,
+            3,
+            GameTile {
+                biome: Biome::Tundra,
+                ..GameTile::new(Tile::Grass)
+            },
+        );
+
+        let weathering = Weathering::new(&calendar, 1, 0, grassland());
+
+        assert_eq!(weathering.location_at(&map, Point::new(3, 3)).biome, Biome::Tundra);
+        assert_eq!(weathering.location_at(&map, Point::new(4, 4)).biome, Biome::Grassland);
+    }
+}
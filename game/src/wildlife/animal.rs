@@ -0,0 +1,11 @@
+use wildlife::Behaviour;
+
+/// The wildlife state for a single creature.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Animal {
+    /// Which species it is, as an index into the list of species.
+    pub species: usize,
+
+    /// What it is doing right now.
+    pub behaviour: Behaviour,
+}
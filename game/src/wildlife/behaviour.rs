@@ -0,0 +1,15 @@
+/// What an animal is currently doing.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Behaviour {
+    /// Ambling about at random.
+    Wander,
+
+    /// Eating the grass it is stood on.
+    Graze,
+
+    /// Running away from something dangerous.
+    Flee,
+
+    /// Heading for the edge of the map, to leave it.
+    Leave,
+}
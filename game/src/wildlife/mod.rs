@@ -0,0 +1,17 @@
+//! Wildlife.
+//!
+//! Animals which wander in from the edges of the map, graze, run from
+//! danger, and wander off again. What animals there are is described in
+//! `data/species.txt`.
+//!
+
+mod animal;
+mod behaviour;
+mod species;
+mod species_parser;
+mod wildlife;
+
+pub use self::behaviour::Behaviour;
+pub use self::species::Species;
+pub use self::species_parser::parse_species;
+pub use self::wildlife::Wildlife;
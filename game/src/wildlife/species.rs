@@ -0,0 +1,56 @@
+use world::biomes::Biome;
+use world::calendar::Season;
+
+use combat::BodyPlan;
+
+/// A kind of animal.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Species {
+    /// What the animal is called, like 'deer'.
+    pub name: String,
+
+    /// The character it is drawn with.
+    pub symbol: char,
+
+    /// The shape of it's body.
+    pub body_plan: BodyPlan,
+
+    /// How big it is. A dwarf is 10.
+    pub size: u8,
+
+    /// The biomes it can be found in.
+    pub biomes: Vec<Biome>,
+
+    /// The seasons it can be found in.
+    /// Outside of these it will leave the map.
+    pub seasons: Vec<Season>,
+
+    /// If it eats thick grass.
+    pub grazes: bool,
+
+    /// How close a threat can get before it runs away, in tiles.
+    pub flee_distance: u32,
+
+    /// The percentage chance it will move, each tick.
+    pub move_chance: u32,
+}
+
+impl Species {
+    /// Returns true if this species can be found in the biome, during the
+    /// season, given.
+    pub fn lives_in(
+        &self,
+        biome: Biome,
+        season: Season,
+    ) -> bool {
+        self.biomes.contains(&biome) && self.is_active_in(season)
+    }
+
+    /// Returns true if this species is around during the season given.
+    pub fn is_active_in(
+        &self,
+        season: Season,
+    ) -> bool {
+        self.seasons.contains(&season)
+    }
+}
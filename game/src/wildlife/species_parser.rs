@@ -0,0 +1,173 @@
+use world::biomes::Biome;
+use world::calendar::Season;
+
+use combat::BodyPlan;
+use data_file::parse_char;
+use data_file::parse_entries;
+use data_file::parse_list;
+use data_file::parse_number;
+use data_file::parse_yes_no;
+use data_file::DataFileError;
+use data_file::PartialEntry;
+use wildlife::Species;
+
+/// Reads a list of species from the text given.
+///
+/// Each species starts with a line of `species <name>`, followed by one line
+/// for each of its properties, as `<property> <value>`. Blank lines, and
+/// lines starting with '#', are skipped. See `data/species.txt` for an
+/// example.
+pub fn parse_species(text: &str) -> Result<Vec<Species>, DataFileError> {
+    parse_entries::<PartialSpecies>(text)
+}
+
+/// A species which is part way through being read.
+struct PartialSpecies {
+    name: String,
+    symbol: Option<char>,
+    body_plan: Option<BodyPlan>,
+    size: Option<u8>,
+    biomes: Option<Vec<Biome>>,
+    seasons: Option<Vec<Season>>,
+    grazes: Option<bool>,
+    flee_distance: Option<u32>,
+    move_chance: Option<u32>,
+}
+
+impl PartialEntry for PartialSpecies {
+    type Entry = Species;
+
+    const KIND: &'static str = "species";
+
+    fn new(name: &str) -> PartialSpecies {
+        PartialSpecies {
+            name: name.to_string(),
+            symbol: None,
+            body_plan: None,
+            size: None,
+            biomes: None,
+            seasons: None,
+            grazes: None,
+            flee_distance: None,
+            move_chance: None,
+        }
+    }
+
+    fn set(
+        &mut self,
+        line: usize,
+        property: &str,
+        value: &str,
+    ) -> Result<(), DataFileError> {
+        let invalid = || {
+            DataFileError::InvalidValue {
+                line: line,
+                property: property.to_string(),
+            }
+        };
+
+        match property {
+            "symbol" => self.symbol = Some(parse_char(value).ok_or_else(invalid)?),
+            "body" => self.body_plan = Some(BodyPlan::from_name(value).ok_or_else(invalid)?),
+            "size" => self.size = Some(parse_number(value).ok_or_else(invalid)?),
+            "biomes" => {
+                self.biomes = Some(parse_list(value, Biome::from_name).ok_or_else(invalid)?)
+            },
+            "seasons" => {
+                self.seasons = Some(parse_list(value, Season::from_name).ok_or_else(invalid)?)
+            },
+            "grazes" => self.grazes = Some(parse_yes_no(value).ok_or_else(invalid)?),
+            "flee_distance" => self.flee_distance = Some(parse_number(value).ok_or_else(invalid)?),
+            "move_chance" => self.move_chance = Some(parse_number(value).ok_or_else(invalid)?),
+            _ => {
+                return Err(DataFileError::UnknownProperty {
+                    line: line,
+                    property: property.to_string(),
+                })
+            },
+        }
+
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Species, DataFileError> {
+        let name = self.name;
+        let missing = |property| {
+            DataFileError::MissingProperty {
+                kind: Self::KIND,
+                name: name.clone(),
+                property: property,
+            }
+        };
+
+        Ok(Species {
+            symbol: self.symbol.ok_or_else(|| missing("symbol"))?,
+            body_plan: self.body_plan.ok_or_else(|| missing("body"))?,
+            size: self.size.ok_or_else(|| missing("size"))?,
+            biomes: self.biomes.ok_or_else(|| missing("biomes"))?,
+            seasons: self.seasons.ok_or_else(|| missing("seasons"))?,
+            grazes: self.grazes.unwrap_or(false),
+            flee_distance: self.flee_distance.ok_or_else(|| missing("flee_distance"))?,
+            move_chance: self.move_chance.ok_or_else(|| missing("move_chance"))?,
+            name: name,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_species() {
+        let text = "
+            # A comment.
+            species mountain goat
+            symbol g
+            body quadruped
+            size 8
+            biomes mountains tundra
+            seasons winter
+            grazes yes
+            flee_distance 6
+            move_chance 25
+        ";
+
+        let species = parse_species(text).unwrap();
+
+        assert_eq!(species.len(), 1);
+        assert_eq!(species[0].name, "mountain goat");
+        assert_eq!(species[0].symbol, 'g');
+        assert_eq!(species[0].body_plan, BodyPlan::Quadruped);
+        assert_eq!(species[0].biomes, vec![Biome::Mountains, Biome::Tundra]);
+        assert_eq!(species[0].seasons, vec![Season::Winter]);
+        assert!(species[0].grazes);
+    }
+
+    #[test]
+    fn reports_bad_values() {
+        let text = "species deer\nbiomes grassland ocean\n";
+
+        assert_eq!(
+            parse_species(text),
+            Err(DataFileError::InvalidValue {
+                line: 2,
+                property: "biomes".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn reports_missing_properties() {
+        let text = "species deer\nsymbol d\n";
+
+        assert_eq!(
+            parse_species(text),
+            Err(DataFileError::MissingProperty {
+                kind: "species",
+                name: "deer".to_string(),
+                property: "body",
+            })
+        );
+    }
+}
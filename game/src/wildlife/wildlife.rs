@@ -0,0 +1,485 @@
+use std::collections::BTreeMap;
+
+use util::random::Random;
+use util::shapes::Point;
+use world::calendar::Season;
+use world::map::Map;
+use world::tiles::Tile;
+
+use combat::Body;
+use creatures::CreatureId;
+use creatures::Creatures;
+use creatures::Faction;
+use navigation::direction_to_nearest_edge;
+use navigation::distance_squared;
+use navigation::free_neighbours;
+use navigation::is_free;
+use navigation::is_on_edge;
+use navigation::step;
+use wildlife::animal::Animal;
+use wildlife::parse_species;
+use wildlife::Behaviour;
+use wildlife::Species;
+use GameTile;
+
+/// The species which come with the game.
+const SPECIES_DATA: &str = include_str!("../../data/species.txt");
+
+/// The most animals on the map at once.
+const MAX_ANIMALS: usize = 8;
+
+/// How many ticks between chances for new animals to arrive.
+const SPAWN_INTERVAL: u64 = 20;
+
+/// The percentage chance a new animal arrives, each time one can.
+const SPAWN_CHANCE: u32 = 20;
+
+/// How many edge tiles are tried when looking for somewhere to spawn.
+const SPAWN_ATTEMPTS: u32 = 10;
+
+/// The percentage chance a grazing animal eats the grass it is stood on.
+const GRAZE_CHANCE: u32 = 40;
+
+/// The chance, in a thousand, a wandering animal decides to leave.
+const LEAVE_CHANCE: u32 = 5;
+
+/// Looks after all of the wild animals on the map.
+///
+/// The animals themselves are normal creatures. This holds what species they
+/// are, and what they are up to.
+pub struct Wildlife {
+    /// Every species which could turn up.
+    species: Vec<Species>,
+
+    /// The creatures which are wild animals.
+    animals: BTreeMap<CreatureId, Animal>,
+
+    /// The tick of the last chance for new animals to arrive.
+    last_spawn_tick: u64,
+}
+
+impl Wildlife {
+    /// Creates wildlife using the species which come with the game.
+    pub fn new() -> Wildlife {
+        let species = parse_species(SPECIES_DATA).expect("built in species data is invalid");
+
+        Wildlife::with_species(species)
+    }
+
+    /// Creates wildlife using the species given.
+    pub fn with_species(species: Vec<Species>) -> Wildlife {
+        Wildlife {
+            species: species,
+            animals: BTreeMap::new(),
+            last_spawn_tick: 0,
+        }
+    }
+
+    /// Returns every species which could turn up.
+    pub fn species(&self) -> &[Species] {
+        &self.species
+    }
+
+    /// Returns how many chances new animals have had to arrive, since this
+    /// was last called, as of the tick given.
+    ///
+    /// There is one chance every `SPAWN_INTERVAL` ticks, however often the
+    /// wildlife is updated.
+    pub fn spawn_chances(
+        &mut self,
+        tick: u64,
+    ) -> u64 {
+        let chances = tick.saturating_sub(self.last_spawn_tick) / SPAWN_INTERVAL;
+        self.last_spawn_tick += chances * SPAWN_INTERVAL;
+
+        chances
+    }
+
+    /// Returns what the animal is currently doing.
+    /// Returns None if the creature is not a wild animal.
+    pub fn behaviour(
+        &self,
+        id: CreatureId,
+    ) -> Option<Behaviour> {
+        self.animals.get(&id).map(|animal| animal.behaviour)
+    }
+
+    /// Maybe brings a new animal onto the map, at one of it's edges.
+    ///
+    /// Only species which live in the biome of the tile it arrives on, and
+    /// are around in the season, are picked.
+    pub fn try_spawn(
+        &mut self,
+        map: &Map<GameTile>,
+        creatures: &mut Creatures,
+        rng: &mut Random,
+        season: Season,
+    ) -> Option<CreatureId> {
+        if self.animals.len() >= MAX_ANIMALS || rng.range(0, 100) >= SPAWN_CHANCE {
+            return None;
+        }
+
+        let position = find_spawn_position(map, creatures, rng)?;
+        let biome = map.get(position.x, position.y).biome;
+        let candidates = (0..self.species.len())
+            .filter(|&i| self.species[i].lives_in(biome, season))
+            .collect::<Vec<usize>>();
+        let species_index = *rng.pick(&candidates)?;
+
+        let species = &self.species[species_index];
+        let id = creatures.spawn(
+            species.name.clone(),
+            species.symbol,
+            Faction::Wild,
+            position,
+            Body::new(species.body_plan, species.size),
+        );
+
+        self.animals.insert(
+            id,
+            Animal {
+                species: species_index,
+                behaviour: Behaviour::Wander,
+            },
+        );
+
+        Some(id)
+    }
+
+    /// Moves every animal along.
+    ///
+    /// Animals run from any threats which get too close, and head off the map
+    /// when they are out of season. Otherwise they wander about, eating any
+    /// thick grass they come across.
+    pub fn update(
+        &mut self,
+        map: &mut Map<GameTile>,
+        creatures: &mut Creatures,
+        rng: &mut Random,
+        threats: &[Point<u32>],
+        season: Season,
+    ) {
+        let ids = self.animals.keys().cloned().collect::<Vec<CreatureId>>();
+
+        for id in ids {
+            let position = match creatures.get(id) {
+                Some(creature) if creature.body.can_walk() => creature.position,
+                Some(_) => continue,
+                None => {
+                    self.animals.remove(&id);
+                    continue;
+                },
+            };
+
+            let mut animal = self.animals[&id];
+            let species = &self.species[animal.species];
+
+            let threat = nearest_threat(position, threats, species.flee_distance);
+            animal.behaviour = match (threat, animal.behaviour) {
+                (Some(_), _) => Behaviour::Flee,
+                (None, Behaviour::Leave) => Behaviour::Leave,
+                (None, _) if !species.is_active_in(season) => Behaviour::Leave,
+                (None, Behaviour::Flee) => Behaviour::Wander,
+                (None, behaviour) => behaviour,
+            };
+
+            if rng.range(0, 100) >= species.move_chance {
+                self.animals.insert(id, animal);
+                continue;
+            }
+
+            let next = match animal.behaviour {
+                Behaviour::Flee | Behaviour::Leave if is_on_edge(map, position) => None,
+                Behaviour::Flee => {
+                    flee_step(map, creatures, threats, position, threat.unwrap_or(position))
+                },
+                Behaviour::Leave => leave_step(map, creatures, threats, rng, position),
+                Behaviour::Wander | Behaviour::Graze => {
+                    let game_tile = map.get(position.x, position.y);
+
+                    if species.grazes
+                        && game_tile.tile == Tile::GrassThick
+                        && rng.range(0, 100) < GRAZE_CHANCE
+                    {
+                        let mut grazed = game_tile;
+                        grazed.tile = Tile::Grass;
+                        map.set(position.x, position.y, grazed);
+
+                        animal.behaviour = Behaviour::Graze;
+                        Some(position)
+                    } else {
+                        if rng.range(0, 1000) < LEAVE_CHANCE {
+                            animal.behaviour = Behaviour::Leave;
+                        } else {
+                            animal.behaviour = Behaviour::Wander;
+                        }
+
+                        Some(
+                            random_step(map, creatures, threats, rng, position).unwrap_or(position),
+                        )
+                    }
+                },
+            };
+
+            match next {
+                Some(next) => {
+                    if let Some(creature) = creatures.get_mut(id) {
+                        creature.position = next;
+                    }
+
+                    self.animals.insert(id, animal);
+                },
+                None => {
+                    creatures.remove(id);
+                    self.animals.remove(&id);
+                },
+            }
+        }
+    }
+}
+
+/// Finds a free tile along the edge of the map.
+fn find_spawn_position(
+    map: &Map<GameTile>,
+    creatures: &Creatures,
+    rng: &mut Random,
+) -> Option<Point<u32>> {
+    if map.width == 0 || map.height == 0 {
+        return None;
+    }
+
+    for _ in 0..SPAWN_ATTEMPTS {
+        let position = match rng.range(0, 4) {
+            0 => Point::new(rng.range(0, map.width), 0),
+            1 => Point::new(rng.range(0, map.width), map.height - 1),
+            2 => Point::new(0, rng.range(0, map.height)),
+            _ => Point::new(map.width - 1, rng.range(0, map.height)),
+        };
+
+        if is_free(map, creatures, &[], position) {
+            return Some(position);
+        }
+    }
+
+    None
+}
+
+/// Returns the closest threat, if it is within the distance given.
+fn nearest_threat(
+    position: Point<u32>,
+    threats: &[Point<u32>],
+    distance: u32,
+) -> Option<Point<u32>> {
+    threats
+        .iter()
+        .cloned()
+        .filter(|&threat| distance_squared(position, threat) <= u64::from(distance * distance))
+        .min_by_key(|&threat| distance_squared(position, threat))
+}
+
+/// Steps to whichever free tile is furthest away from the threat.
+/// If there is nowhere better to go, the animal stays where it is.
+fn flee_step(
+    map: &Map<GameTile>,
+    creatures: &Creatures,
+    threats: &[Point<u32>],
+    position: Point<u32>,
+    threat: Point<u32>,
+) -> Option<Point<u32>> {
+    let best = free_neighbours(map, creatures, threats, position)
+        .into_iter()
+        .max_by_key(|&next| distance_squared(next, threat))
+        .filter(|&next| distance_squared(next, threat) > distance_squared(position, threat));
+
+    Some(best.unwrap_or(position))
+}
+
+/// Steps towards the nearest edge of the map.
+/// If the way is blocked then it steps somewhere else at random.
+fn leave_step(
+    map: &Map<GameTile>,
+    creatures: &Creatures,
+    threats: &[Point<u32>],
+    rng: &mut Random,
+    position: Point<u32>,
+) -> Option<Point<u32>> {
+    let direction = direction_to_nearest_edge(map, position);
+
+    match step(map, position, direction) {
+        Some(next) if is_free(map, creatures, threats, next) => Some(next),
+        _ => Some(random_step(map, creatures, threats, rng, position).unwrap_or(position)),
+    }
+}
+
+/// Steps to a random free tile next to the position given.
+fn random_step(
+    map: &Map<GameTile>,
+    creatures: &Creatures,
+    threats: &[Point<u32>],
+    rng: &mut Random,
+    position: Point<u32>,
+) -> Option<Point<u32>> {
+    let neighbours = free_neighbours(map, creatures, threats, position);
+
+    rng.pick(&neighbours).cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use combat::BodyPlan;
+    use navigation::find_edge_positions;
+    use world::biomes::Biome;
+
+    fn grazer() -> Species {
+        Species {
+            name: "deer".to_string(),
+            symbol: 'd',
+            body_plan: BodyPlan::Quadruped,
+            size: 10,
+            biomes: vec![Biome::Grassland],
+            seasons: vec![Season::Summer],
+            grazes: true,
+            flee_distance: 4,
+            move_chance: 100,
+        }
+    }
+
+    fn spawn_at(
+        wildlife: &mut Wildlife,
+        creatures: &mut Creatures,
+        position: Point<u32>,
+    ) -> CreatureId {
+        let id = creatures.spawn(
+            "deer".to_string(),
+            'd',
+            Faction::Wild,
+            position,
+            Body::new(BodyPlan::Quadruped, 10),
+        );
+
+        wildlife.animals.insert(
+            id,
+            Animal {
+                species: 0,
+                behaviour: Behaviour::Wander,
+            },
+        );
+
+        id
+    }
+
+    #[test]
+    fn built_in_species_are_valid() {
+        let wildlife = Wildlife::new();
+
+        assert!(!wildlife.species().is_empty());
+    }
+
+    #[test]
+    fn grazing_thins_thick_grass() {
+        let mut map = Map::new(9, 9, GameTile::new(Tile::GrassThick));
+        let mut creatures = Creatures::new();
+        let mut wildlife = Wildlife::with_species(vec![grazer()]);
+        let mut rng = Random::new(1);
+        spawn_at(&mut wildlife, &mut creatures, Point::new(4, 4));
+
+        for _ in 0..20 {
+            wildlife.update(&mut map, &mut creatures, &mut rng, &[], Season::Summer);
+        }
+
+        let grazed =
+            map.slice_all().filter(|&(game_tile, _)| game_tile.tile == Tile::Grass).count();
+        assert!(grazed > 0);
+    }
+
+    #[test]
+    fn animals_flee_from_threats() {
+        let mut map = Map::new(20, 20, GameTile::new(Tile::Ground));
+        let mut creatures = Creatures::new();
+        let mut wildlife = Wildlife::with_species(vec![grazer()]);
+        let mut rng = Random::new(1);
+        let id = spawn_at(&mut wildlife, &mut creatures, Point::new(10, 10));
+        let threat = Point::new(8, 10);
+
+        wildlife.update(&mut map, &mut creatures, &mut rng, &[threat], Season::Summer);
+
+        assert_eq!(wildlife.behaviour(id), Some(Behaviour::Flee));
+        assert!(distance_squared(creatures.get(id).unwrap().position, threat) > 4);
+    }
+
+    #[test]
+    fn out_of_season_animals_leave_the_map() {
+        let mut map = Map::new(9, 9, GameTile::new(Tile::Ground));
+        let mut creatures = Creatures::new();
+        let mut wildlife = Wildlife::with_species(vec![grazer()]);
+        let mut rng = Random::new(1);
+        let id = spawn_at(&mut wildlife, &mut creatures, Point::new(4, 4));
+
+        for _ in 0..10 {
+            wildlife.update(&mut map, &mut creatures, &mut rng, &[], Season::Winter);
+        }
+
+        assert!(creatures.get(id).is_none());
+        assert!(wildlife.behaviour(id).is_none());
+    }
+
+    #[test]
+    fn animals_arrive_from_the_biome_they_spawn_in() {
+        let tundra = GameTile {
+            biome: Biome::Tundra,
+            ..GameTile::new(Tile::Ground)
+        };
+        let map = Map::new(9, 9, tundra);
+        let mut creatures = Creatures::new();
+        let mut wildlife = Wildlife::with_species(vec![
+            grazer(),
+            Species {
+                name: "caribou".to_string(),
+                biomes: vec![Biome::Tundra],
+                ..grazer()
+            },
+        ]);
+        let mut rng = Random::new(1);
+
+        let spawned = (0..100)
+            .filter_map(|_| wildlife.try_spawn(&map, &mut creatures, &mut rng, Season::Summer))
+            .collect::<Vec<CreatureId>>();
+
+        assert!(!spawned.is_empty());
+        assert!(spawned.iter().all(|&id| creatures.get(id).unwrap().name == "caribou"));
+    }
+
+    #[test]
+    fn empty_maps_have_nowhere_to_spawn() {
+        let map = Map::new(0, 0, GameTile::new(Tile::Ground));
+        let mut rng = Random::new(1);
+
+        assert_eq!(find_spawn_position(&map, &Creatures::new(), &mut rng), None);
+    }
+
+    #[test]
+    fn empty_maps_have_no_edges_to_find() {
+        let map = Map::new(0, 0, GameTile::new(Tile::Ground));
+        let mut rng = Random::new(1);
+        let origin = Point::new(0, 0);
+
+        assert!(find_edge_positions(&map, &Creatures::new(), &mut rng, 3).is_empty());
+        assert!(is_on_edge(&map, origin));
+        assert_eq!(direction_to_nearest_edge(&map, origin), (-1, 0));
+    }
+
+    #[test]
+    fn spawn_chances_keep_pace_with_the_ticks() {
+        let mut wildlife = Wildlife::with_species(vec![grazer()]);
+
+        let chances = (1..=200)
+            .filter(|tick| tick % 7 == 0)
+            .map(|tick| wildlife.spawn_chances(tick))
+            .sum::<u64>();
+
+        assert_eq!(chances, 196 / SPAWN_INTERVAL);
+        assert_eq!(wildlife.spawn_chances(1_000), (1_000 - 180) / SPAWN_INTERVAL);
+    }
+}
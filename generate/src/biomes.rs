@@ -0,0 +1,195 @@
+use std::collections::VecDeque;
+
+use world::biomes::Biome;
+use world::map::Map;
+use world::tiles::Tile;
+
+use neighbours::neighbours;
+use neighbours::SIDES;
+use noise::FractalNoise;
+
+/// How much the temperature wanders above, and below, the average across
+/// the map.
+const TEMPERATURE_SPREAD: f32 = 0.5;
+
+/// How much colder it gets from the water level up to the highest point.
+const HIGHLAND_COOLING: f32 = 0.4;
+
+/// How much the moisture wanders above, and below, the average across the
+/// map.
+const MOISTURE_SPREAD: f32 = 0.6;
+
+/// How much wetter the land right next to water is.
+/// This fades away over `WATER_REACH` tiles.
+const WATER_MOISTURE: f32 = 0.3;
+
+/// How many tiles away water still makes the land wetter.
+const WATER_REACH: u32 = 6;
+
+/// Temperatures below this are tundra.
+const TUNDRA_TEMPERATURE: f32 = 0.25;
+
+/// Temperatures above this, with moisture below `DESERT_MOISTURE`, are
+/// desert.
+const DESERT_TEMPERATURE: f32 = 0.65;
+const DESERT_MOISTURE: f32 = 0.35;
+
+/// Moisture above this, on low ground, is swamp.
+const SWAMP_MOISTURE: f32 = 0.75;
+
+/// How far above the water level swamps can be.
+const SWAMP_HEIGHT: f32 = 0.2;
+
+/// Moisture above this is forest.
+const FOREST_MOISTURE: f32 = 0.55;
+
+/// Builds how warm each tile is, from 0.0 (freezing) to 1.0 (scorching).
+///
+/// This wanders around the average given, and it's colder up high.
+pub fn temperature_map(
+    heightmap: &Map<f32>,
+    noise: &FractalNoise,
+    average: f32,
+    water_level: f32,
+) -> Map<f32> {
+    let mut temperatures = Map::new(heightmap.width, heightmap.height, 0.0);
+
+    temperatures.fill(|_, x, y| {
+        let wander = (noise.get(x as f32, y as f32) - 0.5) * TEMPERATURE_SPREAD * 2.0;
+        let height = (heightmap.get(x, y) - water_level).max(0.0) / (1.0 - water_level).max(0.01);
+
+        (average + wander - height * HIGHLAND_COOLING).clamp(0.0, 1.0)
+    });
+
+    temperatures
+}
+
+/// Builds how wet each tile is, from 0.0 (parched) to 1.0 (sodden).
+///
+/// This wanders around the average given, and land near water is wetter.
+pub fn moisture_map(
+    tiles: &Map<Tile>,
+    noise: &FractalNoise,
+    average: f32,
+) -> Map<f32> {
+    let distances = water_distances(tiles);
+    let mut moisture = Map::new(tiles.width, tiles.height, 0.0);
+
+    moisture.fill(|_, x, y| {
+        let wander = (noise.get(x as f32, y as f32) - 0.5) * MOISTURE_SPREAD * 2.0;
+        let distance = distances.get(x, y).min(WATER_REACH);
+        let near_water = WATER_MOISTURE * (WATER_REACH - distance) as f32 / WATER_REACH as f32;
+
+        (average + wander + near_water).clamp(0.0, 1.0)
+    });
+
+    moisture
+}
+
+/// Picks the biome for land at the elevation, temperature, and moisture
+/// given.
+///
+/// High ground is always mountains. Otherwise it's the temperature which
+/// matters most, then how wet it is.
+pub fn classify(
+    elevation: f32,
+    temperature: f32,
+    moisture: f32,
+    water_level: f32,
+    hill_level: f32,
+) -> Biome {
+    if elevation >= hill_level {
+        Biome::Mountains
+    } else if temperature < TUNDRA_TEMPERATURE {
+        Biome::Tundra
+    } else if temperature > DESERT_TEMPERATURE && moisture < DESERT_MOISTURE {
+        Biome::Desert
+    } else if moisture > SWAMP_MOISTURE && elevation < water_level + SWAMP_HEIGHT {
+        Biome::Swamp
+    } else if moisture > FOREST_MOISTURE {
+        Biome::Forest
+    } else {
+        Biome::Grassland
+    }
+}
+
+/// Returns how many steps each tile is from the nearest water.
+/// This stops counting at `WATER_REACH`.
+fn water_distances(tiles: &Map<Tile>) -> Map<u32> {
+    let mut distances = tiles.map(|&tile| {
+        if tile == Tile::Water {
+            0
+        } else {
+            WATER_REACH
+        }
+    });
+    let mut open = distances
+        .slice_all()
+        .filter(|&(distance, _)| distance == 0)
+        .map(|(_, position)| position)
+        .collect::<VecDeque<_>>();
+
+    while let Some(position) = open.pop_front() {
+        let distance = distances.get(position.x, position.y) + 1;
+
+        for next in neighbours(tiles, position, &SIDES) {
+            if distance < distances.get(next.x, next.y) {
+                distances.set(next.x, next.y, distance);
+                open.push_back(next);
+            }
+        }
+    }
+
+    distances
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn noise() -> FractalNoise {
+        FractalNoise {
+            seed: 2,
+            octaves: 3,
+            feature_size: 16.0,
+            persistence: 0.5,
+            lacunarity: 2.0,
+        }
+    }
+
+    #[test]
+    fn classifies_by_height_then_temperature_then_moisture() {
+        assert_eq!(classify(0.9, 0.5, 0.5, 0.2, 0.75), Biome::Mountains);
+        assert_eq!(classify(0.5, 0.1, 0.9, 0.2, 0.75), Biome::Tundra);
+        assert_eq!(classify(0.5, 0.8, 0.1, 0.2, 0.75), Biome::Desert);
+        assert_eq!(classify(0.3, 0.5, 0.9, 0.2, 0.75), Biome::Swamp);
+        assert_eq!(classify(0.6, 0.5, 0.9, 0.2, 0.75), Biome::Forest);
+        assert_eq!(classify(0.5, 0.5, 0.4, 0.2, 0.75), Biome::Grassland);
+    }
+
+    #[test]
+    fn it_is_colder_up_high() {
+        let mut heightmap = Map::new(20, 1, 0.0);
+        heightmap.fill(|_, x, _| x as f32 / 19.0);
+        let flat = Map::new(20, 1, 0.2);
+
+        let temperatures = temperature_map(&heightmap, &noise(), 0.5, 0.2);
+        let flat_temperatures = temperature_map(&flat, &noise(), 0.5, 0.2);
+
+        assert!(temperatures.get(19, 0) < flat_temperatures.get(19, 0));
+        assert_eq!(temperatures.get(0, 0), flat_temperatures.get(0, 0));
+    }
+
+    #[test]
+    fn it_is_wetter_near_water() {
+        let mut tiles = Map::new(20, 1, Tile::Grass);
+        tiles.set(0, 0, Tile::Water);
+        let dry = Map::new(20, 1, Tile::Grass);
+
+        let moisture = moisture_map(&tiles, &noise(), 0.5);
+        let dry_moisture = moisture_map(&dry, &noise(), 0.5);
+
+        assert!(moisture.get(1, 0) > dry_moisture.get(1, 0));
+        assert_eq!(moisture.get(10, 0), dry_moisture.get(10, 0));
+    }
+}
@@ -0,0 +1,448 @@
+use std::cmp::Reverse;
+use std::collections::VecDeque;
+
+use rand::Rng;
+
+use util::shapes::Point;
+use world::map::Map;
+use world::tiles::Tile;
+
+use generate::new_map_rng;
+use neighbours::is_on_edge;
+use neighbours::neighbours;
+use neighbours::AROUND;
+use neighbours::SIDES;
+
+/// How many of the eight tiles around one must be rock, for it to fill in
+/// with rock as the caves are smoothed.
+const FILL_NEIGHBOURS: usize = 5;
+
+/// How few of the eight tiles around one can be rock, for it to be hollowed
+/// out as the caves are smoothed.
+const HOLLOW_NEIGHBOURS: usize = 3;
+
+/// How many places are tried for each pool, before giving up on it.
+const POOL_TRIES: u32 = 20;
+
+/// Describes the caves to generate.
+#[derive(Copy, Clone, Debug)]
+pub struct CaveOptions {
+    /// The width of the map, in tiles.
+    pub width: u32,
+
+    /// The height of the map, in tiles.
+    pub height: u32,
+
+    /// The same seed always gives the same caves.
+    /// With no seed, new caves are picked at random.
+    pub seed: Option<usize>,
+
+    /// How much of the map starts out as rock, before it's smoothed.
+    /// From 0.0 (open) to 1.0 (solid). Around 0.45 gives winding caves.
+    pub density: f32,
+
+    /// How many times the caves are smoothed. More smoothing gives rounder
+    /// caverns.
+    pub smoothing: u32,
+
+    /// The fewest tiles a cavern can cover. Anything smaller is filled in.
+    pub min_cavern_size: u32,
+
+    /// How many pools of water to try to add.
+    pub pools: u32,
+
+    /// The most tiles a pool can cover.
+    pub max_pool_size: u32,
+}
+
+impl CaveOptions {
+    /// Creates options for caves of the size given, with the default
+    /// settings.
+    ///
+    /// Bigger caves get more pools.
+    pub fn new(
+        width: u32,
+        height: u32,
+        seed: Option<usize>,
+    ) -> CaveOptions {
+        CaveOptions {
+            width: width,
+            height: height,
+            seed: seed,
+            density: 0.45,
+            smoothing: 5,
+            min_cavern_size: 20,
+            pools: width * height / 600,
+            max_pool_size: 12,
+        }
+    }
+}
+
+/// Generates a map of caves dug through solid rock.
+///
+/// Rock is scattered at random, then smoothed into caverns. Caverns which
+/// are too small are filled in, and the rest are joined up with tunnels, so
+/// every open tile can be reached from every other. Finally pools of water
+/// are added, wherever they don't cut any of the caves off.
+pub fn new_cave_map(options: CaveOptions) -> Map<Tile> {
+    let mut rng = new_map_rng(options.seed);
+    let mut map = Map::new(options.width, options.height, Tile::Wall);
+
+    map.fill(|_, x, y| {
+        if is_on_edge(options.width, options.height, Point::new(x, y))
+            || rng.gen::<f32>() < options.density
+        {
+            Tile::Wall
+        } else {
+            Tile::Ground
+        }
+    });
+
+    for _ in 0..options.smoothing {
+        smooth(&mut map);
+    }
+
+    let caverns = remove_small_caverns(&mut map, options.min_cavern_size);
+    join_caverns(&mut map, caverns);
+    add_pools(&mut map, &mut rng, &options);
+
+    map
+}
+
+/// Fills in tiles mostly surrounded by rock, and hollows out tiles mostly
+/// surrounded by open space. Off the edge of the map counts as rock.
+fn smooth(map: &mut Map<Tile>) {
+    let (width, height) = (map.width, map.height);
+    let before = map.map(|&tile| tile);
+
+    map.fill(|&tile, x, y| {
+        if is_on_edge(width, height, Point::new(x, y)) {
+            return Tile::Wall;
+        }
+
+        let rock = neighbours(&before, Point::new(x, y), &AROUND)
+            .into_iter()
+            .filter(|next| before.get(next.x, next.y) == Tile::Wall)
+            .count();
+
+        if rock >= FILL_NEIGHBOURS {
+            Tile::Wall
+        } else if rock <= HOLLOW_NEIGHBOURS {
+            Tile::Ground
+        } else {
+            tile
+        }
+    });
+}
+
+/// Fills in every cavern smaller than the size given, and returns the
+/// caverns which are left, biggest first.
+///
+/// If every cavern is too small then the biggest is kept anyway, so there
+/// is always somewhere to stand.
+fn remove_small_caverns(
+    map: &mut Map<Tile>,
+    min_cavern_size: u32,
+) -> Vec<Vec<Point<u32>>> {
+    let mut caverns = find_caverns(map);
+    caverns.sort_by_key(|cavern| Reverse(cavern.len()));
+
+    let keep = caverns
+        .iter()
+        .enumerate()
+        .take_while(|&(i, cavern)| i == 0 || cavern.len() as u32 >= min_cavern_size)
+        .count();
+
+    for cavern in caverns.drain(keep..) {
+        for position in cavern {
+            map.set(position.x, position.y, Tile::Wall);
+        }
+    }
+
+    caverns
+}
+
+/// Digs a tunnel from each cavern to the nearest cavern already joined up,
+/// starting from the biggest.
+fn join_caverns(
+    map: &mut Map<Tile>,
+    caverns: Vec<Vec<Point<u32>>>,
+) {
+    let mut caverns = caverns.into_iter();
+    let mut joined = match caverns.next() {
+        Some(biggest) => biggest,
+        None => return,
+    };
+
+    for cavern in caverns {
+        let to = nearest(&joined, cavern[0]);
+        let from = nearest(&cavern, to);
+
+        joined.extend(dig_tunnel(map, from, to));
+        joined.extend(cavern);
+    }
+}
+
+/// Digs a tunnel between the two positions, first across, and then up or
+/// down. Returns every tile dug out.
+fn dig_tunnel(
+    map: &mut Map<Tile>,
+    from: Point<u32>,
+    to: Point<u32>,
+) -> Vec<Point<u32>> {
+    let mut dug = Vec::new();
+    let mut position = from;
+
+    while position.x != to.x || position.y != to.y {
+        if position.x != to.x {
+            position.x = if position.x < to.x {
+                position.x + 1
+            } else {
+                position.x - 1
+            };
+        } else {
+            position.y = if position.y < to.y {
+                position.y + 1
+            } else {
+                position.y - 1
+            };
+        }
+
+        if map.get(position.x, position.y) == Tile::Wall {
+            map.set(position.x, position.y, Tile::Ground);
+            dug.push(position);
+        }
+    }
+
+    dug
+}
+
+/// Floods pools of water into the caves.
+///
+/// Each pool starts from an open tile with no rock around it, and spreads
+/// out over the open ground. A pool which would cut any of the caves off
+/// from the rest is drained again.
+fn add_pools<R: Rng>(
+    map: &mut Map<Tile>,
+    rng: &mut R,
+    options: &CaveOptions,
+) {
+    for _ in 0..options.pools {
+        let open = map
+            .slice_all()
+            .filter(|&(tile, position)| {
+                tile == Tile::Ground && is_surrounded_by_ground(map, position)
+            })
+            .map(|(_, position)| position)
+            .collect::<Vec<Point<u32>>>();
+
+        if open.is_empty() {
+            return;
+        }
+
+        for _ in 0..POOL_TRIES {
+            let start = open[rng.gen_range(0, open.len())];
+            let pool = flood_pool(map, rng, start, options.max_pool_size);
+
+            if find_caverns(map).len() <= 1 {
+                break;
+            }
+
+            for position in pool {
+                map.set(position.x, position.y, Tile::Ground);
+            }
+        }
+    }
+}
+
+/// Spreads water out over the ground from the start, in a random direction
+/// each step. Returns every tile flooded.
+fn flood_pool<R: Rng>(
+    map: &mut Map<Tile>,
+    rng: &mut R,
+    start: Point<u32>,
+    max_pool_size: u32,
+) -> Vec<Point<u32>> {
+    let mut pool = vec![start];
+    map.set(start.x, start.y, Tile::Water);
+
+    while (pool.len() as u32) < max_pool_size {
+        let shore = pool
+            .iter()
+            .flat_map(|&position| walkable_neighbours(map, position))
+            .collect::<Vec<Point<u32>>>();
+
+        if shore.is_empty() {
+            break;
+        }
+
+        let next = shore[rng.gen_range(0, shore.len())];
+        map.set(next.x, next.y, Tile::Water);
+        pool.push(next);
+    }
+
+    pool
+}
+
+/// Finds every group of open tiles which can be walked between.
+fn find_caverns(map: &Map<Tile>) -> Vec<Vec<Point<u32>>> {
+    let mut seen = Map::new(map.width, map.height, false);
+    let mut caverns = Vec::new();
+
+    for (tile, start) in map.slice_all() {
+        if tile != Tile::Ground || seen.get(start.x, start.y) {
+            continue;
+        }
+
+        let mut cavern = Vec::new();
+        let mut open = VecDeque::new();
+        seen.set(start.x, start.y, true);
+        open.push_back(start);
+
+        while let Some(position) = open.pop_front() {
+            cavern.push(position);
+
+            for next in walkable_neighbours(map, position) {
+                if !seen.get(next.x, next.y) {
+                    seen.set(next.x, next.y, true);
+                    open.push_back(next);
+                }
+            }
+        }
+
+        caverns.push(cavern);
+    }
+
+    caverns
+}
+
+/// Returns the open ground you can walk to, in one step from the position.
+fn walkable_neighbours(
+    map: &Map<Tile>,
+    position: Point<u32>,
+) -> Vec<Point<u32>> {
+    neighbours(map, position, &SIDES)
+        .into_iter()
+        .filter(|next| map.get(next.x, next.y) == Tile::Ground)
+        .collect()
+}
+
+/// Returns true if all eight tiles around the position are open ground.
+fn is_surrounded_by_ground(
+    map: &Map<Tile>,
+    position: Point<u32>,
+) -> bool {
+    !is_on_edge(map.width, map.height, position)
+        && neighbours(map, position, &AROUND)
+            .into_iter()
+            .all(|next| map.get(next.x, next.y) == Tile::Ground)
+}
+
+/// Returns the position in the group which is closest to the one given.
+fn nearest(
+    group: &[Point<u32>],
+    to: Point<u32>,
+) -> Point<u32> {
+    *group
+        .iter()
+        .min_by_key(|position| {
+            let dx = i64::from(position.x) - i64::from(to.x);
+            let dy = i64::from(position.y) - i64::from(to.y);
+
+            dx * dx + dy * dy
+        })
+        .expect("caverns are never empty")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tiles(map: &Map<Tile>) -> Vec<Tile> {
+        map.slice_all().map(|(tile, _)| tile).collect()
+    }
+
+    fn open_tiles(map: &Map<Tile>) -> usize {
+        tiles(map).iter().filter(|&&tile| tile == Tile::Ground).count()
+    }
+
+    #[test]
+    fn every_cave_can_be_reached() {
+        for seed in 0..20 {
+            let map = new_cave_map(CaveOptions::new(60, 40, Some(seed)));
+
+            assert_eq!(find_caverns(&map).len(), 1);
+            assert!(open_tiles(&map) > 0);
+        }
+    }
+
+    #[test]
+    fn the_edges_are_solid_rock() {
+        let map = new_cave_map(CaveOptions::new(30, 20, Some(4)));
+
+        for (tile, position) in map.slice_all() {
+            if is_on_edge(30, 20, position) {
+                assert_eq!(tile, Tile::Wall);
+            }
+        }
+    }
+
+    #[test]
+    fn the_same_seed_gives_the_same_caves() {
+        let first = new_cave_map(CaveOptions::new(40, 40, Some(9)));
+        let second = new_cave_map(CaveOptions::new(40, 40, Some(9)));
+
+        assert!(tiles(&first) == tiles(&second));
+    }
+
+    #[test]
+    fn denser_rock_leaves_less_open_space() {
+        let open = |density: f32| {
+            open_tiles(&new_cave_map(CaveOptions {
+                density: density,
+                pools: 0,
+                ..CaveOptions::new(60, 60, Some(2))
+            }))
+        };
+
+        assert!(open(0.35) > open(0.5));
+    }
+
+    #[test]
+    fn small_caverns_are_filled_in() {
+        let options = CaveOptions {
+            min_cavern_size: 50,
+            ..CaveOptions::new(60, 60, Some(5))
+        };
+        let mut map = Map::new(60, 60, Tile::Wall);
+        map.fill(|_, x, y| {
+            let in_big = x > 5 && x < 30 && y > 5 && y < 30;
+            let in_small = x > 40 && x < 44 && y > 40 && y < 44;
+
+            if in_big || in_small {
+                Tile::Ground
+            } else {
+                Tile::Wall
+            }
+        });
+
+        let caverns = remove_small_caverns(&mut map, options.min_cavern_size);
+
+        assert_eq!(caverns.len(), 1);
+        assert_eq!(map.get(42, 42), Tile::Wall);
+        assert_eq!(map.get(10, 10), Tile::Ground);
+    }
+
+    #[test]
+    fn pools_never_cut_caves_off() {
+        for seed in 0..10 {
+            let map = new_cave_map(CaveOptions {
+                pools: 6,
+                ..CaveOptions::new(60, 40, Some(seed))
+            });
+
+            assert!(tiles(&map).contains(&Tile::Water));
+            assert_eq!(find_caverns(&map).len(), 1);
+        }
+    }
+}
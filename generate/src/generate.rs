@@ -1,3 +1,4 @@
+use world::biomes::Biome;
 use world::map::Map;
 use world::tiles::Tile;
 
@@ -5,29 +6,151 @@ use rand::Rng;
 use rand::SeedableRng;
 use rand::StdRng;
 
+use util::shapes::Point;
+
+use biomes::classify;
+use biomes::moisture_map;
+use biomes::temperature_map;
+use heightmap::new_heightmap;
+use hydrology::add_water;
+use neighbours::neighbours;
+use neighbours::AROUND;
+use noise::FractalNoise;
+use structures::add_structure;
+use structures::Structure;
+use structures::StructureKind;
+
+/// How far above the water level the bare ground along the shore reaches.
+const SHORE_HEIGHT: f32 = 0.03;
+
+/// How many layers of detail the temperature and moisture have.
+/// These change more gently than the land.
+const CLIMATE_OCTAVES: u32 = 3;
+
+/// A newly generated map.
+pub struct GeneratedMap {
+    /// The land of each tile.
+    pub tiles: Map<Tile>,
+
+    /// The biome each tile is in.
+    pub biomes: Map<Biome>,
+
+    /// The ruins, cabins, and villages built on the map.
+    pub structures: Vec<Structure>,
+}
+
+/// Describes the map to generate.
+#[derive(Copy, Clone, Debug)]
 pub struct MapOptions {
+    /// The width of the map, in tiles.
     pub width: u32,
+
+    /// The height of the map, in tiles.
     pub height: u32,
+
+    /// The same seed always gives the same map.
+    /// With no seed, a new map is picked at random.
     pub seed: Option<usize>,
+
+    /// How many layers of detail the terrain has.
+    pub octaves: u32,
+
+    /// How far apart the broadest features of the terrain are, in tiles.
+    pub feature_size: f32,
+
+    /// How much fainter each finer layer of detail is than the one before.
+    /// From 0.0 (smooth) to 1.0 (rugged).
+    pub persistence: f32,
+
+    /// How much finer each layer of detail is than the one before.
+    pub lacunarity: f32,
+
+    /// Elevations below this are water.
+    /// Elevations run from 0.0 at the lowest point, to 1.0 at the highest.
+    pub water_level: f32,
+
+    /// Elevations above this are hills.
+    pub hill_level: f32,
+
+    /// Elevations above this are rock outcrops.
+    pub rock_level: f32,
+
+    /// How many rivers spring up in the hills.
+    pub rivers: u32,
+
+    /// The most tiles a lake can cover.
+    pub max_lake_size: u32,
+
+    /// How many ruins, cabins, and villages to try to build.
+    /// There may be fewer, if there isn't the room for them.
+    pub structures: u32,
+
+    /// How warm the map is on average.
+    /// From 0.0 (arctic) to 1.0 (tropical).
+    pub temperature: f32,
+
+    /// How wet the map is on average.
+    /// From 0.0 (arid) to 1.0 (sodden).
+    pub moisture: f32,
 }
 
-pub fn new_map(options: MapOptions) -> Map<Tile> {
+impl MapOptions {
+    /// Creates options for a map of the size given, with the default
+    /// terrain settings.
+    ///
+    /// Bigger maps get more rivers, and more structures.
+    pub fn new(
+        width: u32,
+        height: u32,
+        seed: Option<usize>,
+    ) -> MapOptions {
+        MapOptions {
+            width: width,
+            height: height,
+            seed: seed,
+            octaves: 5,
+            feature_size: 32.0,
+            persistence: 0.5,
+            lacunarity: 2.0,
+            water_level: 0.2,
+            hill_level: 0.75,
+            rock_level: 0.88,
+            rivers: 1 + width * height / 1000,
+            max_lake_size: 150,
+            structures: width * height / 1000,
+            temperature: 0.5,
+            moisture: 0.5,
+        }
+    }
+}
+
+pub fn new_map(options: MapOptions) -> GeneratedMap {
     let mut map = Map::new(options.width, options.height, Tile::Grass);
-    let mut rng = new_map_rng(&options);
+    let mut rng = new_map_rng(options.seed);
+    let mut heightmap = new_map_heightmap(&mut rng, &options);
 
-    add_ground_vegetation(&mut map, &mut rng);
-    add_buildings(&mut map, &mut rng);
+    add_terrain(&mut map, &options, &heightmap);
+    add_rivers(&mut map, &mut rng, &options, &mut heightmap);
 
-    return map;
+    let biomes = new_biome_map(&map, &mut rng, &options, &heightmap);
+    add_vegetation(&mut map, &mut rng, &biomes);
+    let structures = add_structures(&mut map, &mut rng, &options);
+
+    return GeneratedMap {
+        tiles: map,
+        biomes: biomes,
+        structures: structures,
+    };
 }
 
-fn new_map_rng(options: &MapOptions) -> StdRng {
+/// Creates the random number generator a map is built with.
+pub(crate) fn new_map_rng(seed: Option<usize>) -> StdRng {
     let mut rng = match StdRng::new() {
         Ok(rng) => rng,
         Err(e) => panic!("Could not initialise map rng, {}", e),
     };
 
-    match options.seed {
+    match seed {
         Some(seed) => {
             rng.reseed(&[seed]);
         },
@@ -37,33 +160,311 @@ fn new_map_rng(options: &MapOptions) -> StdRng {
     return rng;
 }
 
-fn add_ground_vegetation(
-    mut map: &mut Map<Tile>,
-    mut rng: &mut StdRng,
+/// Builds the heightmap the land is shaped from.
+fn new_map_heightmap(
+    rng: &mut StdRng,
+    options: &MapOptions,
+) -> Map<f32> {
+    let noise = FractalNoise {
+        seed: rng.gen(),
+        octaves: options.octaves,
+        feature_size: options.feature_size,
+        persistence: options.persistence,
+        lacunarity: options.lacunarity,
+    };
+
+    new_heightmap(options.width, options.height, &noise)
+}
+
+/// Shapes the land from the heightmap. Low ground is flooded, and high
+/// ground becomes hills, then rocks. Anything in between is grass.
+fn add_terrain(
+    map: &mut Map<Tile>,
+    options: &MapOptions,
+    heightmap: &Map<f32>,
 ) {
-    map.fill(|_, _, _| random_tile(&mut rng));
+    map.fill(|_, x, y| {
+        let elevation = heightmap.get(x, y);
+
+        if elevation < options.water_level {
+            Tile::Water
+        } else if elevation < options.water_level + SHORE_HEIGHT {
+            Tile::Ground
+        } else if elevation >= options.rock_level {
+            Tile::Rocks
+        } else if elevation >= options.hill_level {
+            Tile::Hill
+        } else {
+            Tile::Grass
+        }
+    });
 }
 
-fn add_buildings(
-    mut map: &mut Map<Tile>,
-    mut rng: &mut StdRng,
+/// Picks springs at random up in the hills, and runs rivers down from them.
+fn add_rivers(
+    map: &mut Map<Tile>,
+    rng: &mut StdRng,
+    options: &MapOptions,
+    heightmap: &mut Map<f32>,
 ) {
-    for x in 20..30 {
-        map.set(x, 15, Tile::Wall);
-    }
+    let hills = heightmap
+        .slice_all()
+        .filter(|&(elevation, _)| elevation >= options.hill_level)
+        .map(|(_, position)| position)
+        .collect::<Vec<Point<u32>>>();
 
-    for y in 15..30 {
-        map.set(20, y, Tile::Wall);
+    if hills.is_empty() {
+        return;
     }
+
+    let springs = (0..options.rivers)
+        .map(|_| hills[rng.gen_range(0, hills.len())])
+        .collect::<Vec<Point<u32>>>();
+
+    add_water(map, heightmap, &springs, options.max_lake_size);
+}
+
+/// Works out how warm, and how wet, each tile is, and picks their biomes
+/// from that.
+fn new_biome_map(
+    map: &Map<Tile>,
+    rng: &mut StdRng,
+    options: &MapOptions,
+    heightmap: &Map<f32>,
+) -> Map<Biome> {
+    let temperature_noise = FractalNoise {
+        seed: rng.gen(),
+        octaves: CLIMATE_OCTAVES,
+        feature_size: options.feature_size * 2.0,
+        persistence: options.persistence,
+        lacunarity: options.lacunarity,
+    };
+    let moisture_noise = FractalNoise {
+        seed: rng.gen(),
+        ..temperature_noise
+    };
+
+    let temperatures =
+        temperature_map(heightmap, &temperature_noise, options.temperature, options.water_level);
+    let moisture = moisture_map(map, &moisture_noise, options.moisture);
+
+    let mut biomes = Map::new(map.width, map.height, Biome::Grassland);
+    biomes.fill(|_, x, y| {
+        classify(
+            heightmap.get(x, y),
+            temperatures.get(x, y),
+            moisture.get(x, y),
+            options.water_level,
+            options.hill_level,
+        )
+    });
+
+    biomes
+}
+
+/// Covers the grass with whatever grows in each biome, and freezes the
+/// water in the tundra. The banks along the water are left as they are.
+fn add_vegetation(
+    map: &mut Map<Tile>,
+    rng: &mut StdRng,
+    biomes: &Map<Biome>,
+) {
+    let banks = water_banks(map);
+
+    map.fill(|&tile, x, y| {
+        let biome = biomes.get(x, y);
+
+        match tile {
+            Tile::Water if biome == Biome::Tundra => Tile::Ice,
+            Tile::Grass if !banks.get(x, y) => random_tile(rng, biome),
+            other => other,
+        }
+    });
 }
 
-fn random_tile(rng: &mut StdRng) -> Tile {
+/// Returns true for every tile next to water.
+fn water_banks(map: &Map<Tile>) -> Map<bool> {
+    let mut banks = Map::new(map.width, map.height, false);
+
+    banks.fill(|_, x, y| {
+        neighbours(map, Point::new(x, y), &AROUND)
+            .into_iter()
+            .any(|next| map.get(next.x, next.y) == Tile::Water)
+    });
+
+    banks
+}
+
+/// Builds ruins, cabins, and villages wherever there is open land for them.
+fn add_structures(
+    map: &mut Map<Tile>,
+    rng: &mut StdRng,
+    options: &MapOptions,
+) -> Vec<Structure> {
+    (0..options.structures)
+        .filter_map(|_| {
+            let kind = StructureKind::random(rng);
+            add_structure(map, rng, kind)
+        })
+        .collect()
+}
+
+fn random_tile(
+    rng: &mut StdRng,
+    biome: Biome,
+) -> Tile {
     let r = rng.gen_range(0, 20);
 
-    return match r {
-        0...3 => Tile::GrassThick,
-        4 => Tile::TreeStump,
-        6 => Tile::Rocks,
-        _ => Tile::Grass,
+    return match (biome, r) {
+        (Biome::Grassland, 0..=3) => Tile::GrassThick,
+        (Biome::Grassland, 4) => Tile::TreeStump,
+        (Biome::Grassland, 6) => Tile::Rocks,
+        (Biome::Grassland, _) => Tile::Grass,
+
+        (Biome::Forest, 0..=8) => Tile::TreeStump,
+        (Biome::Forest, 9..=11) => Tile::GrassThick,
+        (Biome::Forest, _) => Tile::Grass,
+
+        (Biome::Tundra, 0..=5) => Tile::Ice,
+        (Biome::Tundra, 6..=7) => Tile::Rocks,
+        (Biome::Tundra, _) => Tile::Ground,
+
+        (Biome::Desert, 0) => Tile::Rocks,
+        (Biome::Desert, _) => Tile::Ground,
+
+        (Biome::Swamp, 0..=6) => Tile::Water,
+        (Biome::Swamp, 7..=12) => Tile::GrassThick,
+        (Biome::Swamp, _) => Tile::Grass,
+
+        (Biome::Mountains, 0..=5) => Tile::Rocks,
+        (Biome::Mountains, _) => Tile::Hill,
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tiles(map: &Map<Tile>) -> Vec<Tile> {
+        map.slice_all().map(|(tile, _)| tile).collect()
+    }
+
+    #[test]
+    fn the_same_seed_gives_the_same_map() {
+        let first = new_map(MapOptions::new(60, 40, Some(12)));
+        let second = new_map(MapOptions::new(60, 40, Some(12)));
+        let other = new_map(MapOptions::new(60, 40, Some(13)));
+
+        assert!(tiles(&first.tiles) == tiles(&second.tiles));
+        assert!(tiles(&first.tiles) != tiles(&other.tiles));
+    }
+
+    #[test]
+    fn elevation_decides_the_terrain() {
+        let map = new_map(MapOptions::new(80, 80, Some(4)));
+        let tiles = tiles(&map.tiles);
+
+        assert!(tiles.contains(&Tile::Water));
+        assert!(tiles.contains(&Tile::Hill));
+        assert!(tiles.contains(&Tile::Grass));
+    }
+
+    #[test]
+    fn rivers_run_from_the_hills() {
+        let without_rivers = new_map(MapOptions {
+            rivers: 0,
+            ..MapOptions::new(80, 80, Some(4))
+        });
+        let with_rivers = new_map(MapOptions::new(80, 80, Some(4)));
+
+        let water = |map: &GeneratedMap| {
+            tiles(&map.tiles).iter().filter(|&&tile| tile == Tile::Water).count()
+        };
+        assert!(water(&with_rivers) > water(&without_rivers));
+    }
+
+    #[test]
+    fn everything_is_water_below_the_water_level() {
+        let options = MapOptions {
+            water_level: 1.1,
+            ..MapOptions::new(10, 10, Some(1))
+        };
+        let mut map = Map::new(10, 10, Tile::Grass);
+        let mut rng = new_map_rng(options.seed);
+        let heightmap = new_map_heightmap(&mut rng, &options);
+        add_terrain(&mut map, &options, &heightmap);
+
+        assert!(tiles(&map).iter().all(|&tile| tile == Tile::Water));
+    }
+
+    #[test]
+    fn cold_maps_are_frozen_tundra() {
+        let map = new_map(MapOptions {
+            temperature: 0.0,
+            ..MapOptions::new(60, 60, Some(8))
+        });
+
+        assert!(tiles(&map.tiles).contains(&Tile::Ice));
+        for (biome, position) in map.biomes.slice_all() {
+            assert!(biome == Biome::Tundra || biome == Biome::Mountains);
+
+            if biome == Biome::Tundra {
+                assert!(map.tiles.get(position.x, position.y) != Tile::Water);
+            }
+        }
+    }
+
+    #[test]
+    fn biomes_decide_what_grows() {
+        let options = MapOptions {
+            rivers: 0,
+            ..MapOptions::new(60, 60, Some(8))
+        };
+        let wet = new_map(MapOptions {
+            moisture: 1.0,
+            ..options
+        });
+        let dry = new_map(MapOptions {
+            temperature: 1.0,
+            moisture: 0.0,
+            ..options
+        });
+
+        let count = |map: &GeneratedMap, biome: Biome| {
+            map.biomes.slice_all().filter(|&(other, _)| other == biome).count()
+        };
+        let trees = |map: &GeneratedMap| {
+            tiles(&map.tiles).iter().filter(|&&tile| tile == Tile::TreeStump).count()
+        };
+
+        assert!(count(&wet, Biome::Forest) + count(&wet, Biome::Swamp) > 0);
+        assert!(count(&dry, Biome::Desert) > 0);
+        assert_eq!(count(&dry, Biome::Forest), 0);
+        assert!(trees(&wet) > trees(&dry) * 4);
+    }
+
+    #[test]
+    fn bigger_maps_get_more_structures() {
+        let small = new_map(MapOptions::new(40, 40, Some(2)));
+        let big = new_map(MapOptions::new(120, 120, Some(2)));
+
+        assert!(big.structures.len() > small.structures.len());
+        for structure in &big.structures {
+            for door in &structure.doors {
+                assert_eq!(big.tiles.get(door.x, door.y), Tile::Door);
+            }
+        }
+    }
+
+    #[test]
+    fn small_maps_are_fine() {
+        for size in 1..12 {
+            let map = new_map(MapOptions {
+                structures: 5,
+                ..MapOptions::new(size, size, Some(1))
+            });
+
+            assert_eq!(map.tiles.width, size);
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use world::map::Map;
+
+use noise::FractalNoise;
+
+/// Builds an elevation map from the noise given.
+///
+/// Elevations are stretched to run from 0.0 at the lowest point, up to 1.0
+/// at the highest. This way the same levels give a similar amount of water
+/// and hills, whatever the noise settings.
+pub fn new_heightmap(
+    width: u32,
+    height: u32,
+    noise: &FractalNoise,
+) -> Map<f32> {
+    let mut heightmap = Map::new(width, height, 0.0);
+    heightmap.fill(|_, x, y| noise.get(x as f32, y as f32));
+
+    let (lowest, highest) =
+        heightmap.slice_all().fold((1.0f32, 0.0f32), |(lowest, highest), (elevation, _)| {
+            (lowest.min(elevation), highest.max(elevation))
+        });
+
+    let range = highest - lowest;
+    heightmap.fill(|&elevation, _, _| {
+        if range > 0.0 {
+            (elevation - lowest) / range
+        } else {
+            0.5
+        }
+    });
+
+    heightmap
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn covers_the_full_range_of_elevations() {
+        let noise = FractalNoise {
+            seed: 11,
+            octaves: 5,
+            feature_size: 20.0,
+            persistence: 0.5,
+            lacunarity: 2.0,
+        };
+        let heightmap = new_heightmap(40, 30, &noise);
+
+        let elevations =
+            heightmap.slice_all().map(|(elevation, _)| elevation).collect::<Vec<f32>>();
+        assert_eq!(elevations.len(), 40 * 30);
+        assert!(elevations.contains(&0.0));
+        assert!(elevations.contains(&1.0));
+        assert!(elevations.iter().all(|elevation| (0.0..=1.0).contains(elevation)));
+    }
+}
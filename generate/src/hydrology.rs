@@ -0,0 +1,425 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use util::shapes::Point;
+use world::map::Map;
+use world::tiles::Tile;
+
+use neighbours::is_on_edge;
+use neighbours::neighbours;
+use neighbours::AROUND;
+use neighbours::SIDES;
+
+/// How much lower a riverbed is than the land it runs through.
+const RIVERBED_DEPTH: f32 = 0.02;
+
+/// Where a river ends.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Mouth {
+    /// It flows off the edge of the map.
+    Edge,
+
+    /// It flows into a lake, the sea, or another river.
+    Water,
+}
+
+/// A river, from its source down to its mouth.
+pub struct River {
+    /// Every tile of the river, in order from the source.
+    pub path: Vec<Point<u32>>,
+
+    /// Where the river ends.
+    pub mouth: Mouth,
+}
+
+/// The rivers and lakes added to a map.
+pub struct Hydrology {
+    /// Every river, in the order they were made.
+    ///
+    /// A river which fills a lake until it overflows carries on as a new
+    /// river, starting from where it spills out.
+    pub rivers: Vec<River>,
+
+    /// Which lake each tile is part of, numbered from one.
+    /// Zero for tiles which aren't part of a lake.
+    pub lakes: Map<u32>,
+}
+
+/// Where a river starts from.
+#[derive(Copy, Clone)]
+struct Source {
+    /// Where the water comes out of the ground, or spills out of a lake.
+    position: Point<u32>,
+
+    /// The lake the water spills out of, and the index of the river which
+    /// filled it. None for springs.
+    upstream: Option<(u32, usize)>,
+}
+
+/// Runs a river downhill from each spring, carving out its bed as it goes.
+///
+/// Rivers end when they reach the edge of the map, or other water. A river
+/// which gets stuck in a dip fills it with a lake. If the lake overflows
+/// before it grows to the largest size allowed, the river carries on from
+/// where it spills out.
+///
+/// The land along the banks of the rivers and lakes becomes grass.
+pub fn add_water(
+    map: &mut Map<Tile>,
+    heightmap: &mut Map<f32>,
+    springs: &[Point<u32>],
+    max_lake_size: u32,
+) -> Hydrology {
+    let mut hydrology = Hydrology {
+        rivers: Vec::new(),
+        lakes: Map::new(map.width, map.height, 0),
+    };
+
+    let mut sources = springs
+        .iter()
+        .map(|&spring| {
+            Source {
+                position: spring,
+                upstream: None,
+            }
+        })
+        .collect::<Vec<Source>>();
+    let mut next_lake = 1;
+    let mut i = 0;
+
+    while i < sources.len() {
+        let source = sources[i];
+        i += 1;
+
+        if map.get(source.position.x, source.position.y) == Tile::Water {
+            continue;
+        }
+
+        let rivers = &hydrology.rivers;
+        let upstream = source.upstream.map(|(lake, feeder)| (lake, rivers[feeder].path.as_slice()));
+        let (river, is_stuck) = flow(map, heightmap, &hydrology.lakes, source.position, upstream);
+
+        if is_stuck {
+            let lake = next_lake;
+            next_lake += 1;
+
+            let outlet = fill_lake(
+                map,
+                heightmap,
+                &mut hydrology.lakes,
+                &river.path,
+                upstream,
+                lake,
+                max_lake_size,
+            );
+            if let Some(outlet) = outlet {
+                sources.push(Source {
+                    position: outlet,
+                    upstream: Some((lake, hydrology.rivers.len())),
+                });
+            }
+        }
+
+        hydrology.rivers.push(river);
+    }
+
+    add_banks(map, &hydrology);
+
+    hydrology
+}
+
+/// Runs a river downhill from the source, until it reaches the edge of the
+/// map, other water, or somewhere it can't flow any lower.
+///
+/// The lake the river flows out of, and the river which filled that lake,
+/// don't stop it. Returns the river, and true if it got stuck. The last tile
+/// of a stuck river is left dry, ready to be filled by a lake.
+fn flow(
+    map: &mut Map<Tile>,
+    heightmap: &mut Map<f32>,
+    lakes: &Map<u32>,
+    source: Point<u32>,
+    upstream: Option<(u32, &[Point<u32>])>,
+) -> (River, bool) {
+    let mut path = Vec::new();
+    let mut position = source;
+
+    loop {
+        let elevation = heightmap.get(position.x, position.y);
+        path.push(position);
+        heightmap.set(position.x, position.y, elevation - RIVERBED_DEPTH);
+
+        if is_on_edge(map.width, map.height, position) {
+            map.set(position.x, position.y, Tile::Water);
+
+            return (
+                River {
+                    path: path,
+                    mouth: Mouth::Edge,
+                },
+                false,
+            );
+        }
+
+        // Rivers never flow diagonally, so each tile of a river shares an
+        // edge with the one before.
+        let neighbours = neighbours(map, position, &SIDES);
+        let joins_water = neighbours.iter().any(|&next| {
+            let is_upstream = match upstream {
+                Some((lake, feeder)) => lakes.get(next.x, next.y) == lake || feeder.contains(&next),
+                None => false,
+            };
+
+            map.get(next.x, next.y) == Tile::Water && !path.contains(&next) && !is_upstream
+        });
+
+        if joins_water {
+            map.set(position.x, position.y, Tile::Water);
+
+            return (
+                River {
+                    path: path,
+                    mouth: Mouth::Water,
+                },
+                false,
+            );
+        }
+
+        let lowest = neighbours
+            .into_iter()
+            .filter(|&next| map.get(next.x, next.y) != Tile::Water)
+            .min_by_key(|&next| elevation_key(heightmap.get(next.x, next.y)));
+
+        match lowest {
+            Some(next) if heightmap.get(next.x, next.y) < elevation => {
+                map.set(position.x, position.y, Tile::Water);
+                position = next;
+            },
+            _ => {
+                return (
+                    River {
+                        path: path,
+                        mouth: Mouth::Water,
+                    },
+                    true,
+                );
+            },
+        }
+    }
+}
+
+/// Floods the dip at the end of the river, lowest ground first, with the
+/// lake given. The lake fills up around the river, rather than over it, and
+/// the same goes for the lake and river upstream of it.
+///
+/// This stops once the lake reaches the edge of the map, joins other water,
+/// or grows to the largest size allowed. It also stops if the water finds
+/// lower ground beyond the rim of the dip, and returns where it spills out.
+fn fill_lake(
+    map: &mut Map<Tile>,
+    heightmap: &Map<f32>,
+    lakes: &mut Map<u32>,
+    river: &[Point<u32>],
+    upstream: Option<(u32, &[Point<u32>])>,
+    lake: u32,
+    max_lake_size: u32,
+) -> Option<Point<u32>> {
+    let start = *river.last().expect("rivers always have a source");
+    let mut shore = BinaryHeap::new();
+    let mut level = heightmap.get(start.x, start.y);
+    let mut size = 0;
+
+    let mut queued = match upstream {
+        Some((upstream_lake, _)) => lakes.map(|&other| other == upstream_lake),
+        None => Map::new(map.width, map.height, false),
+    };
+    let feeder = upstream.map(|(_, feeder)| feeder).unwrap_or(&[]);
+    for position in river.iter().chain(feeder) {
+        queued.set(position.x, position.y, true);
+    }
+    shore.push(Reverse((elevation_key(level), start.y, start.x)));
+
+    while let Some(Reverse((_, y, x))) = shore.pop() {
+        let position = Point::new(x, y);
+        let elevation = heightmap.get(x, y);
+
+        if size > 0 && map.get(x, y) == Tile::Water {
+            return None;
+        }
+
+        if elevation < level {
+            return Some(position);
+        }
+
+        level = elevation;
+        size += 1;
+        map.set(x, y, Tile::Water);
+        lakes.set(x, y, lake);
+
+        if is_on_edge(map.width, map.height, position) || size >= max_lake_size {
+            return None;
+        }
+
+        for next in neighbours(map, position, &SIDES) {
+            if !queued.get(next.x, next.y) {
+                queued.set(next.x, next.y, true);
+                shore.push(Reverse((elevation_key(heightmap.get(next.x, next.y)), next.y, next.x)));
+            }
+        }
+    }
+
+    None
+}
+
+/// Grows grass on the bare ground, and clears the undergrowth, along the
+/// banks of the rivers and lakes.
+fn add_banks(
+    map: &mut Map<Tile>,
+    hydrology: &Hydrology,
+) {
+    let mut is_wet = hydrology.lakes.map(|&lake| lake != 0);
+    for river in &hydrology.rivers {
+        for position in &river.path {
+            is_wet.set(position.x, position.y, true);
+        }
+    }
+
+    let mut banks = Vec::new();
+    for (wet, position) in is_wet.slice_all() {
+        if wet {
+            banks.extend(neighbours(map, position, &AROUND));
+        }
+    }
+
+    for bank in banks {
+        match map.get(bank.x, bank.y) {
+            Tile::Ground | Tile::GrassThick | Tile::TreeStump => {
+                map.set(bank.x, bank.y, Tile::Grass);
+            },
+            _ => {},
+        }
+    }
+}
+
+/// Turns an elevation into a number which sorts the same way.
+fn elevation_key(elevation: f32) -> u32 {
+    ((elevation + 1.0).max(0.0) * 1_000_000.0) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use heightmap::new_heightmap;
+    use noise::FractalNoise;
+
+    /// A heightmap where each column is the height given, with a valley
+    /// running along the middle row.
+    fn valley(columns: &[f32]) -> Map<f32> {
+        let mut heightmap = Map::new(columns.len() as u32, 11, 0.0);
+        heightmap.fill(|_, x, y| columns[x as usize] + (y as f32 - 5.0).abs() * 0.2);
+
+        heightmap
+    }
+
+    fn is_next_to(
+        a: Point<u32>,
+        b: Point<u32>,
+    ) -> bool {
+        (a.x as i32 - b.x as i32).abs() + (a.y as i32 - b.y as i32).abs() == 1
+    }
+
+    #[test]
+    fn rivers_run_downhill_to_the_edge() {
+        let mut heightmap = valley(&[0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8]);
+        let mut map = Map::new(8, 11, Tile::Ground);
+
+        let hydrology = add_water(&mut map, &mut heightmap, &[Point::new(6, 5)], 50);
+
+        assert_eq!(hydrology.rivers.len(), 1);
+        assert_eq!(hydrology.rivers[0].mouth, Mouth::Edge);
+        assert_eq!(hydrology.rivers[0].path.len(), 7);
+        for x in 0..7 {
+            assert_eq!(map.get(x, 5), Tile::Water);
+            assert_eq!(map.get(x, 4), Tile::Grass);
+        }
+        assert_eq!(map.get(7, 5), Tile::Grass);
+        assert_eq!(map.get(3, 3), Tile::Ground);
+    }
+
+    #[test]
+    fn lakes_fill_dips_then_overflow() {
+        let mut heightmap = valley(&[0.0, 0.1, 0.2, 0.3, 0.6, 0.5, 0.4, 0.5, 0.7, 0.9]);
+        let mut map = Map::new(10, 11, Tile::Ground);
+
+        let hydrology = add_water(&mut map, &mut heightmap, &[Point::new(8, 5)], 50);
+
+        assert_eq!(hydrology.rivers.len(), 2);
+        assert_eq!(hydrology.rivers[0].mouth, Mouth::Water);
+        assert_eq!(hydrology.rivers[1].mouth, Mouth::Edge);
+        assert!(hydrology.rivers[1].path[0] == Point::new(3, 5));
+        assert_eq!(hydrology.lakes.get(5, 5), 1);
+        assert_eq!(hydrology.lakes.get(7, 5), 0);
+        assert_eq!(map.get(0, 5), Tile::Water);
+    }
+
+    #[test]
+    fn lakes_stop_growing_at_the_largest_size() {
+        let mut heightmap = Map::new(11, 11, 0.0);
+        heightmap.fill(|_, x, y| (x as f32 - 5.0).abs() + (y as f32 - 5.0).abs());
+        let mut map = Map::new(11, 11, Tile::Grass);
+
+        let hydrology = add_water(&mut map, &mut heightmap, &[Point::new(5, 2)], 6);
+
+        let lake_size = hydrology.lakes.slice_all().filter(|&(lake, _)| lake != 0).count();
+        assert_eq!(lake_size, 6);
+        assert_eq!(hydrology.rivers.len(), 1);
+        assert_eq!(hydrology.lakes.get(5, 5), 1);
+    }
+
+    #[test]
+    fn rivers_are_continuous_and_reach_an_edge_or_a_lake() {
+        for seed in 0..20 {
+            let noise = FractalNoise {
+                seed: seed,
+                octaves: 5,
+                feature_size: 24.0,
+                persistence: 0.5,
+                lacunarity: 2.0,
+            };
+            let mut heightmap = new_heightmap(64, 48, &noise);
+            let mut map = Map::new(64, 48, Tile::Grass);
+            let springs = heightmap
+                .slice_all()
+                .filter(|&(elevation, position)| {
+                    elevation > 0.6 && position.x % 5 == 0 && position.y % 5 == 0
+                })
+                .map(|(_, position)| position)
+                .collect::<Vec<Point<u32>>>();
+
+            let hydrology = add_water(&mut map, &mut heightmap, &springs, 100);
+
+            assert!(!hydrology.rivers.is_empty());
+            for river in &hydrology.rivers {
+                for pair in river.path.windows(2) {
+                    assert!(is_next_to(pair[0], pair[1]));
+                }
+                for position in &river.path {
+                    assert_eq!(map.get(position.x, position.y), Tile::Water);
+                }
+
+                let mouth = *river.path.last().unwrap();
+                match river.mouth {
+                    Mouth::Edge => assert!(is_on_edge(map.width, map.height, mouth)),
+                    Mouth::Water => {
+                        let in_lake = hydrology.lakes.get(mouth.x, mouth.y) != 0;
+                        let joins_water = neighbours(&map, mouth, &SIDES).into_iter().any(|next| {
+                            map.get(next.x, next.y) == Tile::Water && !river.path.contains(&next)
+                        });
+
+                        assert!(in_lake || joins_water);
+                    },
+                }
+            }
+        }
+    }
+}
@@ -1,4 +1,26 @@
 extern crate rand;
+extern crate util;
 extern crate world;
 
+mod neighbours;
+
+/// Biomes, and the climate which decides them.
+pub mod biomes;
+
+/// Caves dug through solid rock.
+pub mod caves;
+
+/// Generates new maps.
 pub mod generate;
+
+/// Elevation maps, which the terrain is built from.
+pub mod heightmap;
+
+/// Rivers and lakes.
+pub mod hydrology;
+
+/// Smooth random noise.
+pub mod noise;
+
+/// Ruins, cabins, and villages.
+pub mod structures;
@@ -0,0 +1,52 @@
+use util::shapes::Point;
+use world::map::Map;
+
+/// The four directions to the tiles sharing an edge with a tile.
+pub const SIDES: [(i32, i32); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// The eight directions around a tile, including the diagonals.
+pub const AROUND: [(i32, i32); 8] =
+    [(-1, -1), (0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0)];
+
+/// Returns the positions next to the one given, in the directions given,
+/// which are on the map.
+pub fn neighbours<T: Copy>(
+    map: &Map<T>,
+    position: Point<u32>,
+    directions: &[(i32, i32)],
+) -> Vec<Point<u32>> {
+    directions
+        .iter()
+        .map(|&(dx, dy)| (position.x as i32 + dx, position.y as i32 + dy))
+        .filter(|&(x, y)| x >= 0 && y >= 0 && x < map.width as i32 && y < map.height as i32)
+        .map(|(x, y)| Point::new(x as u32, y as u32))
+        .collect()
+}
+
+/// Returns true if the position is along the edge of a map of the size
+/// given.
+pub fn is_on_edge(
+    width: u32,
+    height: u32,
+    position: Point<u32>,
+) -> bool {
+    position.x == 0 || position.y == 0 || position.x + 1 >= width || position.y + 1 >= height
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neighbours_stay_on_the_map() {
+        let map = Map::new(3, 2, 0);
+
+        assert_eq!(
+            neighbours(&map, Point::new(0, 0), &SIDES),
+            vec![Point::new(1, 0), Point::new(0, 1)]
+        );
+        assert_eq!(neighbours(&map, Point::new(1, 1), &AROUND).len(), 5);
+        assert!(is_on_edge(3, 2, Point::new(1, 1)));
+        assert!(!is_on_edge(3, 3, Point::new(1, 1)));
+    }
+}
@@ -0,0 +1,152 @@
+use util::random::Random;
+
+/// Separates the random numbers used for each octave, so they don't all
+/// line up with each other.
+const OCTAVE_SEED_STEP: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// Smooth random noise over a 2D plane, made by layering value noise at
+/// finer and finer scales.
+///
+/// The first octave gives the broad shape, and each octave after adds
+/// smaller, fainter, details on top.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct FractalNoise {
+    /// Picks the random values. The same seed always gives the same noise.
+    pub seed: u64,
+
+    /// How many layers of noise are added together.
+    pub octaves: u32,
+
+    /// How far apart the features of the first octave are, in tiles.
+    pub feature_size: f32,
+
+    /// How much fainter each octave is than the one before.
+    /// i.e. 0.5 gives each octave half the strength of the last.
+    pub persistence: f32,
+
+    /// How much finer each octave is than the one before.
+    /// i.e. 2.0 gives each octave features half the size of the last.
+    pub lacunarity: f32,
+}
+
+impl FractalNoise {
+    /// Returns the noise at the position given, from 0.0 to 1.0.
+    ///
+    /// Positions close together give similar values.
+    pub fn get(
+        &self,
+        x: f32,
+        y: f32,
+    ) -> f32 {
+        let mut total = 0.0;
+        let mut total_amplitude = 0.0;
+        let mut amplitude = 1.0;
+        let mut frequency = 1.0 / self.feature_size.max(1.0);
+
+        for octave in 0..u64::from(self.octaves.max(1)) {
+            let seed = self.seed.wrapping_add(octave.wrapping_mul(OCTAVE_SEED_STEP));
+
+            total += value_noise(seed, x * frequency, y * frequency) * amplitude;
+            total_amplitude += amplitude;
+            amplitude *= self.persistence;
+            frequency *= self.lacunarity;
+        }
+
+        total / total_amplitude
+    }
+}
+
+/// Returns a number from 0.0 to 1.0, which changes smoothly over the plane.
+///
+/// A random number is picked for each whole position, and this eases
+/// between the four around the position given.
+fn value_noise(
+    seed: u64,
+    x: f32,
+    y: f32,
+) -> f32 {
+    let left = x.floor();
+    let top = y.floor();
+    let eased_x = ease(x - left);
+    let eased_y = ease(y - top);
+
+    let left = left as i64;
+    let top = top as i64;
+
+    let top_edge = lerp(lattice(seed, left, top), lattice(seed, left + 1, top), eased_x);
+    let bottom_edge = lerp(lattice(seed, left, top + 1), lattice(seed, left + 1, top + 1), eased_x);
+
+    lerp(top_edge, bottom_edge, eased_y)
+}
+
+/// The random number for one whole position.
+fn lattice(
+    seed: u64,
+    x: i64,
+    y: i64,
+) -> f32 {
+    let mut random =
+        Random::new(seed ^ (x as u64).wrapping_mul(OCTAVE_SEED_STEP) ^ (y as u64).rotate_left(32));
+
+    random.next_f32()
+}
+
+/// Smooths the progress from 0.0 to 1.0, so it starts and ends slowly.
+fn ease(progress: f32) -> f32 {
+    progress * progress * (3.0 - 2.0 * progress)
+}
+
+/// Moves from one value to the other, by the amount given.
+fn lerp(
+    from: f32,
+    to: f32,
+    amount: f32,
+) -> f32 {
+    from + (to - from) * amount
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn noise(seed: u64) -> FractalNoise {
+        FractalNoise {
+            seed: seed,
+            octaves: 4,
+            feature_size: 16.0,
+            persistence: 0.5,
+            lacunarity: 2.0,
+        }
+    }
+
+    #[test]
+    fn stays_within_range() {
+        let noise = noise(3);
+
+        for y in 0..50 {
+            for x in 0..50 {
+                let value = noise.get(x as f32, y as f32);
+                assert!((0.0..=1.0).contains(&value));
+            }
+        }
+    }
+
+    #[test]
+    fn the_same_seed_gives_the_same_noise() {
+        assert_eq!(noise(7).get(12.5, 30.0), noise(7).get(12.5, 30.0));
+        assert!(noise(7).get(12.5, 30.0) != noise(8).get(12.5, 30.0));
+    }
+
+    #[test]
+    fn changes_smoothly() {
+        let noise = FractalNoise {
+            octaves: 1,
+            ..noise(5)
+        };
+
+        for step in 0..200 {
+            let x = step as f32 * 0.1;
+            assert!((noise.get(x, 4.0) - noise.get(x + 0.1, 4.0)).abs() < 0.05);
+        }
+    }
+}
@@ -0,0 +1,343 @@
+use rand::Rng;
+
+use util::shapes::Point;
+use util::shapes::Rect;
+use world::map::Map;
+use world::tiles::Tile;
+
+/// The smallest a cabin, or ruin, can be, including it's walls.
+const MIN_ROOM_SIZE: u32 = 4;
+
+/// The largest a cabin, or ruin, can be, including it's walls.
+const MAX_ROOM_SIZE: u32 = 7;
+
+/// The smallest a village can be.
+const MIN_VILLAGE_SIZE: u32 = 12;
+
+/// The largest a village can be.
+const MAX_VILLAGE_SIZE: u32 = 20;
+
+/// The smallest plot a village is split into. Each plot holds one house,
+/// with a path around it.
+const MIN_PLOT_SIZE: u32 = MIN_ROOM_SIZE + 2;
+
+/// One in this many walls of a ruin have crumbled away.
+const CRUMBLE_CHANCE: u32 = 3;
+
+/// How many places are tried for each structure, before giving up on it.
+const PLACEMENT_TRIES: u32 = 30;
+
+/// The kinds of structure which can be built.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum StructureKind {
+    /// A single room, fallen into disrepair.
+    Ruin,
+
+    /// A single room.
+    Cabin,
+
+    /// A cluster of houses, with paths between them.
+    Village,
+}
+
+impl StructureKind {
+    /// Picks a kind of structure at random.
+    /// Villages are the rarest.
+    pub fn random<R: Rng>(rng: &mut R) -> StructureKind {
+        match rng.gen_range(0, 5) {
+            0 | 1 => StructureKind::Ruin,
+            2 | 3 => StructureKind::Cabin,
+            _ => StructureKind::Village,
+        }
+    }
+}
+
+/// A structure which has been built on the map.
+pub struct Structure {
+    /// What kind of structure it is.
+    pub kind: StructureKind,
+
+    /// The land it's built on.
+    pub area: Rect<u32>,
+
+    /// Each room, including it's walls.
+    pub rooms: Vec<Rect<u32>>,
+
+    /// Where the door into each room is.
+    pub doors: Vec<Point<u32>>,
+}
+
+/// Tries to build a structure of the kind given, somewhere on the map.
+///
+/// Structures are only built on open land, with a tile of space all the way
+/// around them. They're never built on water, hills, rocks, or other
+/// structures. Returns None if nowhere suitable was found.
+pub fn add_structure<R: Rng>(
+    map: &mut Map<Tile>,
+    rng: &mut R,
+    kind: StructureKind,
+) -> Option<Structure> {
+    let (min_size, max_size) = match kind {
+        StructureKind::Ruin | StructureKind::Cabin => (MIN_ROOM_SIZE, MAX_ROOM_SIZE),
+        StructureKind::Village => (MIN_VILLAGE_SIZE, MAX_VILLAGE_SIZE),
+    };
+
+    for _ in 0..PLACEMENT_TRIES {
+        let width = rng.gen_range(min_size, max_size + 1);
+        let height = rng.gen_range(min_size, max_size + 1);
+        if width + 2 > map.width || height + 2 > map.height {
+            continue;
+        }
+
+        let x = rng.gen_range(1, map.width - width);
+        let y = rng.gen_range(1, map.height - height);
+        let area = Rect::new(x, y, width, height);
+
+        if is_buildable(map, area) {
+            return Some(build(map, rng, kind, area));
+        }
+    }
+
+    None
+}
+
+/// Returns true if the area, and a tile all the way around it, is open
+/// land.
+fn is_buildable(
+    map: &Map<Tile>,
+    area: Rect<u32>,
+) -> bool {
+    (area.y - 1..area.y + area.height + 1).all(|y| {
+        (area.x - 1..area.x + area.width + 1).all(|x| {
+            match map.get(x, y) {
+                Tile::Ground | Tile::Grass | Tile::GrassThick | Tile::TreeStump => true,
+                _ => false,
+            }
+        })
+    })
+}
+
+/// Builds a structure of the kind given, over the area.
+///
+/// The land all around it is cleared, so every door can be reached.
+fn build<R: Rng>(
+    map: &mut Map<Tile>,
+    rng: &mut R,
+    kind: StructureKind,
+    area: Rect<u32>,
+) -> Structure {
+    clear(map, Rect::new(area.x - 1, area.y - 1, area.width + 2, area.height + 2));
+
+    let rooms = match kind {
+        StructureKind::Ruin | StructureKind::Cabin => vec![area],
+        StructureKind::Village => {
+            let mut plots = Vec::new();
+            split_plots(rng, area, &mut plots);
+
+            plots
+                .iter()
+                .map(|plot| Rect::new(plot.x + 1, plot.y + 1, plot.width - 2, plot.height - 2))
+                .collect()
+        },
+    };
+
+    let doors = rooms.iter().map(|&room| build_room(map, rng, room)).collect();
+
+    if kind == StructureKind::Ruin {
+        crumble(map, rng, area);
+    }
+
+    Structure {
+        kind: kind,
+        area: area,
+        rooms: rooms,
+        doors: doors,
+    }
+}
+
+/// Splits the area in two, again and again, until the pieces are too small
+/// to split. Each piece is added to the plots.
+///
+/// Areas are split across their longest side, at a random point.
+fn split_plots<R: Rng>(
+    rng: &mut R,
+    area: Rect<u32>,
+    plots: &mut Vec<Rect<u32>>,
+) {
+    let can_split_across = area.width >= MIN_PLOT_SIZE * 2;
+    let can_split_down = area.height >= MIN_PLOT_SIZE * 2;
+
+    if can_split_across && (area.width >= area.height || !can_split_down) {
+        let cut = rng.gen_range(MIN_PLOT_SIZE, area.width - MIN_PLOT_SIZE + 1);
+
+        split_plots(rng, Rect::new(area.x, area.y, cut, area.height), plots);
+        split_plots(rng, Rect::new(area.x + cut, area.y, area.width - cut, area.height), plots);
+    } else if can_split_down {
+        let cut = rng.gen_range(MIN_PLOT_SIZE, area.height - MIN_PLOT_SIZE + 1);
+
+        split_plots(rng, Rect::new(area.x, area.y, area.width, cut), plots);
+        split_plots(rng, Rect::new(area.x, area.y + cut, area.width, area.height - cut), plots);
+    } else {
+        plots.push(area);
+    }
+}
+
+/// Walls in the room, clears the floor, and puts a door in a wall which
+/// isn't a corner. Returns where the door is.
+fn build_room<R: Rng>(
+    map: &mut Map<Tile>,
+    rng: &mut R,
+    room: Rect<u32>,
+) -> Point<u32> {
+    let right = room.x + room.width - 1;
+    let bottom = room.y + room.height - 1;
+
+    for y in room.y..=bottom {
+        for x in room.x..=right {
+            let is_wall = x == room.x || x == right || y == room.y || y == bottom;
+
+            map.set(
+                x,
+                y,
+                if is_wall {
+                    Tile::Wall
+                } else {
+                    Tile::Ground
+                },
+            );
+        }
+    }
+
+    let door = match rng.gen_range(0, 4) {
+        0 => Point::new(rng.gen_range(room.x + 1, right), room.y),
+        1 => Point::new(right, rng.gen_range(room.y + 1, bottom)),
+        2 => Point::new(rng.gen_range(room.x + 1, right), bottom),
+        _ => Point::new(room.x, rng.gen_range(room.y + 1, bottom)),
+    };
+    map.set(door.x, door.y, Tile::Door);
+
+    door
+}
+
+/// Clears the area down to bare ground.
+fn clear(
+    map: &mut Map<Tile>,
+    area: Rect<u32>,
+) {
+    for y in area.y..area.y + area.height {
+        for x in area.x..area.x + area.width {
+            map.set(x, y, Tile::Ground);
+        }
+    }
+}
+
+/// Knocks holes in the walls across the area, leaving rubble behind.
+fn crumble<R: Rng>(
+    map: &mut Map<Tile>,
+    rng: &mut R,
+    area: Rect<u32>,
+) {
+    for y in area.y..area.y + area.height {
+        for x in area.x..area.x + area.width {
+            if map.get(x, y) == Tile::Wall && rng.gen_range(0, CRUMBLE_CHANCE) == 0 {
+                map.set(
+                    x,
+                    y,
+                    if rng.gen() {
+                        Tile::Rocks
+                    } else {
+                        Tile::Ground
+                    },
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::StdRng;
+
+    fn new_rng(seed: usize) -> StdRng {
+        StdRng::from_seed(&[seed])
+    }
+
+    /// Returns the tiles along the edge of the room.
+    fn walls(room: Rect<u32>) -> Vec<Point<u32>> {
+        let right = room.x + room.width - 1;
+        let bottom = room.y + room.height - 1;
+
+        (room.y..=bottom)
+            .flat_map(|y| (room.x..=right).map(move |x| Point::new(x, y)))
+            .filter(|p| p.x == room.x || p.x == right || p.y == room.y || p.y == bottom)
+            .collect()
+    }
+
+    #[test]
+    fn rooms_are_walled_in_with_one_door() {
+        for seed in 0..20 {
+            let mut map = Map::new(40, 40, Tile::Grass);
+            let structure = add_structure(&mut map, &mut new_rng(seed), StructureKind::Village)
+                .expect("there is room for a village");
+
+            assert!(structure.rooms.len() > 1);
+            for (&room, &door) in structure.rooms.iter().zip(&structure.doors) {
+                let walls = walls(room);
+                let doors = walls.iter().filter(|p| map.get(p.x, p.y) == Tile::Door).count();
+
+                let is_corner = (door.x == room.x || door.x == room.x + room.width - 1)
+                    && (door.y == room.y || door.y == room.y + room.height - 1);
+
+                assert_eq!(doors, 1);
+                assert!(!is_corner);
+                assert!(walls.iter().all(|p| *p == door || map.get(p.x, p.y) == Tile::Wall));
+            }
+        }
+    }
+
+    #[test]
+    fn ruins_have_crumbled() {
+        let mut rng = new_rng(3);
+
+        let crumbled = (0..10).any(|_| {
+            let mut map = Map::new(30, 30, Tile::Grass);
+            let ruin = add_structure(&mut map, &mut rng, StructureKind::Ruin).unwrap();
+
+            walls(ruin.rooms[0]).iter().any(|p| {
+                let tile = map.get(p.x, p.y);
+                tile != Tile::Wall && tile != Tile::Door
+            })
+        });
+
+        assert!(crumbled);
+    }
+
+    #[test]
+    fn never_builds_on_water() {
+        let mut map = Map::new(30, 30, Tile::Water);
+        for y in 0..30 {
+            for x in 0..12 {
+                map.set(x, y, Tile::Grass);
+            }
+        }
+
+        for seed in 0..20 {
+            let mut map = map.map(|&tile| tile);
+            if let Some(cabin) = add_structure(&mut map, &mut new_rng(seed), StructureKind::Cabin) {
+                assert!(cabin.area.x + cabin.area.width < 12);
+            }
+
+            assert!(add_structure(&mut map, &mut new_rng(seed), StructureKind::Village).is_none());
+        }
+    }
+
+    #[test]
+    fn gives_up_on_maps_which_are_too_small() {
+        let mut map = Map::new(3, 3, Tile::Grass);
+
+        assert!(add_structure(&mut map, &mut new_rng(1), StructureKind::Cabin).is_none());
+        assert!(add_structure(&mut map, &mut new_rng(1), StructureKind::Village).is_none());
+    }
+}
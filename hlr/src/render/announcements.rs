@@ -0,0 +1,132 @@
+use std::collections::VecDeque;
+
+use game::events::Severity;
+use game::events::TimedEvent;
+use llr::LLR;
+use render::text::clear_row;
+use render::text::draw_text;
+use theme::Theme;
+use util::shapes::Point;
+use util::shapes::Rect;
+
+/// The most announcements kept for scrolling back through.
+const HISTORY_CAPACITY: usize = 500;
+
+/// A panel listing the events which have happened in the game.
+///
+/// The newest announcements are at the bottom. You can scroll back through
+/// older ones, and hide the less important ones.
+pub struct AnnouncementPanel {
+    /// Every announcement we are holding, oldest first.
+    history: VecDeque<TimedEvent>,
+
+    /// How many lines we have scrolled up, from the newest announcement.
+    scroll: usize,
+
+    /// The least severe announcement to show.
+    filter: Severity,
+}
+
+impl AnnouncementPanel {
+    /// Trivial constructor.
+    pub fn new() -> AnnouncementPanel {
+        AnnouncementPanel {
+            history: VecDeque::with_capacity(HISTORY_CAPACITY),
+            scroll: 0,
+            filter: Severity::Info,
+        }
+    }
+
+    /// Adds a new announcement to the bottom of the panel.
+    pub fn push(
+        &mut self,
+        event: TimedEvent,
+    ) {
+        if self.history.len() >= HISTORY_CAPACITY {
+            self.history.pop_front();
+        }
+
+        self.history.push_back(event);
+    }
+
+    /// Scrolls back to older announcements.
+    pub fn scroll_up(&mut self) {
+        if self.scroll + 1 < self.num_shown() {
+            self.scroll += 1;
+        }
+    }
+
+    /// Scrolls forward to newer announcements.
+    pub fn scroll_down(&mut self) {
+        if self.scroll > 0 {
+            self.scroll -= 1;
+        }
+    }
+
+    /// Changes which announcements are hidden.
+    ///
+    /// Each call hides the next least severe level. Once only the most severe
+    /// are shown, it goes back to showing everything.
+    pub fn cycle_filter(&mut self) {
+        self.filter = self.filter.next();
+        self.scroll = 0;
+    }
+
+    /// Draws the panel into the area given.
+    ///
+    /// The top row is a title, and the rest are the announcements.
+    pub fn render(
+        &self,
+        llr: &mut LLR,
+        theme: &Theme,
+        area: Rect<u16>,
+    ) {
+        if area.height == 0 {
+            return;
+        }
+
+        let background = theme.get_panel_background();
+        for y in 0..area.height {
+            clear_row(llr, Point::new(area.x, area.y + y), area.width, background);
+        }
+
+        let title =
+            format!("Announcements, {} and above. PgUp/PgDn scroll, F filter.", self.filter.name());
+        draw_text(
+            llr,
+            area.point(),
+            &title,
+            theme.get_panel_title_colour(),
+            background,
+            area.width,
+        );
+
+        let num_lines = (area.height - 1) as usize;
+        let shown = self.shown();
+        let end = shown.len().saturating_sub(self.scroll);
+        let start = end.saturating_sub(num_lines);
+
+        for (i, event) in shown[start..end].iter().enumerate() {
+            let line = format!("[{}] {}", event.tick, event.event.describe());
+            let pos = Point::new(area.x, area.y + 1 + i as u16);
+
+            draw_text(
+                llr,
+                pos,
+                &line,
+                theme.get_severity_colour(event.severity),
+                background,
+                area.width,
+            );
+        }
+    }
+
+    /// Returns the announcements which pass the filter, oldest first.
+    fn shown(&self) -> Vec<&TimedEvent> {
+        self.history.iter().filter(|event| event.severity >= self.filter).collect()
+    }
+
+    fn num_shown(&self) -> usize {
+        self.shown().len()
+    }
+}
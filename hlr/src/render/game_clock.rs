@@ -0,0 +1,167 @@
+use std::time::Duration;
+use std::time::Instant;
+
+/// How long one tick of the game lasts, at normal speed, in milliseconds.
+const TICK_MILLIS: u64 = 100;
+
+/// The speeds the game can run at, as multiples of normal speed.
+const SPEEDS: [u32; 4] = [1, 2, 5, 10];
+
+/// The most ticks run between two frames.
+///
+/// If the game falls further behind than this, the rest is dropped, rather
+/// than trying to catch up and falling even further behind.
+const MAX_TICKS_PER_FRAME: u32 = 20;
+
+/// Decides when the game should tick, independently of the player's input.
+///
+/// Real time builds up as the game runs, and is spent in fixed sized steps of
+/// one tick each. Running faster spends it quicker.
+pub struct GameClock {
+    /// If the game is stopped.
+    paused: bool,
+
+    /// The current speed, as an index into `SPEEDS`.
+    speed: usize,
+
+    /// Time which has built up, but hasn't been spent on ticks yet.
+    /// This is already multiplied by the speed.
+    pending: Duration,
+
+    /// When the clock was last checked.
+    last_update: Instant,
+}
+
+impl GameClock {
+    /// Creates a clock running at normal speed, starting from the time given.
+    pub fn new(now: Instant) -> GameClock {
+        GameClock {
+            paused: false,
+            speed: 0,
+            pending: Duration::from_millis(0),
+            last_update: now,
+        }
+    }
+
+    /// Stops the game, or starts it again if it's already stopped.
+    pub fn toggle_pause(&mut self) {
+        self.paused = !self.paused;
+    }
+
+    /// Moves up to the next speed, if it isn't already at the fastest.
+    pub fn faster(&mut self) {
+        self.speed = (self.speed + 1).min(SPEEDS.len() - 1);
+    }
+
+    /// Moves down to the previous speed, if it isn't already at the slowest.
+    pub fn slower(&mut self) {
+        self.speed = self.speed.saturating_sub(1);
+    }
+
+    /// Returns how many times faster than normal the game is running.
+    pub fn speed(&self) -> u32 {
+        SPEEDS[self.speed]
+    }
+
+    /// Describes the speed, for showing to the player.
+    /// i.e. 'speed x2'.
+    pub fn describe(&self) -> String {
+        if self.paused {
+            "paused".to_string()
+        } else {
+            format!("speed x{}", self.speed())
+        }
+    }
+
+    /// Returns how many ticks should be run, now it's the time given.
+    ///
+    /// Nothing builds up whilst the game is paused.
+    pub fn ticks_due(
+        &mut self,
+        now: Instant,
+    ) -> u32 {
+        let elapsed = now.saturating_duration_since(self.last_update);
+        self.last_update = now;
+
+        if self.paused {
+            return 0;
+        }
+
+        let tick = Duration::from_millis(TICK_MILLIS);
+        self.pending += elapsed * self.speed();
+
+        let mut ticks = 0;
+        while self.pending >= tick {
+            if ticks == MAX_TICKS_PER_FRAME {
+                self.pending = Duration::from_millis(0);
+                break;
+            }
+
+            self.pending -= tick;
+            ticks += 1;
+        }
+
+        ticks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn paused_clocks_never_tick() {
+        let start = Instant::now();
+        let mut clock = GameClock::new(start);
+        clock.toggle_pause();
+
+        assert_eq!(clock.ticks_due(start + millis(1_000)), 0);
+        assert_eq!(clock.describe(), "paused");
+
+        clock.toggle_pause();
+        assert_eq!(clock.ticks_due(start + millis(1_000)), 0);
+        assert_eq!(clock.ticks_due(start + millis(1_300)), 3);
+    }
+
+    #[test]
+    fn faster_speeds_tick_more() {
+        let start = Instant::now();
+        let mut clock = GameClock::new(start);
+        clock.faster();
+        assert_eq!(clock.ticks_due(start + millis(500)), 10);
+
+        clock.faster();
+        clock.faster();
+        clock.faster();
+        assert_eq!(clock.speed(), 10);
+        assert_eq!(clock.ticks_due(start + millis(600)), 10);
+
+        clock.slower();
+        assert_eq!(clock.speed(), 5);
+        assert_eq!(clock.ticks_due(start + millis(800)), 10);
+    }
+
+    #[test]
+    fn long_stalls_are_not_caught_up() {
+        let start = Instant::now();
+        let mut clock = GameClock::new(start);
+
+        assert_eq!(clock.ticks_due(start + millis(60_000)), MAX_TICKS_PER_FRAME);
+        assert_eq!(clock.ticks_due(start + millis(60_100)), 1);
+    }
+
+    #[test]
+    fn leftover_time_carries_over() {
+        let start = Instant::now();
+        let mut clock = GameClock::new(start);
+
+        assert_eq!(clock.ticks_due(start + millis(150)), 1);
+        assert_eq!(clock.ticks_due(start + millis(200)), 1);
+        assert_eq!(clock.ticks_due(start + millis(260)), 0);
+        assert_eq!(clock.ticks_due(start + millis(300)), 1);
+    }
+}
@@ -0,0 +1,189 @@
+use game::creatures::CreatureId;
+use game::jobs::Labour;
+use game::Game;
+use game::GameCommand;
+use llr::LLR;
+use render::text::clear_row;
+use render::text::draw_text;
+use theme::Theme;
+use util::shapes::Point;
+use util::shapes::Rect;
+
+/// How wide the column of names is, on the left.
+const NAME_WIDTH: u16 = 24;
+
+/// How wide each column of labours is.
+const LABOUR_WIDTH: u16 = 13;
+
+/// A screen showing which labours each citizen will do, as a grid.
+pub struct LaboursScreen {
+    /// The index of the selected citizen.
+    selected: usize,
+
+    /// The index of the selected labour, in `Labour::ALL`.
+    labour: usize,
+}
+
+impl LaboursScreen {
+    /// Trivial constructor.
+    pub fn new() -> LaboursScreen {
+        LaboursScreen {
+            selected: 0,
+            labour: 0,
+        }
+    }
+
+    /// Selects the citizen above the current one.
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Selects the citizen below the current one.
+    pub fn select_next(&mut self) {
+        self.selected += 1;
+    }
+
+    /// Selects the labour to the left of the current one.
+    pub fn select_previous_labour(&mut self) {
+        self.labour = self.labour.saturating_sub(1);
+    }
+
+    /// Selects the labour to the right of the current one.
+    pub fn select_next_labour(&mut self) {
+        self.labour = (self.labour + 1).min(Labour::ALL.len() - 1);
+    }
+
+    /// Returns the command to flip the selected labour, for the selected
+    /// citizen. Returns nothing if there are no citizens.
+    pub fn toggle(
+        &mut self,
+        game: &Game,
+    ) -> Option<GameCommand> {
+        let id = self.selected(game)?;
+        let labour = Labour::ALL[self.labour];
+        let citizen = game.citizens().get(id)?;
+
+        Some(GameCommand::SetLabour {
+            creature: id,
+            labour: labour,
+            enabled: !citizen.labours.is_enabled(labour),
+        })
+    }
+
+    /// Returns the id of the selected citizen, if there are any citizens.
+    fn selected(
+        &mut self,
+        game: &Game,
+    ) -> Option<CreatureId> {
+        let citizens = game.citizens();
+        if citizens.is_empty() {
+            return None;
+        }
+
+        self.selected = self.selected.min(citizens.len() - 1);
+        citizens.iter().nth(self.selected).map(|(&id, _)| id)
+    }
+
+    /// Draws the grid into the area given.
+    ///
+    /// Citizens go down the left, and labours go across the top. Each cell
+    /// shows the citizen's skill in that labour, and if it's turned on.
+    pub fn render(
+        &mut self,
+        llr: &mut LLR,
+        theme: &Theme,
+        game: &Game,
+        area: Rect<u16>,
+    ) {
+        let background = theme.get_panel_background();
+        for y in 0..area.height {
+            clear_row(llr, Point::new(area.x, area.y + y), area.width, background);
+        }
+
+        if area.height < 2 {
+            return;
+        }
+
+        let selected = self.selected(game);
+        draw_text(
+            llr,
+            area.point(),
+            "Labours. L close, arrows select, Space toggle.",
+            theme.get_panel_title_colour(),
+            background,
+            area.width,
+        );
+
+        for (column, labour) in Labour::ALL.iter().enumerate() {
+            let x = NAME_WIDTH + column as u16 * LABOUR_WIDTH;
+            if x >= area.width {
+                break;
+            }
+
+            let colour = if column == self.labour {
+                theme.get_selected_colour()
+            } else {
+                theme.get_text_colour()
+            };
+
+            let pos = Point::new(area.x + x, area.y + 1);
+            draw_text(
+                llr,
+                pos,
+                labour.name(),
+                colour,
+                background,
+                (area.width - x).min(LABOUR_WIDTH),
+            );
+        }
+
+        let num_rows = (area.height - 2) as usize;
+        let first = self.selected.saturating_sub(num_rows.saturating_sub(1));
+
+        for (row, (&id, citizen)) in game.citizens().iter().skip(first).take(num_rows).enumerate() {
+            let creature = match game.get_creature(id) {
+                Some(creature) => creature,
+                None => continue,
+            };
+
+            let y = area.y + 2 + row as u16;
+            let is_selected = Some(id) == selected;
+            let name_colour = if is_selected {
+                theme.get_selected_colour()
+            } else {
+                theme.get_text_colour()
+            };
+
+            draw_text(
+                llr,
+                Point::new(area.x, y),
+                &creature.name,
+                name_colour,
+                background,
+                NAME_WIDTH.min(area.width),
+            );
+
+            for (column, &labour) in Labour::ALL.iter().enumerate() {
+                let x = NAME_WIDTH + column as u16 * LABOUR_WIDTH;
+                if x >= area.width {
+                    break;
+                }
+
+                let mark = if citizen.labours.is_enabled(labour) {
+                    'x'
+                } else {
+                    ' '
+                };
+                let cell = format!("[{}] {}", mark, creature.skills.level(labour.skill()));
+                let colour = if is_selected && column == self.labour {
+                    theme.get_selected_colour()
+                } else {
+                    theme.get_text_colour()
+                };
+
+                let pos = Point::new(area.x + x, y);
+                draw_text(llr, pos, &cell, colour, background, (area.width - x).min(LABOUR_WIDTH));
+            }
+        }
+    }
+}
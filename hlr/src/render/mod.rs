@@ -1,6 +1,14 @@
 pub mod run;
 
+mod announcements;
+mod game_clock;
+mod labours;
 mod render_game;
+mod roster;
+mod screen;
+mod status;
+mod text;
+mod trade;
 
 mod camera;
 mod cursor;
@@ -1,10 +1,24 @@
+use render::announcements::AnnouncementPanel;
 use render::camera::Camera;
+use render::game_clock::GameClock;
+use render::labours::LaboursScreen;
+use render::roster::RosterScreen;
+use render::screen::Screen;
+use render::status::render_status;
+use render::text::char_to_str;
+use render::text::draw_text;
+use render::trade::TradeScreen;
 use theme::Theme;
 
+use game::buildings::Building;
+use game::creatures::Creature;
 use game::Game;
 use game::GameTile;
+use game::Visibility;
 
 use util::shapes::Point;
+use util::shapes::Rect;
+use util::shapes::Size;
 
 use llr::LLR;
 
@@ -12,26 +26,89 @@ pub struct RenderGame<'a> {
     /// How we get visual setup information.
     theme: &'a Theme,
 
-    /// The game state we are using for rendering.
-    game: &'a Game<'a>,
-
     /// The camera whilst drawing.
     camera: Camera,
+
+    /// Lists what has been happening in the game.
+    announcements: AnnouncementPanel,
+
+    /// Which screen is shown above the announcements.
+    screen: Screen,
+
+    /// Lists the citizens of the fortress.
+    roster: RosterScreen,
+
+    /// Lists which labours each citizen will do.
+    labours: LaboursScreen,
+
+    /// Trading with merchants.
+    trade: TradeScreen,
 }
 
+/// How many rows at the bottom of the screen are for announcements.
+const ANNOUNCEMENTS_HEIGHT: u16 = 6;
+
 impl<'a> RenderGame<'a> {
-    pub fn new(
-        game: &'a Game,
-        theme: &'a Theme,
-    ) -> RenderGame<'a> {
+    pub fn new(theme: &'a Theme) -> RenderGame<'a> {
         RenderGame {
             theme: theme,
-            game: game,
             // camera: Camera::new(game.player.position.to_clamped::<i32>()),
             camera: Camera::new(Point::new(0, 0)),
+            announcements: AnnouncementPanel::new(),
+            screen: Screen::Map,
+            roster: RosterScreen::new(),
+            labours: LaboursScreen::new(),
+            trade: TradeScreen::new(),
+        }
+    }
+
+    /// Takes the latest events out of the game, ready for showing.
+    pub fn update(
+        &mut self,
+        game: &mut Game,
+    ) {
+        for event in game.drain_events() {
+            self.announcements.push(event);
         }
     }
 
+    /// The panel of announcements, for scrolling and filtering.
+    pub fn announcements(&mut self) -> &mut AnnouncementPanel {
+        &mut self.announcements
+    }
+
+    /// Returns which screen is being shown.
+    pub fn screen(&self) -> Screen {
+        self.screen
+    }
+
+    /// Shows the screen given, or goes back to the map if it's already shown.
+    pub fn toggle_screen(
+        &mut self,
+        screen: Screen,
+    ) {
+        self.screen = if self.screen == screen {
+            Screen::Map
+        } else {
+            screen
+        };
+    }
+
+    /// The roster of citizens, for changing who is selected.
+    pub fn roster(&mut self) -> &mut RosterScreen {
+        &mut self.roster
+    }
+
+    /// The labours screen, for changing who does what.
+    pub fn labours(&mut self) -> &mut LaboursScreen {
+        &mut self.labours
+    }
+
+    /// The trade screen, for picking out a deal.
+    pub fn trade(&mut self) -> &mut TradeScreen {
+        &mut self.trade
+    }
+
     pub fn move_camera(
         &mut self,
         x: i32,
@@ -43,32 +120,121 @@ impl<'a> RenderGame<'a> {
     pub fn render(
         &mut self,
         llr: &mut LLR,
+        game: &Game,
+        clock: &GameClock,
     ) {
+        let size = llr.size();
+        let panel_height = ANNOUNCEMENTS_HEIGHT.min(size.height);
+        let map_size = Size::new(size.width, size.height - panel_height);
+        let panel_area = Rect::new(0, map_size.height, size.width, panel_height);
+
         llr.clear();
-        self.render_game(llr);
+        match self.screen {
+            Screen::Map => self.render_game(llr, game, map_size),
+            Screen::Roster => {
+                let area = Rect::new(0, 0, map_size.width, map_size.height);
+
+                self.roster.render(llr, self.theme, game, area);
+            },
+            Screen::Labours => {
+                let area = Rect::new(0, 0, map_size.width, map_size.height);
+
+                self.labours.render(llr, self.theme, game, area);
+            },
+            Screen::Status => {
+                let area = Rect::new(0, 0, map_size.width, map_size.height);
+
+                render_status(llr, self.theme, game, area);
+            },
+            Screen::Trade => {
+                let area = Rect::new(0, 0, map_size.width, map_size.height);
+
+                self.trade.render(llr, self.theme, game, area);
+            },
+        }
+        self.announcements.render(llr, self.theme, panel_area);
+        self.render_speed(llr, clock, panel_area);
         llr.finished_drawing();
     }
 
+    /// Shows how fast the game is running, in the top right of the area.
+    fn render_speed(
+        &mut self,
+        llr: &mut LLR,
+        clock: &GameClock,
+        area: Rect<u16>,
+    ) {
+        let text = format!("{}. Space pause, +/- speed.", clock.describe());
+        let width = text.len() as u16;
+
+        if area.height == 0 || width > area.width {
+            return;
+        }
+
+        draw_text(
+            llr,
+            Point::new(area.x + area.width - width, area.y),
+            &text,
+            self.theme.get_panel_title_colour(),
+            self.theme.get_panel_background(),
+            width,
+        );
+    }
+
     pub fn render_game(
         &mut self,
         llr: &mut LLR,
+        game: &Game,
+        view_size: Size<u16>,
     ) {
         let camera_pos = self.camera.position();
-        let llr_size = llr.size().to::<i32>();
-        let top_left = camera_pos - llr_size / 2;
-        let area = top_left.combine(llr_size);
+        let view_size = view_size.to::<i32>();
+        let top_left = camera_pos - view_size / 2;
+        let area = top_left.combine(view_size);
 
-        for (tile, tile_pos) in
-            self.game.slice(area.x, area.y, area.width as u32, area.height as u32)
-        {
+        for (tile, tile_pos) in game.slice(area.x, area.y, area.width as u32, area.height as u32) {
             let pos = tile_pos.to_clamped::<i32>() - top_left;
-            if 0 <= pos.x && 0 <= pos.y {
-                self.tile(llr, tile, pos.to_clamped::<u16>());
+            if 0 <= pos.x && 0 <= pos.y && pos.x < view_size.width && pos.y < view_size.height {
+                let visibility = game.get_visibility(tile_pos.x, tile_pos.y);
+                let light_level = game.get_light_level(tile_pos.x, tile_pos.y);
+                self.tile(llr, tile, visibility, light_level, pos.to_clamped::<u16>());
+            }
+        }
+
+        for building in game.buildings().iter() {
+            let pos = building.position.to_clamped::<i32>() - top_left;
+            let visibility = game.get_visibility(building.position.x, building.position.y);
+
+            if visibility != Visibility::Hidden
+                && 0 <= pos.x
+                && 0 <= pos.y
+                && pos.x < view_size.width
+                && pos.y < view_size.height
+            {
+                self.building(llr, game, building, pos.to_clamped::<u16>());
+            }
+        }
+
+        for creature in game.creatures().iter() {
+            let pos = creature.position.to_clamped::<i32>() - top_left;
+            let visibility = game.get_visibility(creature.position.x, creature.position.y);
+
+            if visibility == Visibility::Visible
+                && 0 <= pos.x
+                && 0 <= pos.y
+                && pos.x < view_size.width
+                && pos.y < view_size.height
+            {
+                self.creature(llr, creature, pos.to_clamped::<u16>());
             }
         }
 
-        let player_pos = self.game.player.position.to_clamped::<i32>() - top_left;
-        if 0 <= player_pos.x && 0 <= player_pos.y {
+        let player_pos = game.player.position.to_clamped::<i32>() - top_left;
+        if 0 <= player_pos.x
+            && 0 <= player_pos.y
+            && player_pos.x < view_size.width
+            && player_pos.y < view_size.height
+        {
             self.player(llr, player_pos.to_clamped::<u16>());
         }
     }
@@ -83,13 +249,46 @@ impl<'a> RenderGame<'a> {
         llr.pixel(colour, draw_pos);
     }
 
+    fn building(
+        &mut self,
+        llr: &mut LLR,
+        game: &Game,
+        building: &Building,
+        draw_pos: Point<u16>,
+    ) {
+        // Farm plots show whatever is growing on them.
+        let pixel = match game.farms().growth_at(building.position) {
+            Some((crop, stage)) => self.theme.get_crop(stage, char_to_str(crop.symbol)),
+            None => self.theme.get_building(building.kind),
+        };
+
+        let _ = llr.pixel(pixel, draw_pos);
+    }
+
+    fn creature(
+        &mut self,
+        llr: &mut LLR,
+        creature: &Creature,
+        draw_pos: Point<u16>,
+    ) {
+        let pixel = self.theme.get_creature(creature.faction, char_to_str(creature.symbol));
+
+        let _ = llr.pixel(pixel, draw_pos);
+    }
+
     fn tile(
         &mut self,
         llr: &mut LLR,
         tile: GameTile,
+        visibility: Visibility,
+        light_level: f32,
         draw_pos: Point<u16>,
     ) {
-        let colour = self.theme.get_game_tile(tile);
+        let colour = match visibility {
+            Visibility::Hidden => self.theme.get_hidden(),
+            Visibility::Remembered => self.theme.get_remembered_game_tile(tile),
+            Visibility::Visible => self.theme.get_lit_game_tile(tile, light_level),
+        };
 
         llr.pixel(colour, draw_pos);
     }
@@ -0,0 +1,179 @@
+use game::creatures::CreatureId;
+use game::Game;
+use llr::LLR;
+use render::text::clear_row;
+use render::text::draw_text;
+use theme::Theme;
+use util::shapes::Point;
+use util::shapes::Rect;
+
+/// How wide the list of names is, on the left.
+const LIST_WIDTH: u16 = 28;
+
+/// A screen listing every citizen, with the details of one of them.
+pub struct RosterScreen {
+    /// The index of the citizen whose details are shown.
+    selected: usize,
+}
+
+impl RosterScreen {
+    /// Trivial constructor.
+    pub fn new() -> RosterScreen {
+        RosterScreen {
+            selected: 0,
+        }
+    }
+
+    /// Selects the citizen above the current one.
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Selects the citizen below the current one.
+    pub fn select_next(&mut self) {
+        self.selected += 1;
+    }
+
+    /// Returns the id of the selected citizen, if there are any citizens.
+    pub fn selected(
+        &mut self,
+        game: &Game,
+    ) -> Option<CreatureId> {
+        let citizens = game.citizens();
+        if citizens.is_empty() {
+            return None;
+        }
+
+        self.selected = self.selected.min(citizens.len() - 1);
+        citizens.iter().nth(self.selected).map(|(&id, _)| id)
+    }
+
+    /// Draws the roster into the area given.
+    ///
+    /// Names are listed down the left, and the selected citizen's details are
+    /// on the right.
+    pub fn render(
+        &mut self,
+        llr: &mut LLR,
+        theme: &Theme,
+        game: &Game,
+        area: Rect<u16>,
+    ) {
+        let background = theme.get_panel_background();
+        for y in 0..area.height {
+            clear_row(llr, Point::new(area.x, area.y + y), area.width, background);
+        }
+
+        if area.height == 0 {
+            return;
+        }
+
+        let selected = self.selected(game);
+        let title =
+            format!("Citizens, {} in total. R close, Up/Down select.", game.citizens().len());
+        draw_text(
+            llr,
+            area.point(),
+            &title,
+            theme.get_panel_title_colour(),
+            background,
+            area.width,
+        );
+
+        let list_width = LIST_WIDTH.min(area.width);
+        let num_rows = (area.height - 1) as usize;
+        let first = self.selected.saturating_sub(num_rows.saturating_sub(1));
+
+        for (row, (&id, _)) in game.citizens().iter().skip(first).take(num_rows).enumerate() {
+            let name = match game.get_creature(id) {
+                Some(creature) => creature.name.as_str(),
+                None => continue,
+            };
+
+            let colour = if Some(id) == selected {
+                theme.get_selected_colour()
+            } else {
+                theme.get_text_colour()
+            };
+
+            let pos = Point::new(area.x, area.y + 1 + row as u16);
+            draw_text(llr, pos, name, colour, background, list_width);
+        }
+
+        if let Some(id) = selected {
+            let details = Rect::new(
+                area.x + list_width + 1,
+                area.y + 1,
+                area.width.saturating_sub(list_width + 1),
+                area.height - 1,
+            );
+
+            self.render_details(llr, theme, game, id, details);
+        }
+    }
+
+    /// Draws everything we know about a single citizen.
+    fn render_details(
+        &self,
+        llr: &mut LLR,
+        theme: &Theme,
+        game: &Game,
+        id: CreatureId,
+        area: Rect<u16>,
+    ) {
+        let creature = match game.get_creature(id) {
+            Some(creature) => creature,
+            None => return,
+        };
+
+        let mut lines = vec![creature.name.clone(), String::new()];
+
+        if game.citizens().is_arriving(id) {
+            lines.push("Arriving at the fortress.".to_string());
+        }
+
+        lines.push(format!("Blood: {:.0}%", creature.body.blood_fraction() * 100.0));
+        if !creature.body.can_walk() {
+            lines.push("Cannot walk.".to_string());
+        }
+
+        lines.push(String::new());
+        lines.push("Skills:".to_string());
+        for (skill, level) in creature.skills.iter() {
+            lines.push(format!("  {} {}", skill.name(), level));
+        }
+
+        if let Some(citizen) = game.citizens().get(id) {
+            let traits = citizen.personality.describe();
+
+            lines.push(String::new());
+            if traits.is_empty() {
+                lines.push("Personality: unremarkable".to_string());
+            } else {
+                lines.push(format!("Personality: {}", traits.join(", ")));
+            }
+
+            lines.push(format!("Mood: {} ({})", citizen.mood().name(), citizen.mood_value()));
+            if let Some(breakdown) = citizen.breakdown {
+                lines.push(format!("In a {}.", breakdown.kind.name()));
+            }
+
+            lines.push(String::new());
+            lines.push("Recent thoughts:".to_string());
+            if citizen.thoughts.is_empty() {
+                lines.push("  nothing in particular".to_string());
+            }
+
+            for thought in citizen.thoughts.iter().rev() {
+                lines.push(format!("  {} ({:+})", thought.kind.describe(), thought.strength()));
+            }
+        }
+
+        let background = theme.get_panel_background();
+        for (row, line) in lines.iter().take(area.height as usize).enumerate() {
+            let pos = Point::new(area.x, area.y + row as u16);
+
+            draw_text(llr, pos, line, theme.get_text_colour(), background, area.width);
+        }
+    }
+}
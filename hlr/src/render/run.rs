@@ -7,71 +7,283 @@ use llr::LLROptions;
 use llr::LLRTerminal;
 
 use render::cursor::Cursor;
+use render::game_clock::GameClock;
 use render::render_game::RenderGame;
+use render::screen::Screen;
 
+use game::buildings::BuildingKind;
+use game::jobs::JobKind;
 use game::Game;
+use game::GameCommand;
 
 use util::shapes::Point;
 
-pub fn run<'a>(
+use std::thread;
+use std::time::Duration;
+use std::time::Instant;
+
+/// The most frames drawn each second.
+const MAX_FRAMES_PER_SECOND: u64 = 30;
+
+/// Runs the game until the player quits.
+///
+/// Each frame takes in the player's input, ticks the game as many times as
+/// the clock says are due, and then draws it all. Frames are capped to
+/// `MAX_FRAMES_PER_SECOND`.
+pub fn run(
     setup: LLROptions,
-    game: &'a Game,
+    game: &mut Game,
 ) {
     let theme = Theme::new();
     let mut llr = LLRTerminal::new(setup);
-    let mut rgame = RenderGame::new(&game, &theme);
+    let mut rgame = RenderGame::new(&theme);
     let mut cursor =
         Cursor::new(setup.window_size.width as f32 / 2.0, setup.window_size.height as f32 / 2.0);
 
+    let frame_duration = Duration::from_millis(1000 / MAX_FRAMES_PER_SECOND);
+    let mut clock = GameClock::new(Instant::now());
+
     llr.on_start();
-    rgame.render(&mut llr);
+    rgame.render(&mut llr, game, &clock);
 
     'main: loop {
-        match llr.poll() {
-            Some(ev) => {
-                match ev {
-                    LLREvent::Quit => {
-                        break 'main;
-                    },
-
-                    // User Input
-                    //
-                    LLREvent::KeyPress(key) => {
-                        match key {
-                            LLRKey::Escape => {
-                                break 'main;
-                            },
-
-                            LLRKey::Left | LLRKey::A => {
-                                rgame.move_camera(-1, 0);
-                            },
-
-                            LLRKey::Right | LLRKey::D => {
-                                rgame.move_camera(1, 0);
-                            },
-
-                            LLRKey::Up | LLRKey::W => {
-                                rgame.move_camera(0, -1);
-                            },
-
-                            LLRKey::Down | LLRKey::S => {
-                                rgame.move_camera(0, 1);
-                            },
-
-                            _ => {},
-                        };
-                    },
-
-                    // do nothing
-                    _ => {},
-                };
-            },
-
-            _ => {},
-        };
-
-        // Re-render everything after each event.
-        rgame.render(&mut llr)
+        let frame_start = Instant::now();
+
+        while let Some(ev) = llr.poll() {
+            match ev {
+                LLREvent::Quit => {
+                    break 'main;
+                },
+
+                // User Input
+                //
+                LLREvent::KeyPress(key) => {
+                    match key {
+                        LLRKey::Escape => {
+                            break 'main;
+                        },
+
+                        // On the labours screen, arrows pick a citizen and
+                        // a labour, and space turns it on or off.
+                        LLRKey::Up if rgame.screen() == Screen::Labours => {
+                            rgame.labours().select_previous();
+                        },
+
+                        LLRKey::Down if rgame.screen() == Screen::Labours => {
+                            rgame.labours().select_next();
+                        },
+
+                        LLRKey::Left if rgame.screen() == Screen::Labours => {
+                            rgame.labours().select_previous_labour();
+                        },
+
+                        LLRKey::Right if rgame.screen() == Screen::Labours => {
+                            rgame.labours().select_next_labour();
+                        },
+
+                        LLRKey::Space if rgame.screen() == Screen::Labours => {
+                            if let Some(command) = rgame.labours().toggle(game) {
+                                game.apply(command);
+                            }
+                        },
+
+                        // On the trade screen, arrows pick goods, space
+                        // marks them, and enter offers the deal.
+                        LLRKey::Up if rgame.screen() == Screen::Trade => {
+                            rgame.trade().select_previous();
+                        },
+
+                        LLRKey::Down if rgame.screen() == Screen::Trade => {
+                            rgame.trade().select_next();
+                        },
+
+                        LLRKey::Left if rgame.screen() == Screen::Trade => {
+                            rgame.trade().select_fortress();
+                        },
+
+                        LLRKey::Right if rgame.screen() == Screen::Trade => {
+                            rgame.trade().select_merchants();
+                        },
+
+                        LLRKey::Space if rgame.screen() == Screen::Trade => {
+                            rgame.trade().toggle();
+                        },
+
+                        LLRKey::Enter if rgame.screen() == Screen::Trade => {
+                            if let Some(command) = rgame.trade().offer() {
+                                game.apply(command);
+                            }
+                        },
+
+                        // Time.
+                        LLRKey::Space => {
+                            clock.toggle_pause();
+                        },
+
+                        LLRKey::Plus => {
+                            clock.faster();
+                        },
+
+                        LLRKey::Minus => {
+                            clock.slower();
+                        },
+
+                        // Arrows move the player.
+                        LLRKey::Left => {
+                            game.apply(GameCommand::MovePlayer {
+                                x: -1,
+                                y: 0,
+                            });
+                        },
+
+                        LLRKey::Right => {
+                            game.apply(GameCommand::MovePlayer {
+                                x: 1,
+                                y: 0,
+                            });
+                        },
+
+                        // Up and down pick a citizen on the roster.
+                        LLRKey::Up if rgame.screen() == Screen::Roster => {
+                            rgame.roster().select_previous();
+                        },
+
+                        LLRKey::Down if rgame.screen() == Screen::Roster => {
+                            rgame.roster().select_next();
+                        },
+
+                        LLRKey::Up => {
+                            game.apply(GameCommand::MovePlayer {
+                                x: 0,
+                                y: -1,
+                            });
+                        },
+
+                        LLRKey::Down => {
+                            game.apply(GameCommand::MovePlayer {
+                                x: 0,
+                                y: 1,
+                            });
+                        },
+
+                        // Screens.
+                        LLRKey::R => {
+                            rgame.toggle_screen(Screen::Roster);
+                        },
+
+                        LLRKey::L => {
+                            rgame.toggle_screen(Screen::Labours);
+                        },
+
+                        LLRKey::Z => {
+                            rgame.toggle_screen(Screen::Status);
+                        },
+
+                        LLRKey::T => {
+                            rgame.toggle_screen(Screen::Trade);
+                        },
+
+                        // Places a trade depot where the player is stood.
+                        LLRKey::B => {
+                            let position = game.player.position;
+
+                            game.apply(GameCommand::QueueJob {
+                                kind: JobKind::Erect {
+                                    building: BuildingKind::TradeDepot,
+                                    x: position.x,
+                                    y: position.y,
+                                },
+                            });
+                        },
+
+                        // Places a farm plot where the player is stood.
+                        LLRKey::P => {
+                            let position = game.player.position;
+
+                            game.apply(GameCommand::QueueJob {
+                                kind: JobKind::Erect {
+                                    building: BuildingKind::FarmPlot,
+                                    x: position.x,
+                                    y: position.y,
+                                },
+                            });
+                        },
+
+                        // Picks the next crop to grow this season, on the
+                        // farm plot the player is stood on.
+                        LLRKey::C => {
+                            let position = game.player.position;
+                            let season = game.get_season();
+
+                            if let Some(plot) = game.farms().plot(position) {
+                                let crops = game.growable_crops(position, season);
+                                let current = plot
+                                    .crop_for(season)
+                                    .and_then(|crop| crops.iter().position(|&c| c == crop));
+                                let next = match current {
+                                    Some(i) => crops.get(i + 1).cloned(),
+                                    None => crops.first().cloned(),
+                                };
+
+                                game.apply(GameCommand::SetCrop {
+                                    x: position.x,
+                                    y: position.y,
+                                    season: season,
+                                    crop: next,
+                                });
+                            }
+                        },
+
+                        // Announcements.
+                        LLRKey::PageUp => {
+                            rgame.announcements().scroll_up();
+                        },
+
+                        LLRKey::PageDown => {
+                            rgame.announcements().scroll_down();
+                        },
+
+                        LLRKey::F => {
+                            rgame.announcements().cycle_filter();
+                        },
+
+                        // WASD moves the camera.
+                        LLRKey::A => {
+                            rgame.move_camera(-1, 0);
+                        },
+
+                        LLRKey::D => {
+                            rgame.move_camera(1, 0);
+                        },
+
+                        LLRKey::W => {
+                            rgame.move_camera(0, -1);
+                        },
+
+                        LLRKey::S => {
+                            rgame.move_camera(0, 1);
+                        },
+
+                        _ => {},
+                    };
+                },
+
+                // do nothing
+                _ => {},
+            };
+        }
+
+        for _ in 0..clock.ticks_due(Instant::now()) {
+            game.tick();
+        }
+
+        rgame.update(game);
+        rgame.render(&mut llr, game, &clock);
+
+        let elapsed = frame_start.elapsed();
+        if elapsed < frame_duration {
+            thread::sleep(frame_duration - elapsed);
+        }
     }
 
     llr.on_quit();
@@ -0,0 +1,18 @@
+/// Which screen is being shown, above the announcements.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Screen {
+    /// The map, with everything on it.
+    Map,
+
+    /// The list of citizens.
+    Roster,
+
+    /// Which labours each citizen will do.
+    Labours,
+
+    /// An overview of the whole fortress.
+    Status,
+
+    /// Trading with merchants at the depot.
+    Trade,
+}
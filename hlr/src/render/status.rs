@@ -0,0 +1,69 @@
+use game::Game;
+use llr::LLR;
+use render::text::clear_row;
+use render::text::draw_text;
+use theme::Theme;
+use util::shapes::Point;
+use util::shapes::Rect;
+
+/// Draws an overview of the whole fortress into the area given.
+///
+/// How many citizens there are, how they are feeling, what there is to do,
+/// and how much the fortress is worth.
+pub fn render_status(
+    llr: &mut LLR,
+    theme: &Theme,
+    game: &Game,
+    area: Rect<u16>,
+) {
+    let background = theme.get_panel_background();
+    for y in 0..area.height {
+        clear_row(llr, Point::new(area.x, area.y + y), area.width, background);
+    }
+
+    if area.height == 0 {
+        return;
+    }
+
+    draw_text(
+        llr,
+        area.point(),
+        "Fortress status. Z close.",
+        theme.get_panel_title_colour(),
+        background,
+        area.width,
+    );
+
+    let weather = game.weather();
+    let here = game.weather_at(game.player.position.x, game.player.position.y);
+    let mut lines = vec![
+        game.format_date(),
+        format!(
+            "Outside: {:.0}C, {}",
+            weather.temperature.as_celcius(),
+            weather.weather.describe()
+        ),
+        format!("Here: {:.0}C", here.temperature.as_celcius()),
+        String::new(),
+        format!("Citizens: {}", game.citizens().len()),
+        format!("Happiness: {:.0}%", game.happiness() * 100.0),
+        format!("Jobs waiting: {}", game.jobs().len()),
+    ];
+
+    if game.is_under_siege() {
+        lines.push("The fortress is under siege!".to_string());
+    }
+
+    lines.push(String::new());
+    lines.push("Wealth:".to_string());
+    for (category, value) in game.wealth_by_category().iter() {
+        lines.push(format!("  {:<14}{:>8}", category.name(), value));
+    }
+    lines.push(format!("  {:<14}{:>8}", "total", game.wealth()));
+
+    for (row, line) in lines.iter().take(area.height as usize - 1).enumerate() {
+        let pos = Point::new(area.x, area.y + 1 + row as u16);
+
+        draw_text(llr, pos, line, theme.get_text_colour(), background, area.width);
+    }
+}
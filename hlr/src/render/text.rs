@@ -0,0 +1,63 @@
+use llr::LLRPixel;
+use llr::LLR;
+use util::colour::RGBA;
+use util::shapes::Point;
+
+/// Every printable ASCII character, in order.
+///
+/// Pixels need a static string for their character. Slicing into this gives
+/// us one for each character.
+const PRINTABLE: &str = " !\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`abcdefghijklmnopqrstuvwxyz{|}~";
+
+/// Returns a static string holding the character given.
+/// Anything which isn't printable ASCII comes back as a question mark.
+pub fn char_to_str(c: char) -> &'static str {
+    let code = c as u32;
+
+    if (32..127).contains(&code) {
+        let index = (code - 32) as usize;
+
+        &PRINTABLE[index..index + 1]
+    } else {
+        "?"
+    }
+}
+
+/// Draws the text, one character per pixel, going right from the position
+/// given. Anything beyond the max width is cut off.
+pub fn draw_text(
+    llr: &mut LLR,
+    pos: Point<u16>,
+    text: &str,
+    foreground: RGBA,
+    background: RGBA,
+    max_width: u16,
+) {
+    for (i, c) in text.chars().take(max_width as usize).enumerate() {
+        let pixel = LLRPixel {
+            character: char_to_str(c),
+            background: background,
+            foreground: foreground,
+        };
+
+        let _ = llr.pixel(pixel, Point::new(pos.x + i as u16, pos.y));
+    }
+}
+
+/// Fills a row with blank pixels of the colour given.
+pub fn clear_row(
+    llr: &mut LLR,
+    pos: Point<u16>,
+    width: u16,
+    background: RGBA,
+) {
+    let pixel = LLRPixel {
+        character: " ",
+        background: background,
+        foreground: background,
+    };
+
+    for x in 0..width {
+        let _ = llr.pixel(pixel, Point::new(pos.x + x, pos.y));
+    }
+}
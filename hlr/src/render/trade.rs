@@ -0,0 +1,269 @@
+use game::items::Item;
+use game::items::Quality;
+use game::trade::price;
+use game::Game;
+use game::GameCommand;
+use llr::LLR;
+use render::text::clear_row;
+use render::text::draw_text;
+use theme::Theme;
+use util::shapes::Point;
+use util::shapes::Rect;
+
+/// The most goods which can be listed on each side.
+/// This is how many fit into a trade mask.
+const MAX_GOODS: usize = 64;
+
+/// The two lists of goods on the trade screen.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Side {
+    /// The fortress' items, on the left.
+    Fortress,
+
+    /// The merchants' goods, on the right.
+    Merchants,
+}
+
+/// A screen for trading with merchants at the depot.
+///
+/// The fortress' items are on the left, and the merchants' goods on the
+/// right. Goods are marked on both sides, and then offered as a deal.
+pub struct TradeScreen {
+    /// Which list is being picked from.
+    side: Side,
+
+    /// The index of the selected item, in the fortress' list.
+    fortress_selected: usize,
+
+    /// The index of the selected item, in the merchants' list.
+    merchants_selected: usize,
+
+    /// The fortress' items marked for offering.
+    offered: u64,
+
+    /// The merchants' goods marked for asking for.
+    requested: u64,
+}
+
+impl TradeScreen {
+    /// Trivial constructor.
+    pub fn new() -> TradeScreen {
+        TradeScreen {
+            side: Side::Fortress,
+            fortress_selected: 0,
+            merchants_selected: 0,
+            offered: 0,
+            requested: 0,
+        }
+    }
+
+    /// Selects the item above the current one.
+    pub fn select_previous(&mut self) {
+        let selected = self.selected_mut();
+
+        *selected = selected.saturating_sub(1);
+    }
+
+    /// Selects the item below the current one.
+    pub fn select_next(&mut self) {
+        let selected = self.selected_mut();
+
+        *selected = (*selected + 1).min(MAX_GOODS - 1);
+    }
+
+    /// Switches to picking from the fortress' items.
+    pub fn select_fortress(&mut self) {
+        self.side = Side::Fortress;
+    }
+
+    /// Switches to picking from the merchants' goods.
+    pub fn select_merchants(&mut self) {
+        self.side = Side::Merchants;
+    }
+
+    /// Marks, or unmarks, the selected item.
+    pub fn toggle(&mut self) {
+        match self.side {
+            Side::Fortress => self.offered ^= 1 << self.fortress_selected,
+            Side::Merchants => self.requested ^= 1 << self.merchants_selected,
+        }
+    }
+
+    /// Returns the command offering the marked goods to the merchants, and
+    /// clears the marks. Returns nothing if nothing is marked.
+    pub fn offer(&mut self) -> Option<GameCommand> {
+        if self.offered == 0 && self.requested == 0 {
+            return None;
+        }
+
+        let command = GameCommand::Trade {
+            offered: self.offered,
+            requested: self.requested,
+        };
+
+        self.offered = 0;
+        self.requested = 0;
+
+        Some(command)
+    }
+
+    /// Draws the trade screen into the area given.
+    pub fn render(
+        &mut self,
+        llr: &mut LLR,
+        theme: &Theme,
+        game: &Game,
+        area: Rect<u16>,
+    ) {
+        let background = theme.get_panel_background();
+        for y in 0..area.height {
+            clear_row(llr, Point::new(area.x, area.y + y), area.width, background);
+        }
+
+        if area.height < 3 {
+            return;
+        }
+
+        draw_text(
+            llr,
+            area.point(),
+            "Trade. T close, arrows select, Space mark, Enter offer.",
+            theme.get_panel_title_colour(),
+            background,
+            area.width,
+        );
+
+        let caravan = match game.caravan().filter(|caravan| caravan.is_trading()) {
+            Some(caravan) => caravan,
+            None => {
+                let pos = Point::new(area.x, area.y + 1);
+                let text = "No merchants are at the trade depot.";
+
+                draw_text(llr, pos, text, theme.get_text_colour(), background, area.width);
+                return;
+            },
+        };
+
+        let tastes =
+            format!("They like {}, and dislike {}.", caravan.likes.name(), caravan.dislikes.name());
+        let summary = match game.evaluate_trade(self.offered, self.requested) {
+            Some(evaluation) => {
+                format!(
+                    "Offering {} for {}. They would {}.",
+                    evaluation.offered,
+                    evaluation.requested,
+                    if evaluation.accepted {
+                        "accept"
+                    } else {
+                        "refuse"
+                    }
+                )
+            },
+            None => String::new(),
+        };
+
+        let text_colour = theme.get_text_colour();
+        draw_text(
+            llr,
+            Point::new(area.x, area.y + 1),
+            &tastes,
+            text_colour,
+            background,
+            area.width,
+        );
+        draw_text(
+            llr,
+            Point::new(area.x, area.y + 2),
+            &summary,
+            text_colour,
+            background,
+            area.width,
+        );
+
+        let column_width = area.width / 2;
+        let lists = Rect::new(area.x, area.y + 4, column_width, area.height.saturating_sub(4));
+        let fortress = game.items().iter().take(MAX_GOODS).map(|item| (*item, item.value()));
+        let merchants = caravan.goods.iter().take(MAX_GOODS).map(|item| (*item, price(item)));
+
+        self.fortress_selected = self.fortress_selected.min(game.items().len().saturating_sub(1));
+        self.merchants_selected =
+            self.merchants_selected.min(caravan.goods.len().saturating_sub(1));
+
+        self.render_list(llr, theme, lists, "Fortress", fortress, Side::Fortress);
+        self.render_list(
+            llr,
+            theme,
+            Rect::new(area.x + column_width, lists.y, column_width, lists.height),
+            "Merchants",
+            merchants,
+            Side::Merchants,
+        );
+    }
+
+    /// Draws one side's list of goods, with their values.
+    fn render_list<I>(
+        &self,
+        llr: &mut LLR,
+        theme: &Theme,
+        area: Rect<u16>,
+        title: &str,
+        goods: I,
+        side: Side,
+    ) where
+        I: Iterator<Item = (Item, u32)>,
+    {
+        if area.height == 0 {
+            return;
+        }
+
+        let background = theme.get_panel_background();
+        let (selected, marked) = match side {
+            Side::Fortress => (self.fortress_selected, self.offered),
+            Side::Merchants => (self.merchants_selected, self.requested),
+        };
+
+        let title_colour = if side == self.side {
+            theme.get_selected_colour()
+        } else {
+            theme.get_panel_title_colour()
+        };
+        draw_text(llr, area.point(), title, title_colour, background, area.width);
+
+        let num_rows = (area.height - 1) as usize;
+        let first = selected.saturating_sub(num_rows.saturating_sub(1));
+
+        for (row, (i, (item, value))) in goods.enumerate().skip(first).take(num_rows).enumerate() {
+            let mark = if marked & (1 << i) != 0 {
+                'x'
+            } else {
+                ' '
+            };
+            let line = format!("[{}] {} ({})", mark, describe(&item), value);
+            let colour = if side == self.side && i == selected {
+                theme.get_selected_colour()
+            } else {
+                theme.get_text_colour()
+            };
+
+            let pos = Point::new(area.x, area.y + 1 + row as u16);
+            draw_text(llr, pos, &line, colour, background, area.width.saturating_sub(1));
+        }
+    }
+
+    /// The index of the selected item, on the side being picked from.
+    fn selected_mut(&mut self) -> &mut usize {
+        match self.side {
+            Side::Fortress => &mut self.fortress_selected,
+            Side::Merchants => &mut self.merchants_selected,
+        }
+    }
+}
+
+/// Describes the item, like 'superior table'.
+fn describe(item: &Item) -> String {
+    if item.quality == Quality::Ordinary {
+        item.kind.name().to_string()
+    } else {
+        format!("{} {}", item.quality.name(), item.kind.name())
+    }
+}
@@ -1,8 +1,17 @@
+use game::buildings::BuildingKind;
+use game::creatures::Faction;
+use game::events::Severity;
+use game::farming::GrowthStage;
 use game::GameTile;
 use llr::LLRPixel;
 use theme::colours;
+use util::colour::RGBA;
 use world::tiles::Tile;
 
+/// How bright remembered tiles are drawn, compared to tiles in view.
+/// From 0.0 (black) to 1.0 (unchanged).
+const REMEMBERED_BRIGHTNESS: f32 = 0.4;
+
 /// This holds the colour type.
 pub struct Theme {}
 
@@ -21,15 +30,174 @@ impl Theme {
         }
     }
 
+    /// Gets the pixel for drawing a creature, using the character given.
+    /// It's coloured by who the creature is loyal to.
+    pub fn get_creature(
+        &self,
+        faction: Faction,
+        character: &'static str,
+    ) -> LLRPixel {
+        let foreground = match faction {
+            Faction::Fortress => colours::LIGHT_CYAN,
+            Faction::Wild => colours::BROWN,
+            Faction::Hostile => colours::LIGHT_RED,
+            Faction::Visitor => colours::YELLOW,
+        };
+
+        LLRPixel {
+            character: character,
+            background: colours::BLACK,
+            foreground: foreground,
+        }
+    }
+
+    /// Gets the pixel for drawing a building.
+    pub fn get_building(
+        &self,
+        kind: BuildingKind,
+    ) -> LLRPixel {
+        match kind {
+            BuildingKind::TradeDepot => {
+                LLRPixel {
+                    character: "$",
+                    background: colours::BROWN,
+                    foreground: colours::YELLOW,
+                }
+            },
+            BuildingKind::FarmPlot => {
+                LLRPixel {
+                    character: "=",
+                    background: colours::BLACK,
+                    foreground: colours::BROWN,
+                }
+            },
+        }
+    }
+
+    /// Gets the pixel for drawing a crop on a farm plot, at the stage of
+    /// growth given. Ripe crops are drawn with the character given.
+    pub fn get_crop(
+        &self,
+        stage: GrowthStage,
+        character: &'static str,
+    ) -> LLRPixel {
+        match stage {
+            GrowthStage::Seedling => {
+                LLRPixel {
+                    character: ".",
+                    background: colours::BROWN,
+                    foreground: colours::LIGHT_GREEN,
+                }
+            },
+            GrowthStage::Growing => {
+                LLRPixel {
+                    character: "\"",
+                    background: colours::BROWN,
+                    foreground: colours::GREEN,
+                }
+            },
+            GrowthStage::Ripe => {
+                LLRPixel {
+                    character: character,
+                    background: colours::BROWN,
+                    foreground: colours::YELLOW,
+                }
+            },
+        }
+    }
+
     /// Gets the pixel information to use when drawing, for the tile inside of
-    /// the game tile.
+    /// the game tile. Snow and puddles are drawn over the top of the tile.
     pub fn get_game_tile(
         &self,
         tile: GameTile,
     ) -> LLRPixel {
+        if tile.snow > 0 {
+            return LLRPixel {
+                character: if tile.snow > 2 {
+                    "▒"
+                } else {
+                    "░"
+                },
+                background: colours::LIGHT_GREY,
+                foreground: colours::WHITE,
+            };
+        }
+
+        if tile.puddle > 0 {
+            return LLRPixel {
+                character: "≈",
+                background: colours::BLACK,
+                foreground: colours::LIGHT_BLUE,
+            };
+        }
+
         self.get_tile(tile.tile)
     }
 
+    /// Gets the pixel for a game tile which has been seen before, but is not in
+    /// view right now. It's the normal tile, but dimmed.
+    pub fn get_remembered_game_tile(
+        &self,
+        tile: GameTile,
+    ) -> LLRPixel {
+        shade(self.get_game_tile(tile), REMEMBERED_BRIGHTNESS)
+    }
+
+    /// Gets the pixel for a game tile which is in view, darkened to match the
+    /// light falling on it.
+    ///
+    /// The light level is from 0.0 (pitch black) to 1.0 (full light).
+    pub fn get_lit_game_tile(
+        &self,
+        tile: GameTile,
+        light_level: f32,
+    ) -> LLRPixel {
+        shade(self.get_game_tile(tile), light_level)
+    }
+
+    /// The colour for announcements of the severity given.
+    pub fn get_severity_colour(
+        &self,
+        severity: Severity,
+    ) -> RGBA {
+        match severity {
+            Severity::Info => colours::LIGHT_GREY,
+            Severity::Notable => colours::LIGHT_CYAN,
+            Severity::Warning => colours::YELLOW,
+            Severity::Danger => colours::LIGHT_RED,
+        }
+    }
+
+    /// The background colour for panels drawn over the map.
+    pub fn get_panel_background(&self) -> RGBA {
+        colours::BLACK
+    }
+
+    /// The colour for titles at the top of panels.
+    pub fn get_panel_title_colour(&self) -> RGBA {
+        colours::WHITE
+    }
+
+    /// The colour for normal text on panels.
+    pub fn get_text_colour(&self) -> RGBA {
+        colours::LIGHT_GREY
+    }
+
+    /// The colour for the selected line of a list.
+    pub fn get_selected_colour(&self) -> RGBA {
+        colours::YELLOW
+    }
+
+    /// Gets the pixel for a tile that has never been seen.
+    pub fn get_hidden(&self) -> LLRPixel {
+        LLRPixel {
+            character: " ",
+            background: colours::BLACK,
+            foreground: colours::BLACK,
+        }
+    }
+
     /// Converts the tile into two colours; a back and front colour.
     /// These two colours are returned.
     pub fn get_tile(
@@ -112,6 +280,40 @@ impl Theme {
                     foreground: colours::WHITE,
                 }
             },
+
+            Tile::Door => {
+                LLRPixel {
+                    character: "+",
+                    background: colours::GREY,
+                    foreground: colours::BROWN,
+                }
+            },
         }
     }
 }
+
+/// Returns the pixel with both of it's colours scaled by the brightness given.
+fn shade(
+    pixel: LLRPixel,
+    brightness: f32,
+) -> LLRPixel {
+    LLRPixel {
+        character: pixel.character,
+        background: dim(pixel.background, brightness),
+        foreground: dim(pixel.foreground, brightness),
+    }
+}
+
+/// Returns the colour with it's brightness scaled by the amount given.
+///
+/// This is done in HSV space, so only the value changes. The colour keeps it's
+/// hue as it gets darker.
+fn dim(
+    colour: RGBA,
+    brightness: f32,
+) -> RGBA {
+    let mut hsva = colour.to_hsva();
+    hsva.value *= brightness.clamp(0.0, 1.0);
+
+    hsva.to_rgba()
+}
@@ -92,41 +92,16 @@ impl LLR for LLRSDL2 {
         num_pixels
     }
 
-    /// Blocks indefinitely until a user event has occurred.
-    /// When the event happens this will return.
+    /// Returns the next event waiting, without blocking.
+    /// Events the LLR doesn't understand are skipped.
     fn poll(&mut self) -> Option<LLREvent> {
-        match self.events.wait_event() {
-            Event::Quit {
-                ..
+        while let Some(event) = self.events.poll_event() {
+            if let Some(event) = to_llr_event(event) {
+                return Some(event);
             }
-            | Event::AppTerminating {
-                ..
-            } => Some(LLREvent::Quit),
-
-            Event::Window {
-                win_event,
-                ..
-            } => {
-                match win_event {
-                    WindowEvent::Resized(_w, _h) | WindowEvent::SizeChanged(_w, _h) => {
-                        Some(LLREvent::Resize)
-                    },
-                    _ => None,
-                }
-            },
-
-            Event::KeyDown {
-                keycode: Some(sdl_key),
-                ..
-            } => {
-                match sdl_key_to_llr_key(sdl_key) {
-                    Some(key) => Some(LLREvent::KeyPress(key)),
-                    None => None,
-                }
-            },
-
-            _ => None,
         }
+
+        None
     }
 
     fn on_start(&mut self) {
@@ -138,12 +113,63 @@ impl LLR for LLRSDL2 {
     }
 }
 
+/// Converts an SDL event into an LLR event.
+/// Returns None for events the LLR doesn't care about.
+fn to_llr_event(event: Event) -> Option<LLREvent> {
+    match event {
+        Event::Quit {
+            ..
+        }
+        | Event::AppTerminating {
+            ..
+        } => Some(LLREvent::Quit),
+
+        Event::Window {
+            win_event,
+            ..
+        } => {
+            match win_event {
+                WindowEvent::Resized(_w, _h) | WindowEvent::SizeChanged(_w, _h) => {
+                    Some(LLREvent::Resize)
+                },
+                _ => None,
+            }
+        },
+
+        Event::KeyDown {
+            keycode: Some(sdl_key),
+            ..
+        } => {
+            match sdl_key_to_llr_key(sdl_key) {
+                Some(key) => Some(LLREvent::KeyPress(key)),
+                None => None,
+            }
+        },
+
+        _ => None,
+    }
+}
+
 fn sdl_key_to_llr_key(sdl_key: Keycode) -> Option<LLRKey> {
     match sdl_key {
         Keycode::Up => Some(LLRKey::Up),
         Keycode::Down => Some(LLRKey::Down),
         Keycode::Left => Some(LLRKey::Left),
         Keycode::Right => Some(LLRKey::Right),
+        Keycode::PageUp => Some(LLRKey::PageUp),
+        Keycode::PageDown => Some(LLRKey::PageDown),
+        Keycode::Plus | Keycode::Equals | Keycode::KpPlus => Some(LLRKey::Plus),
+        Keycode::Minus | Keycode::KpMinus => Some(LLRKey::Minus),
+        Keycode::F => Some(LLRKey::F),
+        Keycode::L => Some(LLRKey::L),
+        Keycode::B => Some(LLRKey::B),
+        Keycode::C => Some(LLRKey::C),
+        Keycode::P => Some(LLRKey::P),
+        Keycode::R => Some(LLRKey::R),
+        Keycode::T => Some(LLRKey::T),
+        Keycode::Z => Some(LLRKey::Z),
+        Keycode::Space => Some(LLRKey::Space),
+        Keycode::Return => Some(LLRKey::Enter),
         _ => None,
     }
 }
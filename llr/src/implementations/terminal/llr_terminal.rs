@@ -6,6 +6,9 @@ use LLRPixel;
 use getch::Getch;
 use implementations::terminal::colour;
 use std::io::{self, Write};
+use std::mem;
+use std::sync::mpsc::{channel, Receiver};
+use std::thread;
 use util::collections::Matrix;
 use util::shapes::Point;
 use util::shapes::Size;
@@ -20,8 +23,12 @@ pub struct LLRTerminal {
     /// Internal buffer that holds that data we will be drawing.
     screen: Matrix<Option<LLRPixel>>,
 
-    /// We use this for reading in user input.
-    getch: Getch,
+    /// Puts the terminal into a mode for reading in user input.
+    /// The terminal is put back when this is dropped.
+    _getch: Getch,
+
+    /// Keys read in by the input thread, waiting to be polled.
+    keys: Receiver<u8>,
 
     /// Where we are writing to.
     out: io::Stdout,
@@ -44,7 +51,8 @@ impl LLRTerminal {
             options: options,
             screen: Matrix::new(matrix_size, None),
             out_buffer: String::with_capacity(estimated_out_capacity),
-            getch: Getch::new(),
+            _getch: Getch::new(),
+            keys: spawn_input_thread(),
             out: io::stdout(),
         };
 
@@ -182,12 +190,44 @@ impl LLR for LLRTerminal {
     }
 
     fn poll(&mut self) -> Option<LLREvent> {
-        match self.getch.getch() {
-            Ok(c) => u8_to_key(c).map(|key| LLREvent::KeyPress(key)),
-            Err(err) => {
-                eprintln!("Error reading getch {}", err);
-                None
-            },
+        while let Ok(c) = self.keys.try_recv() {
+            if let Some(key) = u8_to_key(c) {
+                return Some(LLREvent::KeyPress(key));
+            }
         }
+
+        None
     }
 }
+
+/// Starts a thread which reads keys in from the terminal.
+///
+/// Reading blocks until a key is pressed, so it's done off to the side. The
+/// keys are sent back through the receiver returned.
+fn spawn_input_thread() -> Receiver<u8> {
+    let (sender, receiver) = channel();
+
+    thread::spawn(move || {
+        let getch = Getch::new();
+
+        loop {
+            match getch.getch() {
+                Ok(c) => {
+                    if sender.send(c).is_err() {
+                        break;
+                    }
+                },
+                Err(err) => {
+                    eprintln!("Error reading getch {}", err);
+                    break;
+                },
+            }
+        }
+
+        // Putting the terminal back is left to the LLR's own getch. Dropping
+        // this one could undo that, if the LLR has already gone.
+        mem::forget(getch);
+    });
+
+    receiver
+}
@@ -6,6 +6,8 @@ const ARROW_UP: u8 = 72;
 const ARROW_DOWN: u8 = 80;
 const ARROW_LEFT: u8 = 75;
 const ARROW_RIGHT: u8 = 77;
+const PAGE_UP: u8 = 73;
+const PAGE_DOWN: u8 = 81;
 
 const ESCAPE: u8 = 27;
 const LETTER_Q: u8 = 113;
@@ -13,9 +15,23 @@ const LETTER_Q: u8 = 113;
 const LETTER_W: u8 = 119;
 const LETTER_S: u8 = 115;
 const LETTER_A: u8 = 97;
+const LETTER_B: u8 = 98;
+const LETTER_C: u8 = 99;
 const LETTER_D: u8 = 100;
+const LETTER_F: u8 = 102;
+const LETTER_L: u8 = 108;
+const LETTER_P: u8 = 112;
+const LETTER_R: u8 = 114;
+const LETTER_T: u8 = 116;
+const LETTER_Z: u8 = 122;
+
+const PLUS: u8 = 43;
+const EQUALS: u8 = 61;
+const MINUS: u8 = 45;
 
 const SPACE: u8 = 32;
+const ENTER: u8 = 13;
+const NEW_LINE: u8 = 10;
 
 pub(crate) fn u8_to_key(key: u8) -> Option<LLRKey> {
     match key {
@@ -31,12 +47,34 @@ pub(crate) fn u8_to_key(key: u8) -> Option<LLRKey> {
 
         LETTER_D => Some(LLRKey::D),
 
+        LETTER_F => Some(LLRKey::F),
+
+        LETTER_L => Some(LLRKey::L),
+
+        LETTER_R => Some(LLRKey::R),
+
+        LETTER_T => Some(LLRKey::T),
+
+        LETTER_B => Some(LLRKey::B),
+
+        LETTER_C => Some(LLRKey::C),
+
+        LETTER_P => Some(LLRKey::P),
+
+        LETTER_Z => Some(LLRKey::Z),
+
         ARROW_UP => Some(LLRKey::Up),
         ARROW_DOWN => Some(LLRKey::Down),
         ARROW_LEFT => Some(LLRKey::Left),
         ARROW_RIGHT => Some(LLRKey::Right),
+        PAGE_UP => Some(LLRKey::PageUp),
+        PAGE_DOWN => Some(LLRKey::PageDown),
+
+        PLUS | EQUALS => Some(LLRKey::Plus),
+        MINUS => Some(LLRKey::Minus),
 
         SPACE => Some(LLRKey::Space),
+        ENTER | NEW_LINE => Some(LLRKey::Enter),
 
         _ => None,
     }
@@ -45,6 +45,9 @@ pub trait LLR {
     /// Returns the size of the LLR in pixel tiles.
     fn size(&self) -> Size<u16>;
 
-    /// Polls for events.
+    /// Returns the next event waiting, or None if there are no more.
+    ///
+    /// This never blocks. Call it until it returns None to take every event
+    /// which has happened since it was last called.
     fn poll(&mut self) -> Option<LLREvent>;
 }
@@ -8,11 +8,26 @@ pub enum LLRKey {
     Right,
 
     A,
+    B,
+    C,
     D,
+    F,
+    L,
+    P,
     S,
     Q,
+    R,
+    T,
     W,
+    Z,
+
+    PageUp,
+    PageDown,
+
+    Plus,
+    Minus,
 
     Space,
+    Enter,
     Escape,
 }
@@ -15,6 +15,24 @@ pub struct Args {
     /// The map file we will be using.
     #[structopt(short = "m", long = "map", help = "The map file to load.")]
     pub map: String,
+
+    /// The biome of each tile of the map, as written by the generator.
+    /// If not given, then the whole map is grassland.
+    #[structopt(short = "b", long = "biomes", help = "The biome file to load.")]
+    pub biomes: Option<String>,
+
+    /// The seed for the game.
+    /// If not given, then one is picked from the current time.
+    #[structopt(short = "s", long = "seed", help = "Seed for the game.")]
+    pub seed: Option<u64>,
+
+    /// Where to write the input log, when the game is over.
+    #[structopt(long = "record", help = "Writes the input log to this file on quit.")]
+    pub record: Option<String>,
+
+    /// An input log to replay, instead of playing.
+    #[structopt(long = "replay", help = "Replays an input log, and checks it does not diverge.")]
+    pub replay: Option<String>,
 }
 
 impl Args {
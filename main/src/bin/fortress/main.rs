@@ -1,9 +1,14 @@
 use std::error::Error;
 use std::fs::File;
 use std::io::BufReader;
+use std::io::BufWriter;
+use std::io::Error as IoError;
+use std::io::ErrorKind;
 use std::io::Result;
 use std::path::Path;
 use std::process::exit;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 
 extern crate structopt;
 #[macro_use]
@@ -17,36 +22,24 @@ extern crate world;
 use args::Args;
 use game::Game;
 use game::GameSetup;
+use game::replay::InputLog;
+use game::replay::replay;
+use game::scheduler::default_systems;
 use hlr::HLROptions;
 use hlr::render::run::run;
 use util::colour::RGBA;
 use util::shapes::Size;
-use world::calendar::WorldCalendar;
-use world::calendar::WorldTime;
+use world::biomes::Biome;
+use world::biomes::read_biome_map;
+use world::calendar::Calendar;
 use world::load;
+use world::map::Map;
 use world::player::Player;
+use world::tiles::Tile;
 use world::world_setup::WorldSetup;
 
 mod args;
 
-struct FortressCalendar {}
-
-impl WorldCalendar for FortressCalendar {
-    fn get_time(
-        &self,
-        time: u32,
-    ) -> WorldTime {
-        WorldTime {
-            second: (time % 60) as u8,
-            minute: 1,
-            hour: 1,
-            day: 1,
-            month: 1,
-            year: 1,
-        }
-    }
-}
-
 fn main() {
     let args = Args::new_from_args();
     if !Path::new(&args.map).exists() {
@@ -67,21 +60,22 @@ fn main() {
 }
 
 fn main_run(args: Args) -> Result<()> {
-    let file = File::open(args.map)?;
+    let file = File::open(&args.map)?;
     let mut file = BufReader::new(file);
-
-    let player = Player::new(22, 18);
     let map = &load::read_to_map(&mut file)?;
-
-    let world_setup = WorldSetup {
-        calendar: &FortressCalendar {},
+    let biomes = match args.biomes {
+        Some(ref path) => Some(load_biomes(map, path)?),
+        None => None,
     };
+    let biomes = biomes.as_ref();
+    let calendar = &Calendar::standard();
 
-    let game_setup = GameSetup {
-        time_tick_speed: 5,
-    };
+    if let Some(replay_path) = args.replay {
+        return main_replay(map, biomes, calendar, &replay_path);
+    }
 
-    let game = Game::new(map, player, world_setup, game_setup);
+    let seed = args.seed.unwrap_or_else(new_seed);
+    let mut game = new_game(map, biomes, calendar, seed);
 
     let setup = HLROptions {
         title: "Fortress",
@@ -99,7 +93,92 @@ fn main_run(args: Args) -> Result<()> {
         clear_colour: RGBA::new(0, 0, 0, 255),
     };
 
-    run(setup, &game);
+    run(setup, &mut game);
+    println!("seed {}", seed);
+
+    if let Some(record_path) = args.record {
+        game.record_state_hash();
+
+        let mut out = BufWriter::new(File::create(record_path)?);
+        game.input_log().write(&mut out)?;
+    }
+
+    Ok(())
+}
+
+/// Replays the input log at the path given, against a new game.
+/// Ends the application with an error if the replay diverges.
+fn main_replay(
+    map: &Map<Tile>,
+    biomes: Option<&Map<Biome>>,
+    calendar: &Calendar,
+    replay_path: &str,
+) -> Result<()> {
+    let file = File::open(replay_path)?;
+    let log = InputLog::read(&mut BufReader::new(file))?;
+    let mut game = new_game(map, biomes, calendar, log.seed);
+
+    match replay(&mut game, &log) {
+        Ok(()) => {
+            println!("replay ok, {} ticks", game.ticks());
+        },
+        Err(err) => {
+            eprintln!("replay failed, {}", err);
+            exit(1);
+        },
+    }
 
     Ok(())
 }
+
+/// Loads the biome of each tile, which must line up with the map.
+fn load_biomes(
+    map: &Map<Tile>,
+    path: &str,
+) -> Result<Map<Biome>> {
+    let file = File::open(path)?;
+    let biomes = read_biome_map(&mut BufReader::new(file))?;
+
+    if biomes.width != map.width || biomes.height != map.height {
+        return Err(IoError::new(
+            ErrorKind::InvalidData,
+            format!("The biome map is a different size to the map, {}", path),
+        ));
+    }
+
+    Ok(biomes)
+}
+
+fn new_game<'a>(
+    map: &Map<Tile>,
+    biomes: Option<&'a Map<Biome>>,
+    calendar: &'a Calendar,
+    seed: u64,
+) -> Game<'a> {
+    let player = Player::new(22, 18);
+
+    let world_setup = WorldSetup {
+        calendar: calendar,
+        biome: Biome::Grassland,
+        biomes: biomes,
+        latitude: 30.0,
+        elevation: 0.0,
+    };
+
+    let game_setup = GameSetup {
+        time_tick_speed: 5,
+        view_distance: 12,
+        seed: seed,
+        systems: default_systems(),
+    };
+
+    Game::new(map, player, world_setup, game_setup)
+}
+
+/// Picks a seed from the current time.
+fn new_seed() -> u64 {
+    match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(duration) => duration.as_secs() ^ u64::from(duration.subsec_nanos()),
+        Err(_) => 0,
+    }
+}
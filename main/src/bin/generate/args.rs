@@ -28,6 +28,50 @@ pub struct Args {
                 help = "Set height for the map.")]
     pub height: u32,
 
+    /// How many layers of detail the terrain has.
+    #[structopt(long = "octaves", default_value = "5",
+                help = "Layers of detail in the terrain.")]
+    pub octaves: u32,
+
+    /// How far apart the broadest features of the terrain are.
+    #[structopt(long = "feature-size", default_value = "32",
+                help = "Size of the broadest terrain features, in tiles.")]
+    pub feature_size: f32,
+
+    /// How much of the land is under water.
+    #[structopt(long = "water-level", default_value = "0.2",
+                help = "Elevation, from 0 to 1, below which is water.")]
+    pub water_level: f32,
+
+    /// How warm the map is.
+    #[structopt(long = "temperature", default_value = "0.5",
+                help = "Average temperature, from 0 (arctic) to 1 (tropical).")]
+    pub temperature: f32,
+
+    /// How wet the map is.
+    #[structopt(long = "moisture", default_value = "0.5",
+                help = "Average moisture, from 0 (arid) to 1 (sodden).")]
+    pub moisture: f32,
+
+    /// Where to save the biome of each tile.
+    #[structopt(long = "biomes",
+                help = "Writes the biome of each tile to this file, for the game to load.")]
+    pub biomes: Option<String>,
+
+    /// Should caves be generated, instead of land.
+    #[structopt(long = "caves", help = "Generate caves dug through rock.")]
+    pub caves: bool,
+
+    /// How much of the caves start out as rock.
+    #[structopt(long = "cave-density", default_value = "0.45",
+                help = "How much of the caves start as rock, from 0 to 1.")]
+    pub cave_density: f32,
+
+    /// The smallest a cavern can be.
+    #[structopt(long = "min-cavern-size", default_value = "20",
+                help = "Caverns smaller than this are filled in.")]
+    pub min_cavern_size: u32,
+
     /// Should colour be on or off.
     #[structopt(subcommand)]
     pub colour: Option<ArgsColour>,
@@ -13,12 +13,18 @@ extern crate llr;
 extern crate world;
 extern crate util;
 
+use std::fs::File;
+use std::io::BufWriter;
 use std::io::stdout;
+use std::process::exit;
 
 use args::Args;
 use args::ArgsColour;
+use generate::caves::CaveOptions;
+use generate::caves::new_cave_map;
 use generate::generate::MapOptions;
 use generate::generate::new_map;
+use world::biomes::write_biome_map;
 
 mod args;
 mod print;
@@ -27,11 +33,36 @@ mod print;
 fn main() {
     let args = Args::new_from_args();
 
-    let map = new_map(MapOptions {
-        width: args.width,
-        height: args.height,
-        seed: args.seed,
-    });
+    let (map, biomes) = if args.caves {
+        let map = new_cave_map(CaveOptions {
+            density: args.cave_density,
+            min_cavern_size: args.min_cavern_size,
+            ..CaveOptions::new(args.width, args.height, args.seed)
+        });
+
+        (map, None)
+    } else {
+        let generated = new_map(MapOptions {
+            octaves: args.octaves,
+            feature_size: args.feature_size,
+            water_level: args.water_level,
+            temperature: args.temperature,
+            moisture: args.moisture,
+            ..MapOptions::new(args.width, args.height, args.seed)
+        });
+
+        (generated.tiles, Some(generated.biomes))
+    };
+
+    if let (Some(path), Some(biomes)) = (args.biomes.as_ref(), biomes.as_ref()) {
+        let saved =
+            File::create(path).and_then(|file| write_biome_map(biomes, &mut BufWriter::new(file)));
+
+        if let Err(err) = saved {
+            eprintln!("Error, {}", err);
+            exit(1);
+        }
+    }
 
     let mut out = stdout();
     out.lock();
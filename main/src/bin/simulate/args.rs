@@ -0,0 +1,69 @@
+use structopt::StructOpt;
+
+/// The structure of the commands.
+///
+/// A large amount of this is generated by `StructOpt`.
+/// See that project for how to write large amounts of this.
+///
+/// The gist however is that we make a struct that will hold
+/// all of our arguments. Commands are then parsed, and then
+/// turned into this struct.
+///
+#[derive(StructOpt, Debug)]
+#[structopt(name = "Simulate", about = "Runs the game without a renderer, and reports on it.")]
+pub struct Args {
+    /// The map file to use.
+    /// If not given, then a new map is generated.
+    #[structopt(short = "m", long = "map", help = "The map file to load.")]
+    pub map: Option<String>,
+
+    /// The seed for the game, and for generating the map.
+    #[structopt(short = "s", default_value = "1", long = "seed", help = "Seed for the game.")]
+    pub seed: u64,
+
+    /// The width of the map to generate.
+    #[structopt(long = "width", default_value = "50", help = "Width of a generated map.")]
+    pub width: u32,
+
+    /// The height of the map to generate.
+    #[structopt(long = "height", default_value = "50", help = "Height of a generated map.")]
+    pub height: u32,
+
+    /// How many ticks to run.
+    #[structopt(
+        short = "t",
+        long = "ticks",
+        default_value = "10000",
+        help = "How many ticks to run."
+    )]
+    pub ticks: u64,
+
+    /// How many ticks between each report.
+    #[structopt(
+        short = "r",
+        long = "report-every",
+        default_value = "1000",
+        help = "How many ticks between each report."
+    )]
+    pub report_every: u64,
+
+    /// How many seconds of game time pass on each tick.
+    #[structopt(
+        long = "tick-seconds",
+        default_value = "60",
+        help = "Seconds of game time which pass on each tick."
+    )]
+    pub tick_seconds: u32,
+
+    /// Print the reports as JSON, one object per line.
+    #[structopt(long = "json", help = "Prints reports as JSON, one per line.")]
+    pub json: bool,
+}
+
+impl Args {
+    /// Builds a new args from the main arguments given.
+    ///
+    pub fn new_from_args() -> Args {
+        Args::from_args()
+    }
+}
@@ -0,0 +1,144 @@
+//! # Simulate.
+//!
+//! Runs the game without a renderer, as fast as it will go, and reports on
+//! how it is getting on. This is for profiling, and for soak testing the game.
+//!
+//! It never uses the HLR or LLR, so it runs anywhere without a terminal.
+//!
+
+use std::fs::File;
+use std::io::BufReader;
+use std::io::Result;
+use std::process::exit;
+use std::time::Instant;
+
+extern crate structopt;
+#[macro_use]
+extern crate structopt_derive;
+
+extern crate game;
+extern crate generate;
+extern crate world;
+
+use args::Args;
+use game::scheduler::default_systems;
+use game::Game;
+use game::GameSetup;
+use generate::generate::new_map;
+use generate::generate::MapOptions;
+use report::Stats;
+use world::biomes::Biome;
+use world::calendar::Calendar;
+use world::load;
+use world::map::Map;
+use world::player::Player;
+use world::tiles::Tile;
+use world::world_setup::WorldSetup;
+
+mod args;
+mod report;
+
+/// Entry point.
+fn main() {
+    let args = Args::new_from_args();
+
+    if let Err(err) = main_run(args) {
+        eprintln!("Error, {}", err);
+        exit(1);
+    }
+}
+
+fn main_run(args: Args) -> Result<()> {
+    let (map, biomes) = match args.map {
+        Some(ref path) => {
+            let file = File::open(path)?;
+            (load::read_to_map(&mut BufReader::new(file))?, None)
+        },
+        None => {
+            let generated =
+                new_map(MapOptions::new(args.width, args.height, Some(args.seed as usize)));
+            (generated.tiles, Some(generated.biomes))
+        },
+    };
+
+    let calendar = Calendar::standard();
+    let mut game = new_game(&map, biomes.as_ref(), &calendar, args.seed, args.tick_seconds);
+    let mut stats = Stats::new(&game);
+    let report_every = args.report_every.max(1);
+    let start = Instant::now();
+
+    for _ in 0..args.ticks {
+        game.tick();
+        stats.add_events(game.drain_events().count());
+
+        if game.ticks() % report_every == 0 {
+            print_report(&mut stats, &game, args.json);
+        }
+    }
+
+    if game.ticks() % report_every != 0 {
+        print_report(&mut stats, &game, args.json);
+    }
+
+    let elapsed = start.elapsed().as_secs_f64();
+    if args.json {
+        println!(
+            "{{\"done\":true,\"ticks\":{},\"seconds\":{:.3},\"state_hash\":{}}}",
+            game.ticks(),
+            elapsed,
+            game.state_hash()
+        );
+    } else {
+        println!(
+            "done, {} ticks in {:.3}s, seed {}, state hash {:016x}",
+            game.ticks(),
+            elapsed,
+            args.seed,
+            game.state_hash()
+        );
+    }
+
+    Ok(())
+}
+
+/// Prints how the game has got on since the last report.
+fn print_report(
+    stats: &mut Stats,
+    game: &Game,
+    json: bool,
+) {
+    let report = stats.report(game);
+
+    if json {
+        println!("{}", report.to_json());
+    } else {
+        println!("{}", report.to_text());
+    }
+}
+
+fn new_game<'a>(
+    map: &Map<Tile>,
+    biomes: Option<&'a Map<Biome>>,
+    calendar: &'a Calendar,
+    seed: u64,
+    tick_seconds: u32,
+) -> Game<'a> {
+    let player = Player::new(map.width / 2, map.height / 2);
+
+    let world_setup = WorldSetup {
+        calendar: calendar,
+        biome: Biome::Grassland,
+        biomes: biomes,
+        latitude: 30.0,
+        elevation: 0.0,
+    };
+
+    let game_setup = GameSetup {
+        time_tick_speed: tick_seconds,
+        view_distance: 12,
+        seed: seed,
+        systems: default_systems(),
+    };
+
+    Game::new(map, player, world_setup, game_setup)
+}
@@ -0,0 +1,373 @@
+use std::time::Duration;
+use std::time::Instant;
+
+use game::scheduler::SystemTiming;
+use game::Game;
+
+/// How the game got on, since the last report.
+pub struct Report {
+    /// The tick the report was taken on.
+    pub tick: u64,
+
+    /// How many ticks were run each second, since the last report.
+    pub ticks_per_second: f64,
+
+    /// How many citizens are in the fortress.
+    pub citizens: usize,
+
+    /// How many creatures are alive, including citizens.
+    pub creatures: usize,
+
+    /// How many jobs are waiting, or being done.
+    pub jobs: usize,
+
+    /// How many items are lying around.
+    pub items: usize,
+
+    /// How much the fortress is worth.
+    pub wealth: u32,
+
+    /// How many events happened since the last report.
+    pub events: usize,
+
+    /// How long each system took, since the last report.
+    pub systems: Vec<SystemReport>,
+}
+
+/// How long a system took, since the last report.
+pub struct SystemReport {
+    /// The system's name.
+    pub name: &'static str,
+
+    /// How many times it ran.
+    pub runs: u64,
+
+    /// How long it took on average, for each run, in microseconds.
+    pub micros_per_run: f64,
+}
+
+impl Report {
+    /// Writes the report out as lines of text, for people to read.
+    pub fn to_text(&self) -> String {
+        let mut text = format!(
+            "tick {}: {:.1} ticks/s, {} citizens, {} creatures, {} jobs, {} items, wealth {}, \
+             {} events",
+            self.tick,
+            self.ticks_per_second,
+            self.citizens,
+            self.creatures,
+            self.jobs,
+            self.items,
+            self.wealth,
+            self.events,
+        );
+
+        for system in &self.systems {
+            text += &format!(
+                "\n    {:<16} {:>8} runs {:>10.2}us",
+                system.name, system.runs, system.micros_per_run
+            );
+        }
+
+        text
+    }
+
+    /// Writes the report out as a single line of JSON.
+    pub fn to_json(&self) -> String {
+        let systems = self
+            .systems
+            .iter()
+            .map(|system| {
+                format!(
+                    "{{\"name\":{},\"runs\":{},\"micros_per_run\":{:.2}}}",
+                    json_string(system.name),
+                    system.runs,
+                    system.micros_per_run
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(",");
+
+        format!(
+            "{{\"tick\":{},\"ticks_per_second\":{:.1},\"citizens\":{},\"creatures\":{},\
+             \"jobs\":{},\"items\":{},\"wealth\":{},\"events\":{},\"systems\":[{}]}}",
+            self.tick,
+            self.ticks_per_second,
+            self.citizens,
+            self.creatures,
+            self.jobs,
+            self.items,
+            self.wealth,
+            self.events,
+            systems,
+        )
+    }
+}
+
+/// Keeps track of the game between reports, so each report only covers the
+/// ticks since the one before it.
+pub struct Stats {
+    /// The tick of the last report.
+    last_tick: u64,
+
+    /// When the last report was taken.
+    last_time: Instant,
+
+    /// The system timings as of the last report.
+    last_timings: Vec<SystemTiming>,
+
+    /// How many events have happened since the last report.
+    events: usize,
+}
+
+impl Stats {
+    /// Starts keeping track of the game, from where it is now.
+    pub fn new(game: &Game) -> Stats {
+        Stats {
+            last_tick: game.ticks(),
+            last_time: Instant::now(),
+            last_timings: game.system_timings().to_vec(),
+            events: 0,
+        }
+    }
+
+    /// Counts events taken out of the game.
+    pub fn add_events(
+        &mut self,
+        count: usize,
+    ) {
+        self.events += count;
+    }
+
+    /// Reports on the game since the last report, and starts a new one.
+    pub fn report(
+        &mut self,
+        game: &Game,
+    ) -> Report {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_time);
+        let ticks = game.ticks() - self.last_tick;
+
+        let systems = game
+            .system_timings()
+            .iter()
+            .map(|timing| {
+                let (runs, total) =
+                    match self.last_timings.iter().find(|last| last.system == timing.system) {
+                        Some(last) => (timing.runs - last.runs, timing.total - last.total),
+                        None => (timing.runs, timing.total),
+                    };
+
+                SystemReport {
+                    name: timing.system.name(),
+                    runs: runs,
+                    micros_per_run: micros_per_run(total, runs),
+                }
+            })
+            .collect();
+
+        let report = Report {
+            tick: game.ticks(),
+            ticks_per_second: ticks as f64 / elapsed.as_secs_f64().max(1e-9),
+            citizens: game.citizens().len(),
+            creatures: game.creatures().len(),
+            jobs: game.jobs().len(),
+            items: game.items().len(),
+            wealth: game.wealth(),
+            events: self.events,
+            systems: systems,
+        };
+
+        self.last_tick = game.ticks();
+        self.last_time = now;
+        self.last_timings = game.system_timings().to_vec();
+        self.events = 0;
+
+        report
+    }
+}
+
+/// Writes the text as a JSON string, in quotes, escaping anything which
+/// needs it.
+fn json_string(text: &str) -> String {
+    let mut json = String::from("\"");
+
+    for c in text.chars() {
+        match c {
+            '"' => json += "\\\"",
+            '\\' => json += "\\\\",
+            '\n' => json += "\\n",
+            c if c.is_control() => json += &format!("\\u{:04x}", c as u32),
+            c => json.push(c),
+        }
+    }
+
+    json.push('"');
+    json
+}
+
+/// The average time for each run, in microseconds.
+fn micros_per_run(
+    total: Duration,
+    runs: u64,
+) -> f64 {
+    if runs == 0 {
+        0.0
+    } else {
+        total.as_secs_f64() * 1_000_000.0 / runs as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use generate::generate::new_map;
+    use generate::generate::MapOptions;
+    use new_game;
+    use std::iter::Peekable;
+    use world::calendar::Calendar;
+
+    /// Runs a game for a few ticks, and reports on it.
+    fn run(calendar: &Calendar) -> Report {
+        let generated = new_map(MapOptions::new(30, 30, Some(4)));
+        let mut game = new_game(&generated.tiles, Some(&generated.biomes), calendar, 4, 60);
+        let mut stats = Stats::new(&game);
+
+        for _ in 0..50 {
+            game.tick();
+            stats.add_events(game.drain_events().count());
+        }
+
+        stats.report(&game)
+    }
+
+    /// Returns true if the text is one whole JSON value.
+    fn is_json(text: &str) -> bool {
+        let mut chars = text.chars().peekable();
+
+        skip_value(&mut chars) && chars.next().is_none()
+    }
+
+    /// Skips over one JSON value, and returns true if it was valid.
+    fn skip_value<I: Iterator<Item = char>>(chars: &mut Peekable<I>) -> bool {
+        match chars.next() {
+            Some('{') => {
+                skip_list(chars, '}', |chars| {
+                    chars.next() == Some('"')
+                        && skip_string(chars)
+                        && chars.next() == Some(':')
+                        && skip_value(chars)
+                })
+            },
+            Some('[') => skip_list(chars, ']', skip_value),
+            Some('"') => skip_string(chars),
+            Some(c) if c == '-' || c.is_ascii_digit() => {
+                while chars.peek().is_some_and(|&c| c.is_ascii_digit() || c == '.') {
+                    chars.next();
+                }
+
+                true
+            },
+            _ => false,
+        }
+    }
+
+    /// Skips the items of an object or array, up to, and including, the end.
+    fn skip_list<I, F>(
+        chars: &mut Peekable<I>,
+        end: char,
+        skip_item: F,
+    ) -> bool
+    where
+        I: Iterator<Item = char>,
+        F: Fn(&mut Peekable<I>) -> bool,
+    {
+        if chars.peek() == Some(&end) {
+            chars.next();
+            return true;
+        }
+
+        loop {
+            if !skip_item(chars) {
+                return false;
+            }
+
+            match chars.next() {
+                Some(',') => {},
+                Some(c) => return c == end,
+                None => return false,
+            }
+        }
+    }
+
+    /// Skips the rest of a string, after the opening quote.
+    fn skip_string<I: Iterator<Item = char>>(chars: &mut Peekable<I>) -> bool {
+        while let Some(c) = chars.next() {
+            match c {
+                '"' => return true,
+                '\\' => {
+                    chars.next();
+                },
+                c if c.is_control() => return false,
+                _ => {},
+            }
+        }
+
+        false
+    }
+
+    #[test]
+    fn reports_the_same_game_the_same_way() {
+        let calendar = Calendar::standard();
+        let report = run(&calendar);
+        let again = run(&calendar);
+
+        assert_eq!(report.tick, 50);
+        assert!(report.creatures >= report.citizens);
+        assert_eq!(
+            (report.citizens, report.creatures, report.jobs, report.items, report.wealth),
+            (again.citizens, again.creatures, again.jobs, again.items, again.wealth)
+        );
+        assert_eq!(report.events, again.events);
+
+        let runs = |report: &Report| {
+            report.systems.iter().map(|system| (system.name, system.runs)).collect::<Vec<_>>()
+        };
+        assert_eq!(runs(&report), runs(&again));
+        assert!(report.systems.iter().all(|system| system.runs == 50));
+        assert!(report.to_text().starts_with("tick 50: "));
+    }
+
+    #[test]
+    fn writes_valid_json() {
+        let calendar = Calendar::standard();
+        let json = run(&calendar).to_json();
+
+        assert!(is_json(&json), "invalid JSON, {}", json);
+        assert!(json.starts_with("{\"tick\":50,"));
+        assert!(json.contains("\"name\":\"weather\""));
+    }
+
+    #[test]
+    fn escapes_names() {
+        let report = Report {
+            tick: 1,
+            ticks_per_second: 1.0,
+            citizens: 0,
+            creatures: 0,
+            jobs: 0,
+            items: 0,
+            wealth: 0,
+            events: 0,
+            systems: vec![SystemReport {
+                name: "a \"quoted\\ name\n",
+                runs: 1,
+                micros_per_run: 1.0,
+            }],
+        };
+
+        assert!(is_json(&report.to_json()));
+        assert!(!is_json("{\"name\":\"a \"quoted\"}"));
+        assert_eq!(json_string("a \"b\"\n"), "\"a \\\"b\\\"\\n\"");
+    }
+}
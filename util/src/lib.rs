@@ -24,3 +24,6 @@ pub mod colour;
 
 /// Some extra enums for representing items.
 pub mod states;
+
+/// Seeded random number generation.
+pub mod random;
@@ -0,0 +1,6 @@
+//! Seeded random number generation.
+//! 
+
+mod random;
+
+pub use self::random::Random;
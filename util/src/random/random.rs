@@ -0,0 +1,192 @@
+/// A small, seeded, pseudo random number generator.
+///
+/// This is an xorshift64* generator. It's not cryptographically secure, but
+/// it's fast, and it's portable. The same seed gives the same numbers on every
+/// platform, and with every version of Rust. That makes it safe to use for
+/// anything which needs to be replayed exactly.
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Random {
+    /// The internal state.
+    /// This is never zero.
+    state: u64,
+}
+
+impl Random {
+    /// Creates a new generator from the seed given.
+    ///
+    /// Any seed is fine, including zero. Similar seeds still give very
+    /// different numbers.
+    ///
+    pub fn new(seed: u64) -> Random {
+        let state = split_mix(seed);
+
+        Random {
+            state: if state == 0 {
+                0x9E37_79B9_7F4A_7C15
+            } else {
+                state
+            },
+        }
+    }
+
+    /// Returns the internal state of the generator.
+    ///
+    /// This is useful for checking two generators are in step.
+    ///
+    pub fn state(&self) -> u64 {
+        self.state
+    }
+
+    /// Returns a random u64.
+    ///
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+
+        self.state.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// Returns a random u32.
+    ///
+    pub fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    /// Returns a random number from 0.0, up to but not including, 1.0.
+    ///
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u32() >> 8) as f32 / (1u32 << 24) as f32
+    }
+
+    /// Returns a number from min, up to but not including max.
+    ///
+    /// If max is not greater than min, then min is returned.
+    ///
+    pub fn range(
+        &mut self,
+        min: u32,
+        max: u32,
+    ) -> u32 {
+        if max <= min {
+            return min;
+        }
+
+        min + (self.next_u64() % u64::from(max - min)) as u32
+    }
+
+    /// Returns a number from min, up to but not including max.
+    ///
+    /// If max is not greater than min, then min is returned.
+    ///
+    pub fn range_i32(
+        &mut self,
+        min: i32,
+        max: i32,
+    ) -> i32 {
+        if max <= min {
+            return min;
+        }
+
+        let span = (i64::from(max) - i64::from(min)) as u64;
+
+        (i64::from(min) + (self.next_u64() % span) as i64) as i32
+    }
+
+    /// Returns true with the probability given.
+    ///
+    /// The probability is from 0.0 (never), to 1.0 (always).
+    ///
+    pub fn chance(
+        &mut self,
+        probability: f32,
+    ) -> bool {
+        self.next_f32() < probability
+    }
+
+    /// Returns a random item from the slice given.
+    /// If the slice is empty, then you get None.
+    ///
+    pub fn pick<'a, T>(
+        &mut self,
+        items: &'a [T],
+    ) -> Option<&'a T> {
+        if items.is_empty() {
+            None
+        } else {
+            let index = self.range(0, items.len() as u32) as usize;
+
+            items.get(index)
+        }
+    }
+}
+
+/// Scrambles the seed given.
+///
+/// Xorshift gives poor numbers from seeds with only a few bits set, like 1 or
+/// 2. This spreads those bits out first.
+///
+fn split_mix(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_gives_same_numbers() {
+        let mut a = Random::new(1234);
+        let mut b = Random::new(1234);
+
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+    }
+
+    #[test]
+    fn different_seeds_give_different_numbers() {
+        let mut a = Random::new(1);
+        let mut b = Random::new(2);
+
+        assert_ne!(a.next_u64(), b.next_u64());
+    }
+
+    #[test]
+    fn known_sequence() {
+        // If this changes then old replays will no longer work.
+        let mut random = Random::new(0);
+
+        assert_eq!(random.next_u64(), 0x7BBC_B40D_5506_82D0);
+        assert_eq!(random.next_u64(), 0xDE7F_E413_D00C_C9FD);
+    }
+
+    #[test]
+    fn range_stays_in_bounds() {
+        let mut random = Random::new(99);
+
+        for _ in 0..1000 {
+            let n = random.range(5, 10);
+            assert!((5..10).contains(&n));
+
+            let i = random.range_i32(-3, 3);
+            assert!((-3..3).contains(&i));
+
+            let f = random.next_f32();
+            assert!((0.0..1.0).contains(&f));
+        }
+    }
+
+    #[test]
+    fn empty_range_returns_min() {
+        let mut random = Random::new(99);
+
+        assert_eq!(random.range(5, 5), 5);
+        assert_eq!(random.range(5, 2), 5);
+    }
+}
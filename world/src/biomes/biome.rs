@@ -0,0 +1,51 @@
+/// The broad kind of land an area of the world is.
+///
+/// This decides things like what lives there, and what grows there.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Biome {
+    /// Open grassy plains.
+    Grassland,
+
+    /// Woodland.
+    Forest,
+
+    /// Cold, and mostly frozen.
+    Tundra,
+
+    /// Hot, dry, and sandy.
+    Desert,
+
+    /// Wet, and boggy.
+    Swamp,
+
+    /// High and rocky.
+    Mountains,
+}
+
+impl Biome {
+    /// Finds the biome with the name given.
+    /// i.e. 'grassland'.
+    pub fn from_name(name: &str) -> Option<Biome> {
+        match name {
+            "grassland" => Some(Biome::Grassland),
+            "forest" => Some(Biome::Forest),
+            "tundra" => Some(Biome::Tundra),
+            "desert" => Some(Biome::Desert),
+            "swamp" => Some(Biome::Swamp),
+            "mountains" => Some(Biome::Mountains),
+            _ => None,
+        }
+    }
+
+    /// A human readable name for the biome.
+    pub fn name(self) -> &'static str {
+        match self {
+            Biome::Grassland => "grassland",
+            Biome::Forest => "forest",
+            Biome::Tundra => "tundra",
+            Biome::Desert => "desert",
+            Biome::Swamp => "swamp",
+            Biome::Mountains => "mountains",
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use std::io::BufRead;
+use std::io::Error;
+use std::io::ErrorKind;
+use std::io::Result;
+use std::io::Write;
+
+use biomes::Biome;
+use map::Map;
+
+/// Writes out the biome of every tile, one row of the map per line.
+///
+/// Each biome is written as a single character, so the file lines up with
+/// the map it describes.
+pub fn write_biome_map(
+    map: &Map<Biome>,
+    out: &mut Write,
+) -> Result<()> {
+    for (biome, position) in map.slice_all() {
+        if position.x == 0 && position.y > 0 {
+            writeln!(out)?;
+        }
+
+        write!(out, "{}", biome_to_char(biome))?;
+    }
+
+    writeln!(out)
+}
+
+/// Reads in biomes previously written with `write_biome_map`.
+///
+/// Every line must be the same length.
+pub fn read_biome_map(read_in: &mut BufRead) -> Result<Map<Biome>> {
+    let mut rows = Vec::new();
+    for line in read_in.lines() {
+        let row = line?
+            .chars()
+            .map(|c| char_to_biome(c).ok_or_else(|| invalid(format!("unknown biome '{}'", c))))
+            .collect::<Result<Vec<Biome>>>()?;
+
+        rows.push(row);
+    }
+
+    let width = rows.first().map_or(0, |row| row.len());
+    if rows.iter().any(|row| row.len() != width) {
+        return Err(invalid("every line must be the same length".to_string()));
+    }
+
+    let mut map = Map::new(width as u32, rows.len() as u32, Biome::Grassland);
+    map.fill(|_, x, y| rows[y as usize][x as usize]);
+
+    Ok(map)
+}
+
+fn invalid(message: String) -> Error {
+    Error::new(ErrorKind::InvalidData, format!("Invalid biome map, {}", message))
+}
+
+fn biome_to_char(biome: Biome) -> char {
+    match biome {
+        Biome::Grassland => 'g',
+        Biome::Forest => 'f',
+        Biome::Tundra => 't',
+        Biome::Desert => 'd',
+        Biome::Swamp => 's',
+        Biome::Mountains => 'm',
+    }
+}
+
+fn char_to_biome(c: char) -> Option<Biome> {
+    match c {
+        'g' => Some(Biome::Grassland),
+        'f' => Some(Biome::Forest),
+        't' => Some(Biome::Tundra),
+        'd' => Some(Biome::Desert),
+        's' => Some(Biome::Swamp),
+        'm' => Some(Biome::Mountains),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn write_then_read() {
+        let mut map = Map::new(4, 3, Biome::Grassland);
+        map.set(1, 0, Biome::Tundra);
+        map.set(3, 2, Biome::Swamp);
+
+        let mut buffer = Vec::new();
+        write_biome_map(&map, &mut buffer).unwrap();
+        assert_eq!(String::from_utf8(buffer.clone()).unwrap(), "gtgg\ngggg\ngggs\n");
+
+        let read = read_biome_map(&mut Cursor::new(buffer)).unwrap();
+        assert_eq!((read.width, read.height), (4, 3));
+        assert_eq!(read.get(1, 0), Biome::Tundra);
+        assert_eq!(read.get(3, 2), Biome::Swamp);
+        assert_eq!(read.get(0, 2), Biome::Grassland);
+    }
+
+    #[test]
+    fn rejects_bad_maps() {
+        assert!(read_biome_map(&mut Cursor::new("gg\ng\n")).is_err());
+        assert!(read_biome_map(&mut Cursor::new("gx\n")).is_err());
+    }
+}
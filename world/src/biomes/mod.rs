@@ -0,0 +1,6 @@
+mod biome;
+mod biome_map;
+
+pub use self::biome::Biome;
+pub use self::biome_map::read_biome_map;
+pub use self::biome_map::write_biome_map;
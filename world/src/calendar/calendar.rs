@@ -0,0 +1,303 @@
+use calendar::CalendarError;
+use calendar::Month;
+use calendar::Season;
+use calendar::WorldCalendar;
+use calendar::WorldTime;
+
+/// The most months a calendar can have.
+/// This is as many as `WorldTime::month` can count.
+const MAX_MONTHS: usize = 256;
+
+/// A calendar where the length of each unit of time is configured up front.
+///
+/// Every minute, hour, and day is the same length. Months can each have a
+/// different number of days, and each belongs to a season.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Calendar {
+    /// How many seconds in a minute.
+    seconds_per_minute: u8,
+
+    /// How many minutes in an hour.
+    minutes_per_hour: u8,
+
+    /// How many hours in a day.
+    hours_per_day: u8,
+
+    /// The months of the year, in order.
+    months: Vec<Month>,
+}
+
+impl Calendar {
+    /// Creates a new calendar, with units of the lengths given.
+    pub fn new(
+        seconds_per_minute: u8,
+        minutes_per_hour: u8,
+        hours_per_day: u8,
+        months: Vec<Month>,
+    ) -> Result<Calendar, CalendarError> {
+        let units =
+            [("minute", seconds_per_minute), ("hour", minutes_per_hour), ("day", hours_per_day)];
+
+        if let Some(&(unit, _)) = units.iter().find(|&&(_, length)| length == 0) {
+            return Err(CalendarError::EmptyUnit {
+                unit: unit,
+            });
+        }
+
+        if months.is_empty() {
+            return Err(CalendarError::NoMonths);
+        }
+
+        if months.len() > MAX_MONTHS {
+            return Err(CalendarError::TooManyMonths);
+        }
+
+        if let Some(month) = months.iter().find(|month| month.days == 0) {
+            return Err(CalendarError::EmptyMonth {
+                month: month.name.clone(),
+            });
+        }
+
+        Ok(Calendar {
+            seconds_per_minute: seconds_per_minute,
+            minutes_per_hour: minutes_per_hour,
+            hours_per_day: hours_per_day,
+            months: months,
+        })
+    }
+
+    /// The calendar used by the fortress.
+    ///
+    /// This has sixty second minutes, sixty minute hours, and twenty four
+    /// hour days. The year has twelve months of twenty eight days, named
+    /// after stones, with three months to each season starting with spring.
+    pub fn standard() -> Calendar {
+        let names = [
+            "Granite",
+            "Slate",
+            "Felsite",
+            "Hematite",
+            "Malachite",
+            "Galena",
+            "Limestone",
+            "Sandstone",
+            "Timber",
+            "Moonstone",
+            "Opal",
+            "Obsidian",
+        ];
+
+        let months = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| Month::new(name, 28, Season::ALL[i / 3]))
+            .collect();
+
+        Calendar::new(60, 60, 24, months).expect("standard calendar is invalid")
+    }
+
+    /// Returns the months of the year, in order.
+    pub fn months(&self) -> &[Month] {
+        &self.months
+    }
+
+    /// Returns how many seconds there are in a day.
+    pub fn seconds_per_day(&self) -> u64 {
+        u64::from(self.seconds_per_minute)
+            * u64::from(self.minutes_per_hour)
+            * u64::from(self.hours_per_day)
+    }
+
+    /// Returns how many days there are in a year.
+    pub fn days_per_year(&self) -> u64 {
+        self.months.iter().map(|month| u64::from(month.days)).sum()
+    }
+
+    /// Describes how far through its season the month given is.
+    /// i.e. 'Early', 'Mid', or 'Late'.
+    ///
+    /// This is None when the season only lasts for that one month.
+    fn season_phase(
+        &self,
+        month: usize,
+    ) -> Option<&'static str> {
+        let season = self.months[month].season;
+        let same_season = |other: &Month| other.season == season;
+
+        let before = self.months[..month].iter().rev().take_while(|m| same_season(m)).count();
+        let after = self.months[month + 1..].iter().take_while(|m| same_season(m)).count();
+
+        match (before, after) {
+            (0, 0) => None,
+            (0, _) => Some("Early"),
+            (_, 0) => Some("Late"),
+            _ => Some("Mid"),
+        }
+    }
+}
+
+impl WorldCalendar for Calendar {
+    fn hours_per_day(&self) -> u8 {
+        self.hours_per_day
+    }
+
+    fn get_time(
+        &self,
+        time: u64,
+    ) -> WorldTime {
+        let minutes = time / u64::from(self.seconds_per_minute);
+        let hours = minutes / u64::from(self.minutes_per_hour);
+        let days = hours / u64::from(self.hours_per_day);
+
+        let days_per_year = self.days_per_year();
+        let mut day = days % days_per_year;
+        let mut month = 0;
+        while day >= u64::from(self.months[month].days) {
+            day -= u64::from(self.months[month].days);
+            month += 1;
+        }
+
+        WorldTime {
+            second: (time % u64::from(self.seconds_per_minute)) as u8,
+            minute: (minutes % u64::from(self.minutes_per_hour)) as u8,
+            hour: (hours % u64::from(self.hours_per_day)) as u8,
+            day: day as u8,
+            month: month as u8,
+            year: days / days_per_year,
+        }
+    }
+
+    fn to_timestamp(
+        &self,
+        time: WorldTime,
+    ) -> u64 {
+        let months_before = self
+            .months
+            .iter()
+            .take(usize::from(time.month))
+            .map(|month| u64::from(month.days))
+            .sum::<u64>();
+
+        let days = time.year * self.days_per_year() + months_before + u64::from(time.day);
+        let hours = days * u64::from(self.hours_per_day) + u64::from(time.hour);
+        let minutes = hours * u64::from(self.minutes_per_hour) + u64::from(time.minute);
+
+        minutes * u64::from(self.seconds_per_minute) + u64::from(time.second)
+    }
+
+    fn get_season(
+        &self,
+        time: u64,
+    ) -> Season {
+        let month = self.get_time(time).month;
+
+        self.months[usize::from(month)].season
+    }
+
+    fn format_date(
+        &self,
+        time: u64,
+    ) -> String {
+        let time = self.get_time(time);
+        let month = usize::from(time.month);
+        let season = capitalise(self.months[month].season.name());
+
+        let season = match self.season_phase(month) {
+            Some(phase) => format!("{} {}", phase, season),
+            None => season,
+        };
+
+        format!(
+            "{} {}, {}, Year {}",
+            ordinal(u64::from(time.day) + 1),
+            self.months[month].name,
+            season,
+            time.year + 1
+        )
+    }
+}
+
+/// Writes the number out as an ordinal.
+/// i.e. '1st', '2nd', '11th'.
+fn ordinal(n: u64) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+
+    format!("{}{}", n, suffix)
+}
+
+/// Returns the text with the first letter in upper case.
+fn capitalise(text: &str) -> String {
+    let mut chars = text.chars();
+
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn breaks_down_a_timestamp() {
+        let calendar = Calendar::standard();
+        let day = calendar.seconds_per_day();
+        let year = day * calendar.days_per_year();
+        let time = 11 * year + 30 * day + 2 * 3600 + 3 * 60 + 4;
+
+        assert_eq!(
+            calendar.get_time(time),
+            WorldTime {
+                second: 4,
+                minute: 3,
+                hour: 2,
+                day: 2,
+                month: 1,
+                year: 11,
+            }
+        );
+    }
+
+    #[test]
+    fn converts_back_to_the_same_timestamp() {
+        let calendar = Calendar::standard();
+
+        for &time in &[0, 59, 86_399, 2_419_200, 29_030_399, 5_000_000_000_000] {
+            assert_eq!(calendar.to_timestamp(calendar.get_time(time)), time);
+        }
+    }
+
+    #[test]
+    fn formats_dates() {
+        let calendar = Calendar::standard();
+        let day = calendar.seconds_per_day();
+        let year = day * calendar.days_per_year();
+
+        assert_eq!(calendar.format_date(11 * year + 2 * day), "3rd Granite, Early Spring, Year 12");
+        assert_eq!(
+            calendar.format_date(4 * 28 * day + 10 * day),
+            "11th Malachite, Mid Summer, Year 1"
+        );
+        assert_eq!(calendar.get_season(9 * 28 * day), Season::Winter);
+    }
+
+    #[test]
+    fn rejects_empty_units() {
+        let months = vec![Month::new("Only", 10, Season::Spring)];
+
+        assert_eq!(
+            Calendar::new(60, 0, 24, months),
+            Err(CalendarError::EmptyUnit {
+                unit: "hour",
+            })
+        );
+        assert_eq!(Calendar::new(60, 60, 24, Vec::new()), Err(CalendarError::NoMonths));
+    }
+}
@@ -0,0 +1,46 @@
+use std::error::Error;
+use std::fmt;
+
+/// The reasons why a calendar cannot be made.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CalendarError {
+    /// The calendar has no months.
+    NoMonths,
+
+    /// The calendar has more months than a `WorldTime` can count.
+    TooManyMonths,
+
+    /// A unit of time was given a length of zero.
+    /// i.e. zero seconds in a minute.
+    EmptyUnit {
+        /// The name of the unit.
+        unit: &'static str,
+    },
+
+    /// A month was given no days.
+    EmptyMonth {
+        /// The month's name.
+        month: String,
+    },
+}
+
+impl fmt::Display for CalendarError {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        match *self {
+            CalendarError::NoMonths => write!(f, "the calendar has no months"),
+            CalendarError::TooManyMonths => write!(f, "the calendar has too many months"),
+            CalendarError::EmptyUnit {
+                unit,
+            } => write!(f, "a {} cannot be empty", unit),
+            CalendarError::EmptyMonth {
+                ref month,
+            } => write!(f, "the month '{}' has no days", month),
+        }
+    }
+}
+
+impl Error for CalendarError {
+}
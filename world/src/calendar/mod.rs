@@ -4,8 +4,16 @@
 ///
 /// Instead it provides the building blocks to be able to define this yourself.
 /// 
+mod calendar;
+mod calendar_error;
+mod month;
+mod season;
 mod world_calendar;
 mod world_time;
 
+pub use self::calendar::Calendar;
+pub use self::calendar_error::CalendarError;
+pub use self::month::Month;
+pub use self::season::Season;
 pub use self::world_calendar::WorldCalendar;
 pub use self::world_time::WorldTime;
@@ -0,0 +1,30 @@
+use calendar::Season;
+
+/// A month in a calendar.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Month {
+    /// The month's name.
+    /// i.e. 'Granite'.
+    pub name: String,
+
+    /// How many days there are in the month.
+    pub days: u8,
+
+    /// The season the month falls in.
+    pub season: Season,
+}
+
+impl Month {
+    /// Creates a new month.
+    pub fn new(
+        name: &str,
+        days: u8,
+        season: Season,
+    ) -> Month {
+        Month {
+            name: name.to_string(),
+            days: days,
+            season: season,
+        }
+    }
+}
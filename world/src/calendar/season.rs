@@ -0,0 +1,55 @@
+/// The seasons of the year.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Season {
+    /// When things start to grow.
+    Spring,
+
+    /// The long, hot days.
+    Summer,
+
+    /// When things die back.
+    Autumn,
+
+    /// The short, cold days.
+    Winter,
+}
+
+impl Season {
+    /// Every season, in the order they come in the year.
+    pub const ALL: [Season; 4] = [Season::Spring, Season::Summer, Season::Autumn, Season::Winter];
+
+    /// Returns the season for the month given.
+    ///
+    /// This presumes a year of twelve months, with three months to each
+    /// season, starting with spring.
+    pub fn from_month(month: u8) -> Season {
+        match (month % 12) / 3 {
+            0 => Season::Spring,
+            1 => Season::Summer,
+            2 => Season::Autumn,
+            _ => Season::Winter,
+        }
+    }
+
+    /// Finds the season with the name given.
+    /// i.e. 'spring'.
+    pub fn from_name(name: &str) -> Option<Season> {
+        match name {
+            "spring" => Some(Season::Spring),
+            "summer" => Some(Season::Summer),
+            "autumn" => Some(Season::Autumn),
+            "winter" => Some(Season::Winter),
+            _ => None,
+        }
+    }
+
+    /// A human readable name for the season.
+    pub fn name(self) -> &'static str {
+        match self {
+            Season::Spring => "spring",
+            Season::Summer => "summer",
+            Season::Autumn => "autumn",
+            Season::Winter => "winter",
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use calendar::Season;
 use calendar::WorldTime;
 
 /// This is the number of days in a months,
@@ -20,6 +21,47 @@ pub trait WorldCalendar {
     /// laid out version of that. A version with the exact date, hours, etc.
     fn get_time(
         &self,
-        time: u32,
+        time: u64,
     ) -> WorldTime;
+
+    /// The reverse of `get_time`. Given a broken down time, this returns the
+    /// numerical time stamp for it.
+    fn to_timestamp(
+        &self,
+        time: WorldTime,
+    ) -> u64;
+
+    /// Returns how many hours there are in a day.
+    ///
+    /// By default this is 24.
+    fn hours_per_day(&self) -> u8 {
+        24
+    }
+
+    /// Returns the season at the time given.
+    ///
+    /// By default this goes by the month, as in `Season::from_month`.
+    fn get_season(
+        &self,
+        time: u64,
+    ) -> Season {
+        Season::from_month(self.get_time(time).month)
+    }
+
+    /// Describes the date at the time given, for showing to the player.
+    ///
+    /// By default this is just the numbers. i.e. 'Day 3, Month 1, Year 12'.
+    fn format_date(
+        &self,
+        time: u64,
+    ) -> String {
+        let time = self.get_time(time);
+
+        format!(
+            "Day {}, Month {}, Year {}",
+            u32::from(time.day) + 1,
+            u32::from(time.month) + 1,
+            time.year + 1
+        )
+    }
 }
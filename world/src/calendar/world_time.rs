@@ -1,6 +1,6 @@
 /// This represents time in our world.
 /// 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct WorldTime {
     /// The second.
     /// Value from 0 to 60 (or whatever bounds the world uses).
@@ -22,5 +22,6 @@ pub struct WorldTime {
     pub month: u8,
 
     /// The current year.
-    pub year: u8,
+    /// Starting from 0.
+    pub year: u64,
 }
@@ -13,6 +13,9 @@ pub mod weather;
 /// The player management in the world.
 pub mod player;
 
+/// The broad kinds of land in the world.
+pub mod biomes;
+
 /// The world's tiles.
 pub mod tiles;
 
@@ -32,6 +32,8 @@ fn new_tile_map() -> BTreeMap<char, Tile> {
 
     store_tile(&mut map, Tile::Ice);
 
+    store_tile(&mut map, Tile::Door);
+
     map
 }
 
@@ -126,5 +128,7 @@ fn tile_to_char(tile: Tile) -> char {
         Tile::TreeStump => 'o',
 
         Tile::Ice => '∴',
+
+        Tile::Door => '+',
     }
 }
@@ -202,8 +202,40 @@ type MapIteratorItem<T> = (T, Point<u32>);
 fn map_index(
     x: u32,
     y: u32,
-    _width: u32,
-    height: u32,
+    width: u32,
+    _height: u32,
 ) -> usize {
-    (y * height + x) as usize
+    (y * width + x) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stores_each_position_separately_on_wide_maps() {
+        let mut map = Map::new(8, 3, (0, 0));
+        map.fill(|_, x, y| (x, y));
+
+        for y in 0..3 {
+            for x in 0..8 {
+                assert_eq!(map.get(x, y), (x, y));
+            }
+        }
+    }
+
+    #[test]
+    fn slices_tall_maps_in_order() {
+        let mut map = Map::new(2, 5, (0, 0));
+        for y in 0..5 {
+            for x in 0..2 {
+                map.set(x, y, (x, y));
+            }
+        }
+
+        for (data, pos) in map.slice_all() {
+            assert_eq!(data, (pos.x, pos.y));
+        }
+        assert_eq!(map.slice_all().count(), 10);
+    }
 }
@@ -1,5 +1,5 @@
 /// Represents the various types of ground.
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Tile {
     /// An empty tile.
     /// There is nothing here, and you cannot stand on it.
@@ -34,4 +34,39 @@ pub enum Tile {
 
     /// Ice.
     Ice,
+
+    /// A door in a wall.
+    /// You can walk through it, but you can't see through it.
+    Door,
+}
+
+impl Tile {
+    /// Returns true if you can walk over this tile.
+    pub fn is_passable(self) -> bool {
+        match self {
+            Tile::Ground | Tile::Grass | Tile::GrassThick | Tile::Hill | Tile::Ice | Tile::Door => {
+                true
+            },
+            Tile::Empty | Tile::Rocks | Tile::Water | Tile::Wall | Tile::TreeStump => false,
+        }
+    }
+
+    /// Returns true if you cannot see through this tile.
+    ///
+    /// Opaque tiles can still be seen themselves. It's the tiles behind them
+    /// which are hidden.
+    pub fn is_opaque(self) -> bool {
+        match self {
+            Tile::Wall | Tile::Door => true,
+            _ => false,
+        }
+    }
+
+    /// Returns true if things can be grown in this tile.
+    pub fn is_soil(self) -> bool {
+        match self {
+            Tile::Ground | Tile::Grass | Tile::GrassThick => true,
+            _ => false,
+        }
+    }
 }
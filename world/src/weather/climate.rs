@@ -0,0 +1,57 @@
+use biomes::Biome;
+use calendar::Season;
+
+/// The weather a biome tends to have, over the year.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Climate {
+    /// The temperature over the whole year, at sea level, in celcius.
+    pub mean_temperature: f32,
+
+    /// How far the temperature moves between the middle of the night and
+    /// the middle of the afternoon, in celcius.
+    pub daily_swing: f32,
+
+    /// How much warmer summer is, and how much colder winter is, than the
+    /// rest of the year, in celcius.
+    pub seasonal_swing: f32,
+
+    /// How much cloudier than usual the sky tends to be.
+    /// Negative for dry climates.
+    pub cloud_bias: f32,
+}
+
+impl Climate {
+    /// Returns the climate for the biome given.
+    pub fn for_biome(biome: Biome) -> Climate {
+        let (mean_temperature, daily_swing, seasonal_swing, cloud_bias) = match biome {
+            Biome::Grassland => (12.0, 16.0, 12.0, 0.0),
+            Biome::Forest => (10.0, 10.0, 12.0, 0.05),
+            Biome::Tundra => (-8.0, 8.0, 14.0, -0.05),
+            Biome::Desert => (26.0, 24.0, 8.0, -0.35),
+            Biome::Swamp => (22.0, 6.0, 6.0, 0.2),
+            Biome::Mountains => (6.0, 14.0, 12.0, 0.1),
+        };
+
+        Climate {
+            mean_temperature: mean_temperature,
+            daily_swing: daily_swing,
+            seasonal_swing: seasonal_swing,
+            cloud_bias: cloud_bias,
+        }
+    }
+
+    /// How much warmer, or colder, the season is than the rest of the year.
+    pub fn season_offset(
+        &self,
+        season: Season,
+    ) -> f32 {
+        let offset = match season {
+            Season::Spring => 0.0,
+            Season::Summer => 1.0,
+            Season::Autumn => -0.25,
+            Season::Winter => -1.0,
+        };
+
+        offset * self.seasonal_swing
+    }
+}
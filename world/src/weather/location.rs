@@ -0,0 +1,59 @@
+use biomes::Biome;
+use calendar::Season;
+
+/// How much colder it gets for each metre higher up, in celcius.
+const LAPSE_RATE: f32 = 6.5 / 1000.0;
+
+/// How much colder it gets for each degree of latitude further from the
+/// equator, in celcius.
+const LATITUDE_COOLING: f32 = 0.4;
+
+/// The latitude a biome's climate is described at, in degrees.
+/// Places closer to the equator are warmer, and further are colder.
+const TEMPERATE_LATITUDE: f32 = 30.0;
+
+/// Where in the world the weather is being asked about.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Location {
+    /// The kind of land around it.
+    pub biome: Biome,
+
+    /// How far north, or south, of the equator it is, in degrees.
+    /// From -90.0 (the south pole) to 90.0 (the north pole).
+    pub latitude: f32,
+
+    /// How high above sea level it is, in metres.
+    pub elevation: f32,
+
+    /// If it's beneath the ground, out of the sky.
+    pub underground: bool,
+}
+
+impl Location {
+    /// How much warmer, or colder, it is here than the biome's climate
+    /// describes. This is from the latitude, and the elevation.
+    pub fn temperature_offset(&self) -> f32 {
+        let latitude = (TEMPERATE_LATITUDE - self.latitude.abs()) * LATITUDE_COOLING;
+        let elevation = -self.elevation.max(0.0) * LAPSE_RATE;
+
+        latitude + elevation
+    }
+
+    /// Returns the season it is here, when it's the season given in the
+    /// north. The seasons are the other way around south of the equator.
+    pub fn local_season(
+        &self,
+        season: Season,
+    ) -> Season {
+        if self.latitude >= 0.0 {
+            return season;
+        }
+
+        match season {
+            Season::Spring => Season::Autumn,
+            Season::Summer => Season::Winter,
+            Season::Autumn => Season::Spring,
+            Season::Winter => Season::Summer,
+        }
+    }
+}
@@ -1,8 +1,16 @@
 /// A module for the weather modelling.
 /// This is the traits for modelling, and utility code.
 /// 
+mod climate;
+mod location;
+mod precipitation;
 mod temperature;
+mod weather;
 mod weather_report;
 
+pub use self::climate::Climate;
+pub use self::location::Location;
+pub use self::precipitation::Precipitation;
 pub use self::temperature::Temperature;
+pub use self::weather::Weather;
 pub use self::weather_report::WeatherReport;
@@ -0,0 +1,23 @@
+/// What is falling from the sky.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Precipitation {
+    /// Nothing is falling.
+    Clear,
+
+    /// It's raining.
+    Rain,
+
+    /// It's snowing.
+    Snow,
+}
+
+impl Precipitation {
+    /// A human readable name for the precipitation.
+    pub fn name(self) -> &'static str {
+        match self {
+            Precipitation::Clear => "clear",
+            Precipitation::Rain => "rain",
+            Precipitation::Snow => "snow",
+        }
+    }
+}
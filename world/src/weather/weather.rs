@@ -0,0 +1,242 @@
+use util::random::Random;
+
+use calendar::Season;
+use weather::Climate;
+use weather::Location;
+use weather::Precipitation;
+use weather::Temperature;
+
+/// How long it takes for one weather front to give way to the next.
+/// In seconds.
+///
+/// The weather is picked at random for the start of each front, and
+/// changes smoothly from one to the next.
+const FRONT_SECONDS: u64 = 4 * 60 * 60;
+
+/// How cloudy it must be before anything falls.
+/// From 0.0 (clear skies) to 1.0 (overcast).
+const PRECIPITATION_CLOUD_COVER: f32 = 0.65;
+
+/// The fastest the wind will blow at sea level, in metres per second.
+const MAX_WIND_SPEED: f32 = 25.0;
+
+/// How much higher up it needs to be, in metres, for the wind to blow
+/// twice as fast.
+const WIND_DOUBLING_ELEVATION: f32 = 3000.0;
+
+/// Separates the random numbers used for each part of the weather, so they
+/// don't all move together.
+const CLOUD_CHANNEL: u64 = 1;
+const WIND_SPEED_CHANNEL: u64 = 2;
+const WIND_DIRECTION_CHANNEL: u64 = 3;
+
+/// The names of the eight points of the compass, going clockwise from north.
+const COMPASS_POINTS: [&str; 8] =
+    ["north", "north-east", "east", "south-east", "south", "south-west", "west", "north-west"];
+
+/// The state of the sky at a moment in time.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Weather {
+    /// How much of the sky is covered in cloud.
+    /// From 0.0 (clear skies) to 1.0 (overcast).
+    pub cloud_cover: f32,
+
+    /// What is falling from the sky.
+    pub precipitation: Precipitation,
+
+    /// How heavily it's falling.
+    /// From 0.0 (nothing) to 1.0 (a downpour).
+    pub intensity: f32,
+
+    /// Where the wind is blowing from, in degrees clockwise from north.
+    pub wind_direction: f32,
+
+    /// How fast the wind is blowing, in metres per second.
+    pub wind_speed: f32,
+}
+
+impl Weather {
+    /// Generates the weather at the time given, out in the open at the
+    /// location given.
+    ///
+    /// The same seed and time always gives the same weather, and times close
+    /// together give similar weather. The season and climate decide how
+    /// cloudy it tends to be, and the temperature decides if it rains or
+    /// snows. The wind blows harder higher up.
+    pub fn generate(
+        seed: u64,
+        time: u64,
+        season: Season,
+        location: &Location,
+        temperature: Temperature,
+    ) -> Weather {
+        let bias = cloud_bias(season) + Climate::for_biome(location.biome).cloud_bias;
+        let cloud_cover = (smooth_noise(seed, CLOUD_CHANNEL, time) + bias).clamp(0.0, 1.0);
+        let wind = smooth_noise(seed, WIND_SPEED_CHANNEL, time);
+        let wind_scale = 1.0 + location.elevation.max(0.0) / WIND_DOUBLING_ELEVATION;
+
+        let (precipitation, intensity) = if cloud_cover < PRECIPITATION_CLOUD_COVER {
+            (Precipitation::Clear, 0.0)
+        } else {
+            let intensity =
+                (cloud_cover - PRECIPITATION_CLOUD_COVER) / (1.0 - PRECIPITATION_CLOUD_COVER);
+
+            if temperature.as_celcius() < 0.0 {
+                (Precipitation::Snow, intensity)
+            } else {
+                (Precipitation::Rain, intensity)
+            }
+        };
+
+        Weather {
+            cloud_cover: cloud_cover,
+            precipitation: precipitation,
+            intensity: intensity,
+            wind_direction: smooth_noise(seed, WIND_DIRECTION_CHANNEL, time) * 360.0,
+            wind_speed: wind * wind * MAX_WIND_SPEED * wind_scale,
+        }
+    }
+
+    /// The weather somewhere out of the sky, like underground.
+    /// Nothing falls, and there is no wind.
+    pub fn sheltered() -> Weather {
+        Weather {
+            cloud_cover: 0.0,
+            precipitation: Precipitation::Clear,
+            intensity: 0.0,
+            wind_direction: 0.0,
+            wind_speed: 0.0,
+        }
+    }
+
+    /// Returns true if anything is falling from the sky.
+    pub fn is_precipitating(&self) -> bool {
+        self.precipitation != Precipitation::Clear
+    }
+
+    /// Returns the point of the compass the wind is blowing from.
+    /// i.e. 'north-west'.
+    pub fn wind_compass_point(&self) -> &'static str {
+        let point = ((self.wind_direction / 45.0).round() as usize) % COMPASS_POINTS.len();
+
+        COMPASS_POINTS[point]
+    }
+
+    /// Describes the weather, for showing to the player.
+    /// i.e. 'heavy rain, wind 12 m/s from the north-west'.
+    pub fn describe(&self) -> String {
+        let sky = match self.precipitation {
+            Precipitation::Clear if self.cloud_cover < 0.3 => "clear skies".to_string(),
+            Precipitation::Clear => "cloudy".to_string(),
+            precipitation if self.intensity < 0.3 => format!("light {}", precipitation.name()),
+            precipitation if self.intensity < 0.7 => precipitation.name().to_string(),
+            precipitation => format!("heavy {}", precipitation.name()),
+        };
+
+        format!("{}, wind {:.0} m/s from the {}", sky, self.wind_speed, self.wind_compass_point())
+    }
+}
+
+/// How much cloudier than usual each season is.
+fn cloud_bias(season: Season) -> f32 {
+    match season {
+        Season::Spring => 0.1,
+        Season::Summer => -0.1,
+        Season::Autumn => 0.15,
+        Season::Winter => 0.05,
+    }
+}
+
+/// Returns a number from 0.0 to 1.0, which changes smoothly over time.
+///
+/// A random number is picked at the start of each front, and this eases from
+/// one to the next.
+fn smooth_noise(
+    seed: u64,
+    channel: u64,
+    time: u64,
+) -> f32 {
+    let front = time / FRONT_SECONDS;
+    let progress = (time % FRONT_SECONDS) as f32 / FRONT_SECONDS as f32;
+    let eased = progress * progress * (3.0 - 2.0 * progress);
+
+    let from = front_noise(seed, channel, front);
+    let to = front_noise(seed, channel, front + 1);
+
+    from + (to - from) * eased
+}
+
+/// The random number for one front.
+fn front_noise(
+    seed: u64,
+    channel: u64,
+    front: u64,
+) -> f32 {
+    let mut random =
+        Random::new(seed ^ channel.wrapping_mul(0x9E37_79B9_7F4A_7C15) ^ front.rotate_left(32));
+
+    random.next_f32()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biomes::Biome;
+
+    fn grassland() -> Location {
+        Location {
+            biome: Biome::Grassland,
+            latitude: 30.0,
+            elevation: 0.0,
+            underground: false,
+        }
+    }
+
+    #[test]
+    fn the_same_time_gives_the_same_weather() {
+        let temperature = Temperature::from_celcius(10.0);
+
+        for &time in &[0, 1_000, 123_456_789] {
+            assert_eq!(
+                Weather::generate(7, time, Season::Spring, &grassland(), temperature),
+                Weather::generate(7, time, Season::Spring, &grassland(), temperature)
+            );
+        }
+    }
+
+    #[test]
+    fn changes_smoothly_over_time() {
+        let temperature = Temperature::from_celcius(10.0);
+        let mut last = Weather::generate(3, 0, Season::Autumn, &grassland(), temperature);
+
+        for minute in 1..(24 * 60) {
+            let next = Weather::generate(3, minute * 60, Season::Autumn, &grassland(), temperature);
+
+            assert!((next.cloud_cover - last.cloud_cover).abs() < 0.01);
+            assert!((next.wind_speed - last.wind_speed).abs() < 0.5);
+            last = next;
+        }
+    }
+
+    #[test]
+    fn snows_when_below_freezing() {
+        let cold = Temperature::from_celcius(-5.0);
+        let warm = Temperature::from_celcius(5.0);
+
+        let time = (0..1_000)
+            .map(|front| front * FRONT_SECONDS)
+            .find(|&time| {
+                Weather::generate(1, time, Season::Winter, &grassland(), cold).is_precipitating()
+            })
+            .unwrap();
+
+        assert_eq!(
+            Weather::generate(1, time, Season::Winter, &grassland(), cold).precipitation,
+            Precipitation::Snow
+        );
+        assert_eq!(
+            Weather::generate(1, time, Season::Winter, &grassland(), warm).precipitation,
+            Precipitation::Rain
+        );
+    }
+}
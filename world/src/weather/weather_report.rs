@@ -1,25 +1,162 @@
+use calendar::WorldCalendar;
 use calendar::WorldTime;
+use weather::Climate;
+use weather::Location;
 use weather::Temperature;
+use weather::Weather;
+
+/// How far through the day it's warmest, from 0.0 (midnight) to 1.0 (the
+/// next midnight). In a 24 hour day this is 4pm.
+const WARMEST_TIME_OF_DAY: f32 = 2.0 / 3.0;
 
 /// A description of the current weather.
+#[derive(Copy, Clone)]
 pub struct WeatherReport {
     /// The current temperature, in celsius.
     pub temperature: Temperature,
+
+    /// What the sky is doing.
+    pub weather: Weather,
 }
 
 impl WeatherReport {
-    /// Generates a new report for the time given.
-    /// If you give the same time again, then you get the same report.
-    pub fn generate_report(time: WorldTime) -> WeatherReport {
+    /// Generates a new report for the time given, at the location given.
+    /// If you give the same seed, time, and location again, then you get
+    /// the same report.
+    ///
+    /// Underground the temperature stays the same all year round, and
+    /// nothing falls from the sky.
+    pub fn generate_report(
+        calendar: &WorldCalendar,
+        seed: u64,
+        time: u64,
+        location: &Location,
+    ) -> WeatherReport {
+        let climate = Climate::for_biome(location.biome);
+        let mean_temperature = climate.mean_temperature + location.temperature_offset();
+
+        if location.underground {
+            return WeatherReport {
+                temperature: Temperature::from_celcius(mean_temperature),
+                weather: Weather::sheltered(),
+            };
+        }
+
+        let season = location.local_season(calendar.get_season(time));
+        let celcius = mean_temperature
+            + climate.season_offset(season)
+            + WeatherReport::daily_offset(&climate, calendar, calendar.get_time(time));
+        let temperature = Temperature::from_celcius(celcius);
+
         WeatherReport {
-            temperature: WeatherReport::generate_temperature(time),
+            temperature: temperature,
+            weather: Weather::generate(seed, time, season, location, temperature),
         }
     }
 
-    /// Generates the temperature for the time given.
-    fn generate_temperature(time: WorldTime) -> Temperature {
-        let celcius = (20 - (16 - i32::from(time.hour)).abs()) as f32;
+    /// How much warmer, or colder, it is at the time of day given than the
+    /// day as a whole. It's coldest at night, and warmest in the afternoon.
+    ///
+    /// The hours from the warmest are counted the short way round, so it
+    /// changes smoothly across midnight.
+    fn daily_offset(
+        climate: &Climate,
+        calendar: &WorldCalendar,
+        time: WorldTime,
+    ) -> f32 {
+        let hours_per_day = f32::from(calendar.hours_per_day().max(1));
+        let distance = (hours_per_day * WARMEST_TIME_OF_DAY - f32::from(time.hour)).abs();
+        let hours_from_warmest = distance.min(hours_per_day - distance);
+
+        climate.daily_swing * (0.5 - hours_from_warmest / (hours_per_day / 2.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biomes::Biome;
+    use calendar::Calendar;
+    use calendar::Month;
+    use calendar::Season;
+
+    fn location(
+        biome: Biome,
+        elevation: f32,
+        underground: bool,
+    ) -> Location {
+        Location {
+            biome: biome,
+            latitude: 30.0,
+            elevation: elevation,
+            underground: underground,
+        }
+    }
+
+    fn celcius_at(
+        time: u64,
+        location: &Location,
+    ) -> f32 {
+        let calendar = Calendar::standard();
+
+        WeatherReport::generate_report(&calendar, 1, time, location).temperature.as_celcius()
+    }
+
+    #[test]
+    fn higher_is_colder() {
+        let low = location(Biome::Mountains, 0.0, false);
+        let high = location(Biome::Mountains, 2000.0, false);
+
+        assert!(celcius_at(0, &high) < celcius_at(0, &low) - 10.0);
+    }
+
+    #[test]
+    fn biomes_have_different_climates() {
+        let tundra = location(Biome::Tundra, 0.0, false);
+        let swamp = location(Biome::Swamp, 0.0, false);
+
+        assert!(celcius_at(0, &tundra) < 0.0);
+        assert!(celcius_at(0, &swamp) > 10.0);
+    }
+
+    #[test]
+    fn warms_and_cools_smoothly_across_midnight() {
+        let desert = location(Biome::Desert, 0.0, false);
+        let hour = 60 * 60;
+        let midnight = 10 * 24 * hour;
+
+        let before = celcius_at(midnight - hour, &desert);
+        let after = celcius_at(midnight, &desert);
+        assert!((before - after).abs() <= 2.01, "{} then {}", before, after);
+
+        assert!(celcius_at(midnight + 16 * hour, &desert) > after + 10.0);
+    }
+
+    #[test]
+    fn the_warmest_hour_follows_the_length_of_the_day() {
+        let months = vec![Month::new("Only", 10, Season::Summer)];
+        let calendar = Calendar::new(60, 60, 12, months).unwrap();
+        let grassland = location(Biome::Grassland, 0.0, false);
+        let celcius = |hour: u64| {
+            WeatherReport::generate_report(&calendar, 1, hour * 60 * 60, &grassland)
+                .temperature
+                .as_celcius()
+        };
+
+        let warmest = (0..12).max_by(|&a, &b| celcius(a).partial_cmp(&celcius(b)).unwrap());
+        assert_eq!(warmest, Some(8));
+    }
+
+    #[test]
+    fn underground_stays_the_same() {
+        let cave = location(Biome::Grassland, 0.0, true);
+        let hour = 60 * 60;
+        let season = 3 * 28 * 24 * hour;
 
-        Temperature::from_celcius(celcius)
+        assert_eq!(celcius_at(0, &cave), celcius_at(12 * hour, &cave));
+        assert_eq!(celcius_at(0, &cave), celcius_at(3 * season, &cave));
+        assert!(!WeatherReport::generate_report(&Calendar::standard(), 1, 0, &cave)
+            .weather
+            .is_precipitating());
     }
 }
@@ -1,4 +1,6 @@
+use biomes::Biome;
 use calendar::WorldCalendar;
+use map::Map;
 
 /// This struct contains all the information for creating a world.
 /// What that world does with it, well, that's up to the world.
@@ -6,4 +8,17 @@ use calendar::WorldCalendar;
 pub struct WorldSetup<'a> {
     /// A description of the calendar for this world.
     pub calendar: &'a WorldCalendar,
+
+    /// The kind of land the world is set in.
+    pub biome: Biome,
+
+    /// The biome of each tile on the map, if the map has them.
+    /// Otherwise every tile is in `biome`.
+    pub biomes: Option<&'a Map<Biome>>,
+
+    /// How far north, or south, of the equator the world is, in degrees.
+    pub latitude: f32,
+
+    /// How high above sea level the ground is, in metres.
+    pub elevation: f32,
 }